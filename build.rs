@@ -0,0 +1,6 @@
+//! Rebuilds the executable when a bundled challenge changes: `include_dir!`
+//! embeds `challenges/` but doesn't tell cargo to watch it.
+
+fn main() {
+    println!("cargo:rerun-if-changed=challenges");
+}
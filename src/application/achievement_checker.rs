@@ -1,6 +1,5 @@
 use crate::domain::{Achievement, AchievementId, MasteryTier, Progress};
 use chrono::Utc;
-use std::collections::HashSet;
 
 /// Service for checking and unlocking achievements
 pub struct AchievementChecker;
@@ -39,7 +38,7 @@ impl AchievementChecker {
                     .filter(|stats| {
                         stats
                             .best_time()
-                            .map_or(false, |t| t.as_secs() < 10)
+                            .is_some_and(|t| t.as_secs() < 10)
                     })
                     .count();
                 fast_completions >= 10
@@ -50,7 +49,7 @@ impl AchievementChecker {
                 progress
                     .all_challenge_stats()
                     .values()
-                    .any(|stats| stats.best_time().map_or(false, |t| t.as_secs() < 5))
+                    .any(|stats| stats.best_time().is_some_and(|t| t.as_secs() < 5))
             }
 
             // Perfectionist - Complete a challenge with under 20 keystrokes
@@ -58,12 +57,12 @@ impl AchievementChecker {
                 progress
                     .all_challenge_stats()
                     .values()
-                    .any(|stats| stats.best_keystrokes().map_or(false, |ks| ks < 20))
+                    .any(|stats| stats.best_keystrokes().is_some_and(|ks| ks < 20))
             }
 
             // Efficiency Expert - Maintain an average under 40 keystrokes
             AchievementId::EfficiencyExpert => {
-                progress.average_keystrokes().map_or(false, |avg| avg < 40)
+                progress.average_keystrokes().is_some_and(|avg| avg < 40)
             }
 
             // Consistent Learner - Practice 7 days in a row
@@ -77,11 +76,7 @@ impl AchievementChecker {
                 let gold_count = progress
                     .all_challenge_stats()
                     .values()
-                    .filter(|stats| {
-                        stats
-                            .mastery_tier()
-                            .map_or(false, |tier| tier == MasteryTier::Gold)
-                    })
+                    .filter(|stats| stats.mastery_tier().is_some_and(|tier| tier >= MasteryTier::Gold))
                     .count();
                 gold_count >= 25
             }
@@ -93,11 +88,7 @@ impl AchievementChecker {
                 let gold_count = progress
                     .all_challenge_stats()
                     .values()
-                    .filter(|stats| {
-                        stats
-                            .mastery_tier()
-                            .map_or(false, |tier| tier == MasteryTier::Gold)
-                    })
+                    .filter(|stats| stats.mastery_tier().is_some_and(|tier| tier >= MasteryTier::Gold))
                     .count();
                 gold_count >= 10
             }
@@ -113,7 +104,7 @@ impl AchievementChecker {
                     return false;
                 }
                 let completed = progress.total_completed();
-                let halfway = (total_challenges + 1) / 2; // Round up
+                let halfway = total_challenges.div_ceil(2); // Round up
                 completed >= halfway
             }
 
@@ -147,7 +138,8 @@ mod tests {
 
         // Should unlock FirstSteps
         let newly_unlocked = AchievementChecker::check_achievements(&mut progress, 50);
-        assert!(newly_unlocked.iter().any(|a| a.id() == AchievementId::FirstSteps));
+        let ids: Vec<AchievementId> = newly_unlocked.iter().map(|a| a.id()).collect();
+        assert_eq!(ids, vec![AchievementId::FirstSteps, AchievementId::EfficiencyExpert]);
     }
 
     #[test]
@@ -164,7 +156,8 @@ mod tests {
 
         // First check should unlock
         let newly_unlocked = AchievementChecker::check_achievements(&mut progress, 50);
-        assert_eq!(newly_unlocked.len(), 1);
+        let ids: Vec<AchievementId> = newly_unlocked.iter().map(|a| a.id()).collect();
+        assert_eq!(ids, vec![AchievementId::FirstSteps, AchievementId::EfficiencyExpert]);
 
         // Second check should not unlock again
         let newly_unlocked = AchievementChecker::check_achievements(&mut progress, 50);
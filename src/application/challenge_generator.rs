@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use crate::application::shuffle::{shuffle, SeededRng};
+use crate::domain::{Challenge, IdentifierCase, ParamKind, TemplateParam};
+
+/// First words of generated identifiers
+///
+/// 13 and 16 words are coprime, so pairing the i-th of each (modulo their
+/// lengths) gives 208 distinct identifiers before one repeats.
+const QUALIFIERS: [&str; 13] = [
+    "max", "min", "user", "item", "total", "next", "last", "raw", "base", "temp", "page",
+    "cache", "line",
+];
+const NOUNS: [&str; 16] = [
+    "count", "index", "name", "value", "size", "buffer", "offset", "limit", "result", "score",
+    "width", "height", "path", "label", "token", "node",
+];
+
+/// Words used by filler code, never by identifier parameters
+const FILLER: [&str; 8] = ["alpha", "beta", "gamma", "delta", "kappa", "omega", "sigma", "theta"];
+
+/// Service filling in template challenges
+///
+/// The same seed always gives the same text, so a generated attempt can be
+/// reproduced; callers pass a fresh seed to get a fresh buffer.
+pub struct ChallengeGenerator;
+
+impl ChallengeGenerator {
+    /// A rendering of the challenge's template, or the challenge itself if
+    /// it isn't generated
+    pub fn generate(challenge: &Challenge, seed: u64) -> Challenge {
+        let Some(template) = challenge.template() else {
+            return challenge.clone();
+        };
+
+        let values = Self::values(template.params(), seed);
+        let mut generated = challenge.clone().with_files(template.render_files(&values));
+        if let Some((solution, keystrokes)) = template.render_solution(&values) {
+            generated = generated.with_optimal_solution(solution, keystrokes);
+        }
+        generated
+    }
+
+    /// A value for every parameter; identifiers never repeat within one set
+    fn values(params: &[TemplateParam], seed: u64) -> HashMap<String, String> {
+        let mut qualifiers = QUALIFIERS;
+        let mut nouns = NOUNS;
+        shuffle(&mut qualifiers, seed);
+        shuffle(&mut nouns, seed.rotate_left(32));
+        let mut rng = SeededRng::new(seed ^ 0x9e37_79b9_7f4a_7c15);
+        let mut identifiers = 0;
+        let mut next_identifier = |case: &IdentifierCase| {
+            let words = [
+                qualifiers[identifiers % qualifiers.len()],
+                nouns[identifiers % nouns.len()],
+            ];
+            identifiers += 1;
+            case.join(&words)
+        };
+
+        params
+            .iter()
+            .map(|param| {
+                let value = match param.kind() {
+                    ParamKind::Identifier { case } => next_identifier(case),
+                    ParamKind::Number { min, max } => rng.between(*min, *max).to_string(),
+                    ParamKind::List {
+                        min,
+                        max,
+                        separator,
+                    } => {
+                        let count = rng.between(*min as i64, *max as i64) as usize;
+                        (0..count)
+                            .map(|_| next_identifier(&IdentifierCase::Snake))
+                            .collect::<Vec<_>>()
+                            .join(separator)
+                    }
+                    ParamKind::Lorem { min, max } => {
+                        let lines = rng.between(*min as i64, *max as i64) as usize;
+                        (0..lines)
+                            .map(|_| Self::filler_line(&mut rng))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    }
+                };
+                (param.name().to_string(), value)
+            })
+            .collect()
+    }
+
+    /// One line of plausible code made of filler words
+    fn filler_line(rng: &mut SeededRng) -> String {
+        let mut word = || FILLER[rng.below(FILLER.len() as u64) as usize];
+        let (a, b, c) = (word(), word(), word());
+        let n = rng.between(1, 99);
+        match rng.below(4) {
+            0 => format!("let {} = {}({});", a, b, n),
+            1 => format!("{}.{}({}, {});", a, b, c, n),
+            2 => format!("if {} > {} {{ {}(); }}", a, n, b),
+            _ => format!("{} = {} + {};", a, b, n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ChallengeFile, ChallengeTemplate};
+
+    fn template_challenge() -> Challenge {
+        let template = ChallengeTemplate::new(
+            vec![
+                TemplateParam::new("old", ParamKind::Identifier { case: IdentifierCase::Snake }),
+                TemplateParam::new("new", ParamKind::Identifier { case: IdentifierCase::Camel }),
+                TemplateParam::new("n", ParamKind::Number { min: 2, max: 9 }),
+                TemplateParam::new(
+                    "items",
+                    ParamKind::List {
+                        min: 3,
+                        max: 3,
+                        separator: ", ".to_string(),
+                    },
+                ),
+                TemplateParam::new("body", ParamKind::Lorem { min: 2, max: 4 }),
+            ],
+            vec![ChallengeFile::new(
+                "main.rs",
+                "{{body}}\nlet {{old}} = [{{items}}; {{n}}];",
+                "{{body}}\nlet {{new}} = [{{items}}; {{n}}];",
+            )],
+        )
+        .with_optimal_solution("c{{new}}<esc>", 2);
+        Challenge::new("generated", "T", "D", "", "", "h").with_template(template)
+    }
+
+    #[test]
+    fn test_generate_is_seeded() {
+        let challenge = template_challenge();
+        let first = ChallengeGenerator::generate(&challenge, 1);
+        let again = ChallengeGenerator::generate(&challenge, 1);
+        assert_eq!(first.files(), again.files());
+        assert!(first.is_generated());
+
+        let differs = (2..10).any(|seed| {
+            ChallengeGenerator::generate(&challenge, seed).starting_content() != first.starting_content()
+        });
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_generated_values_fit_their_params() {
+        let values = ChallengeGenerator::values(template_challenge().template().unwrap().params(), 42);
+
+        assert_ne!(values["old"].replace('_', "").to_lowercase(), values["new"].to_lowercase());
+        assert!(values["old"].contains('_'));
+        assert!((2..=9).contains(&values["n"].parse::<i64>().unwrap()));
+        assert_eq!(values["items"].split(", ").count(), 3);
+        assert!((2..=4).contains(&values["body"].lines().count()));
+        assert!(!QUALIFIERS.iter().any(|word| values["body"].contains(word)));
+    }
+
+    #[test]
+    fn test_optimal_keystrokes_count_typed_values() {
+        let challenge = template_challenge();
+        let generated = ChallengeGenerator::generate(&challenge, 5);
+        let new = ChallengeGenerator::values(challenge.template().unwrap().params(), 5)["new"].clone();
+
+        assert_eq!(generated.optimal_solution(), Some(format!("c{}<esc>", new).as_str()));
+        assert_eq!(generated.optimal_keystrokes(), Some(2 + new.len() as u32));
+    }
+
+    #[test]
+    fn test_thresholds_are_the_same_for_every_rendering() {
+        let challenge = template_challenge();
+        let thresholds = challenge.tier_thresholds();
+        assert!(!thresholds.ranks_keystrokes());
+        assert!((1..10)
+            .all(|seed| ChallengeGenerator::generate(&challenge, seed).tier_thresholds() == thresholds));
+    }
+
+    #[test]
+    fn test_plain_challenges_are_unchanged() {
+        let challenge = Challenge::new("plain", "T", "D", "a", "b", "h");
+        assert_eq!(ChallengeGenerator::generate(&challenge, 3).files(), challenge.files());
+    }
+}
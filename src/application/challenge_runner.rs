@@ -1,50 +1,164 @@
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
+use crate::application::editor_process::{EditorSession, RunnerEvent};
 use crate::application::validator::SolutionValidator;
-use crate::domain::{Challenge, Solution};
+use crate::domain::{Challenge, ChallengeFile, Recording, Solution};
 use crate::infrastructure::recorder::Recorder;
 
+/// How long to wait for further file events before validating
+///
+/// Editors often save in several steps (write temp file, rename over the
+/// original, update metadata); validating after the burst avoids reading a
+/// half-written file.
+const DEBOUNCE: Duration = Duration::from_millis(25);
+
+/// Upper bound on a debounced burst, so a chatty editor can't stall validation
+const MAX_BURST: Duration = Duration::from_millis(250);
+
+/// A temporary directory holding the files of a challenge attempt
+///
+/// `files` is in the same order as `Challenge::files`, so each path can be
+/// paired with the file it was created from.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    root: PathBuf,
+    files: Vec<PathBuf>,
+}
+
+impl Workspace {
+    pub fn new(root: PathBuf, files: Vec<PathBuf>) -> Self {
+        Self { root, files }
+    }
+
+    /// Directory containing all challenge files
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Absolute paths of the challenge files
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+}
+
+/// Fully resolved command line for launching an editor session
+///
+/// Built by the `EditorSpawner` so that a `Recorder` can wrap exactly the
+/// same invocation (arguments, isolated config environment, working dir).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchCommand {
+    program: String,
+    args: Vec<String>,
+    env: Vec<(String, String)>,
+    current_dir: PathBuf,
+}
+
+impl LaunchCommand {
+    pub fn new(program: impl Into<String>, current_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            env: Vec::new(),
+            current_dir: current_dir.into(),
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Environment variables set on top of the inherited environment
+    pub fn envs(&self) -> &[(String, String)] {
+        &self.env
+    }
+
+    pub fn current_dir(&self) -> &Path {
+        &self.current_dir
+    }
+}
+
 /// Trait for spawning and managing an editor process
 pub trait EditorSpawner {
-    /// Spawns an editor with the given file path
-    fn spawn(&mut self, file_path: &Path) -> Result<()>;
+    /// Builds the command that opens the editor on the workspace with all
+    /// challenge files, including any per-session config isolation
+    fn launch_command(&mut self, workspace: &Workspace) -> Result<LaunchCommand>;
 
-    /// Terminates the editor process gracefully
-    fn terminate(&mut self) -> Result<()>;
-
-    /// Checks if the editor is still running
-    fn is_running(&self) -> bool;
+    /// Spawns `command` (from `launch_command`, possibly wrapped by a
+    /// recorder) and sends `RunnerEvent::EditorExited` on `events` when it exits
+    fn spawn(
+        &mut self,
+        command: &LaunchCommand,
+        events: mpsc::Sender<RunnerEvent>,
+    ) -> Result<Box<dyn EditorSession>>;
 }
 
 /// Trait for watching file changes
 pub trait FileWatcher {
-    /// Starts watching the file and sends notifications on the channel
-    fn watch(&mut self, file_path: &Path, tx: mpsc::Sender<()>) -> Result<()>;
+    /// Starts watching the directory recursively and sends
+    /// `RunnerEvent::FileChanged` for every content change inside it
+    ///
+    /// Watching the directory rather than individual files keeps
+    /// notifications flowing when an editor saves by renaming a new file
+    /// over the old one.
+    fn watch(&mut self, path: &Path, tx: mpsc::Sender<RunnerEvent>) -> Result<()>;
 
-    /// Stops watching the file
+    /// Stops watching
     fn stop(&mut self) -> Result<()>;
 }
 
 /// Trait for file system operations
 pub trait FileSystem {
-    /// Creates a temporary file with the given content
-    fn create_temp_file(&self, content: &str) -> Result<PathBuf>;
+    /// Creates a temporary directory containing the starting content of each file
+    fn create_workspace(&self, files: &[ChallengeFile]) -> Result<Workspace>;
 
     /// Reads the content of a file
     fn read_file(&self, path: &Path) -> Result<String>;
 
-    /// Cleans up the temporary file
-    fn cleanup(&self, path: &Path) -> Result<()>;
+    /// Removes the workspace and everything in it
+    fn cleanup(&self, workspace: &Workspace) -> Result<()>;
+}
+
+/// What an editing session left behind (see `ChallengeRunner::capture`)
+#[derive(Debug)]
+pub struct CapturedEdit {
+    /// Final content of each file, in the order they were given
+    pub contents: Vec<String>,
+    /// Time from start to the last save, if anything was saved
+    pub last_change: Option<Duration>,
+    pub recording: Option<Recording>,
+}
+
+/// An editor session in progress and what it is working on
+struct RunningEditor {
+    workspace: Workspace,
+    recording_path: Option<PathBuf>,
+    session: Box<dyn EditorSession>,
+    events: mpsc::Receiver<RunnerEvent>,
+    start_time: Instant,
 }
 
 /// Orchestrates the challenge execution flow
 ///
 /// This is the main application service that coordinates:
-/// - File creation
+/// - Workspace creation
 /// - Editor spawning
 /// - File watching
 /// - Solution validation
@@ -86,99 +200,372 @@ where
 
     /// Runs the challenge and returns the solution
     pub fn run(&mut self, challenge: &Challenge) -> Result<Solution> {
-        // Create temp file with starting content
-        let temp_file = self
-            .filesystem
-            .create_temp_file(challenge.starting_content())?;
+        let mut running = self.launch(challenge.files(), challenge.id())?;
 
-        // Set up file watching
-        let (tx, rx) = mpsc::channel();
-        self.watcher.watch(&temp_file, tx)?;
+        // The workspace is removed whether or not the session ended cleanly
+        let finished = self.finish(&mut running, challenge);
+        let cleaned_up = self.filesystem.cleanup(&running.workspace);
+        let solved_at = finished?;
+        cleaned_up?;
 
-        // Prepare recording if available
-        let recording_path = if self.recorder.is_some() {
-            use crate::infrastructure::AsciinemaRecorder;
-            Some(AsciinemaRecorder::generate_recording_path(challenge.id())?)
+        let completed = solved_at.is_some();
+        let elapsed = solved_at.unwrap_or_else(|| running.start_time.elapsed());
+
+        // Build solution
+        let mut solution = if completed {
+            Solution::completed(elapsed)
         } else {
-            None
+            Solution::incomplete(elapsed)
         };
 
-        // Start timer and spawn editor (with or without recording)
-        let start_time = Instant::now();
-        let mut recording_process = None;
+        // Keys sent to close the editor come after the solve and are not counted
+        if let Some(recording) = self.finalize_recording(running.recording_path, completed.then_some(elapsed)) {
+            solution = solution.with_recording(recording);
+        }
+
+        Ok(solution)
+    }
+
+    /// Waits for the solve or the editor's exit, then closes the editor and
+    /// stops watching; returns when it was solved, if it was
+    fn finish(&mut self, running: &mut RunningEditor, challenge: &Challenge) -> Result<Option<Duration>> {
+        let solved_at =
+            self.wait_for_solution(&running.events, &running.workspace, challenge, running.start_time)?;
 
-        if let (Some(recorder), Some(rec_path)) = (self.recorder.as_mut(), &recording_path) {
-            // Spawn with recording
-            let child = recorder.start_recording(&temp_file, rec_path)?;
-            recording_process = Some(child);
+        // Close the editor ourselves once solved; otherwise it already exited
+        if solved_at.is_some() {
+            running.session.close()?;
         } else {
-            // Spawn without recording
-            self.editor.spawn(&temp_file)?;
+            running.session.wait()?;
+        }
+        self.watcher.stop()?;
+        Ok(solved_at)
+    }
+
+    /// Opens the files in the editor and keeps whatever the user leaves in
+    /// them, for authoring a challenge by doing it once
+    ///
+    /// Runs until the editor exits. Keys after the last save (quitting the
+    /// editor) are left out of the recording.
+    pub fn capture(&mut self, files: &[ChallengeFile], id: &str) -> Result<CapturedEdit> {
+        let mut running = self.launch(files, id)?;
+
+        let captured = self.wait_and_read(&mut running);
+        let cleaned_up = self.filesystem.cleanup(&running.workspace);
+        let (last_change, contents) = captured?;
+        cleaned_up?;
+
+        Ok(CapturedEdit {
+            contents,
+            last_change,
+            recording: self.finalize_recording(running.recording_path, last_change),
+        })
+    }
+
+    /// Waits for the editor to exit and reads back every workspace file
+    fn wait_and_read(&mut self, running: &mut RunningEditor) -> Result<(Option<Duration>, Vec<String>)> {
+        let last_change = Self::wait_for_exit(&running.events, running.start_time)?;
+        running.session.wait()?;
+        self.watcher.stop()?;
+
+        let contents = running
+            .workspace
+            .files()
+            .iter()
+            .map(|path| self.filesystem.read_file(path))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((last_change, contents))
+    }
+
+    /// Creates the workspace, starts watching it and spawns the (recorded) editor
+    ///
+    /// The workspace is removed again if the editor cannot be started.
+    fn launch(&mut self, files: &[ChallengeFile], id: &str) -> Result<RunningEditor> {
+        // Create workspace with the starting content of every file
+        let workspace = self.filesystem.create_workspace(files)?;
+
+        let started = self.start_editor(workspace.clone(), id);
+        if started.is_err() {
+            let _ = self.filesystem.cleanup(&workspace);
+        }
+        started
+    }
+
+    /// Starts watching the workspace and spawns the (recorded) editor on it
+    fn start_editor(&mut self, workspace: Workspace, id: &str) -> Result<RunningEditor> {
+        // File changes and editor exit are delivered on a single channel
+        let (tx, events) = mpsc::channel();
+        self.watcher.watch(workspace.root(), tx.clone())?;
+
+        // Prepare recording if available
+        let recording_path = self
+            .recorder
+            .as_ref()
+            .map(|recorder| recorder.recording_path(id))
+            .transpose()?;
+
+        // Wrap the editor in the recorder if available
+        let mut command = self.editor.launch_command(&workspace)?;
+        if let (Some(recorder), Some(rec_path)) = (self.recorder.as_ref(), &recording_path) {
+            command = recorder.wrap_command(&command, rec_path);
+        }
+
+        // Start timer and spawn editor
+        let start_time = Instant::now();
+        let session = self.editor.spawn(&command, tx)?;
+
+        Ok(RunningEditor {
+            workspace,
+            recording_path,
+            session,
+            events,
+            start_time,
+        })
+    }
+
+    /// Parses the recording, if there is one; a failure is only a warning
+    fn finalize_recording(&self, recording_path: Option<PathBuf>, cutoff: Option<Duration>) -> Option<Recording> {
+        let (recorder, rec_path) = (self.recorder.as_ref()?, recording_path?);
+        match recorder.finalize_recording(&rec_path, cutoff) {
+            Ok(recording) => Some(recording),
+            Err(e) => {
+                eprintln!("Warning: Failed to finalize recording: {}", e);
+                None
+            }
         }
+    }
+
+    /// Blocks until the editor exits, returning the time of the last file change
+    fn wait_for_exit(rx: &mpsc::Receiver<RunnerEvent>, start_time: Instant) -> Result<Option<Duration>> {
+        let mut last_change = None;
+        loop {
+            match rx.recv().context("Event channel closed unexpectedly")? {
+                RunnerEvent::FileChanged => last_change = Some(start_time.elapsed()),
+                RunnerEvent::EditorExited => return Ok(last_change),
+            }
+        }
+    }
+
+    /// Blocks on runner events until the challenge is solved or the editor exits
+    ///
+    /// Returns the time from start to the file change that solved the
+    /// challenge, or None if the editor exited first. A final check runs on
+    /// exit so a save-and-quit in one command still counts.
+    fn wait_for_solution(
+        &self,
+        rx: &mpsc::Receiver<RunnerEvent>,
+        workspace: &Workspace,
+        challenge: &Challenge,
+        start_time: Instant,
+    ) -> Result<Option<Duration>> {
+        let mut last_change = None;
 
-        // Wait for file changes and validate
-        let mut completed = false;
         loop {
-            // Check if process is still running
-            let is_running = if let Some(child) = recording_process.as_mut() {
-                child.try_wait()?.is_none()
-            } else {
-                self.editor.is_running()
-            };
-
-            if !is_running {
-                break;
+            let event = rx.recv().context("Event channel closed unexpectedly")?;
+            let mut editor_exited = event == RunnerEvent::EditorExited;
+            if event == RunnerEvent::FileChanged {
+                last_change = Some(start_time.elapsed());
             }
 
-            // Check for file change notifications
-            if rx.try_recv().is_ok() {
-                // Read current content
-                let current_content = self.filesystem.read_file(&temp_file)?;
-
-                // Validate against target
-                if self
-                    .validator
-                    .is_valid(&current_content, challenge.target_content())
-                {
-                    completed = true;
+            // Debounce: collect the rest of a save burst before reading files
+            let burst_start = Instant::now();
+            while burst_start.elapsed() < MAX_BURST {
+                let Ok(event) = rx.recv_timeout(DEBOUNCE) else {
                     break;
+                };
+                match event {
+                    RunnerEvent::FileChanged => last_change = Some(start_time.elapsed()),
+                    RunnerEvent::EditorExited => editor_exited = true,
+                }
+            }
+
+            if let Some(changed_at) = last_change {
+                if self.is_solved(workspace, challenge) {
+                    return Ok(Some(changed_at));
                 }
             }
 
-            // Small sleep to avoid busy waiting
-            std::thread::sleep(std::time::Duration::from_millis(100));
+            if editor_exited {
+                return Ok(None);
+            }
         }
+    }
 
-        let elapsed = start_time.elapsed();
+    /// Checks whether every workspace file matches its target
+    ///
+    /// A file that cannot be read (e.g. mid-save) counts as not matching yet.
+    fn is_solved(&self, workspace: &Workspace, challenge: &Challenge) -> bool {
+        workspace
+            .files()
+            .iter()
+            .zip(challenge.files())
+            .all(|(path, file)| {
+                self.filesystem
+                    .read_file(path)
+                    .is_ok_and(|content| self.validator.is_valid(&content, file.target_content()))
+            })
+    }
+}
 
-        // Cleanup editor process
-        if let Some(mut child) = recording_process {
-            let _ = child.wait(); // Wait for asciinema to finish
-        } else {
-            self.editor.terminate()?;
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    struct NoEditor;
+
+    impl EditorSpawner for NoEditor {
+        fn launch_command(&mut self, workspace: &Workspace) -> Result<LaunchCommand> {
+            Ok(LaunchCommand::new("true", workspace.root()))
         }
-        self.watcher.stop()?;
-        self.filesystem.cleanup(&temp_file)?;
 
-        // Build solution
-        let mut solution = if completed {
-            Solution::completed(elapsed)
-        } else {
-            Solution::incomplete(elapsed)
-        };
+        fn spawn(
+            &mut self,
+            _command: &LaunchCommand,
+            _events: mpsc::Sender<RunnerEvent>,
+        ) -> Result<Box<dyn EditorSession>> {
+            anyhow::bail!("not used")
+        }
+    }
 
-        // Attach recording if available
-        if let (Some(recorder), Some(rec_path)) = (self.recorder.as_ref(), recording_path) {
-            match recorder.finalize_recording(&rec_path) {
-                Ok(recording) => {
-                    solution = solution.with_recording(recording);
-                }
-                Err(e) => {
-                    eprintln!("Warning: Failed to finalize recording: {}", e);
-                }
-            }
+    /// Editor that exits straight away and then fails to restore the terminal
+    struct BrokenEditor;
+
+    struct BrokenSession;
+
+    impl EditorSession for BrokenSession {
+        fn close(&mut self) -> Result<()> {
+            anyhow::bail!("close failed")
         }
 
-        Ok(solution)
+        fn wait(&mut self) -> Result<()> {
+            anyhow::bail!("wait failed")
+        }
+    }
+
+    impl EditorSpawner for BrokenEditor {
+        fn launch_command(&mut self, workspace: &Workspace) -> Result<LaunchCommand> {
+            Ok(LaunchCommand::new("true", workspace.root()))
+        }
+
+        fn spawn(
+            &mut self,
+            _command: &LaunchCommand,
+            events: mpsc::Sender<RunnerEvent>,
+        ) -> Result<Box<dyn EditorSession>> {
+            events.send(RunnerEvent::EditorExited).unwrap();
+            Ok(Box::new(BrokenSession))
+        }
+    }
+
+    struct NoWatcher;
+
+    impl FileWatcher for NoWatcher {
+        fn watch(&mut self, _path: &Path, _tx: mpsc::Sender<RunnerEvent>) -> Result<()> {
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// File system whose single file always holds `content`
+    struct FakeFileSystem {
+        content: String,
+        cleanups: Cell<usize>,
+    }
+
+    impl FileSystem for FakeFileSystem {
+        fn create_workspace(&self, _files: &[ChallengeFile]) -> Result<Workspace> {
+            Ok(workspace())
+        }
+
+        fn read_file(&self, _path: &Path) -> Result<String> {
+            Ok(self.content.clone())
+        }
+
+        fn cleanup(&self, _workspace: &Workspace) -> Result<()> {
+            self.cleanups.set(self.cleanups.get() + 1);
+            Ok(())
+        }
+    }
+
+    fn workspace() -> Workspace {
+        Workspace::new(PathBuf::from("/tmp/dojo"), vec![PathBuf::from("/tmp/dojo/a.txt")])
+    }
+
+    fn filesystem(content: &str) -> FakeFileSystem {
+        FakeFileSystem {
+            content: content.to_string(),
+            cleanups: Cell::new(0),
+        }
+    }
+
+    fn runner(content: &str) -> ChallengeRunner<NoEditor, NoWatcher, FakeFileSystem> {
+        ChallengeRunner::new(NoEditor, NoWatcher, filesystem(content))
+    }
+
+    #[test]
+    fn test_workspace_is_removed_when_the_editor_fails_to_start() {
+        let challenge = Challenge::new("id", "Title", "Desc", "start", "target", "hint");
+        let mut runner = runner("start");
+
+        assert!(runner.run(&challenge).is_err());
+        assert_eq!(runner.filesystem.cleanups.get(), 1);
+    }
+
+    #[test]
+    fn test_workspace_is_removed_when_the_session_fails() {
+        let challenge = Challenge::new("id", "Title", "Desc", "start", "target", "hint");
+        let mut runner = ChallengeRunner::new(BrokenEditor, NoWatcher, filesystem("start"));
+        assert!(runner.run(&challenge).is_err());
+        assert_eq!(runner.filesystem.cleanups.get(), 1);
+
+        let mut runner = ChallengeRunner::new(BrokenEditor, NoWatcher, filesystem("start"));
+        assert!(runner.capture(challenge.files(), challenge.id()).is_err());
+        assert_eq!(runner.filesystem.cleanups.get(), 1);
+    }
+
+    #[test]
+    fn test_save_and_quit_in_one_burst_counts_as_solved() {
+        let challenge = Challenge::new("id", "Title", "Desc", "start", "target", "hint");
+        let runner = runner("target");
+        let (tx, rx) = mpsc::channel();
+        tx.send(RunnerEvent::FileChanged).unwrap();
+        tx.send(RunnerEvent::EditorExited).unwrap();
+
+        let solved_at = runner
+            .wait_for_solution(&rx, &workspace(), &challenge, Instant::now())
+            .unwrap();
+        assert!(solved_at.is_some());
+    }
+
+    #[test]
+    fn test_wait_for_exit_keeps_last_change() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(RunnerEvent::EditorExited).unwrap();
+        let unchanged = ChallengeRunner::<NoEditor, NoWatcher, FakeFileSystem>::wait_for_exit(&rx, Instant::now());
+        assert_eq!(unchanged.unwrap(), None);
+
+        tx.send(RunnerEvent::FileChanged).unwrap();
+        tx.send(RunnerEvent::EditorExited).unwrap();
+        let changed = ChallengeRunner::<NoEditor, NoWatcher, FakeFileSystem>::wait_for_exit(&rx, Instant::now());
+        assert!(changed.unwrap().is_some());
+    }
+
+    #[test]
+    fn test_exit_without_solution() {
+        let challenge = Challenge::new("id", "Title", "Desc", "start", "target", "hint");
+        let runner = runner("start");
+        let (tx, rx) = mpsc::channel();
+        tx.send(RunnerEvent::FileChanged).unwrap();
+        tx.send(RunnerEvent::EditorExited).unwrap();
+
+        let solved_at = runner
+            .wait_for_solution(&rx, &workspace(), &challenge, Instant::now())
+            .unwrap();
+        assert!(solved_at.is_none());
     }
 }
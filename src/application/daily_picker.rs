@@ -0,0 +1,64 @@
+use crate::domain::fnv::fnv1a;
+use crate::domain::Challenge;
+use chrono::NaiveDate;
+
+/// Service choosing the daily challenge
+///
+/// The pick depends only on the date and the ids of the candidates, so
+/// everyone choosing among the same challenges gets the same one on the
+/// same day.
+pub struct DailyPicker;
+
+impl DailyPicker {
+    /// Seed for a date: FNV-1a of its ISO form (e.g. "2025-06-01")
+    pub fn seed(date: NaiveDate) -> u64 {
+        fnv1a(date.to_string().bytes())
+    }
+
+    /// The daily challenge for a date, independent of load order
+    pub fn pick(challenges: &[Challenge], date: NaiveDate) -> Option<&Challenge> {
+        let mut by_id: Vec<&Challenge> = challenges.iter().collect();
+        by_id.sort_by(|a, b| a.id().cmp(b.id()));
+
+        let index = (Self::seed(date) % by_id.len().max(1) as u64) as usize;
+        by_id.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenges(ids: &[&str]) -> Vec<Challenge> {
+        ids.iter()
+            .map(|id| Challenge::new(*id, "T", "D", "a", "b", "h"))
+            .collect()
+    }
+
+    #[test]
+    fn test_pick_depends_on_date_not_load_order() {
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let forward = challenges(&["a", "b", "c", "d", "e"]);
+        let backward = challenges(&["e", "d", "c", "b", "a"]);
+
+        let picked = DailyPicker::pick(&forward, date).unwrap().id();
+        assert_eq!(picked, DailyPicker::pick(&backward, date).unwrap().id());
+
+        // Not the same challenge every day
+        let week: Vec<&str> = (1..=7)
+            .map(|d| {
+                let date = NaiveDate::from_ymd_opt(2025, 6, d).unwrap();
+                DailyPicker::pick(&forward, date).unwrap().id()
+            })
+            .collect();
+        assert!(week.iter().any(|id| *id != picked));
+    }
+
+    #[test]
+    fn test_seed_is_stable() {
+        // Changing the seed changes everyone's daily challenge history
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        assert_eq!(DailyPicker::seed(date), 0x35cd_9d78_f2da_a5f9);
+        assert_eq!(DailyPicker::pick(&[], date).map(Challenge::id), None);
+    }
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+/// Events the challenge runner reacts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunnerEvent {
+    /// A file in the workspace was created, written or renamed
+    FileChanged,
+    /// The editor (or the recorder wrapping it) exited
+    EditorExited,
+}
+
+/// A running editor session
+///
+/// The session reports its own exit by sending `RunnerEvent::EditorExited`
+/// on the channel it was spawned with, so the runner never polls for it.
+pub trait EditorSession {
+    /// Closes the editor once the challenge is solved
+    ///
+    /// Implementations escalate from the editor's own quit command to
+    /// SIGTERM and finally SIGKILL, then restore the terminal.
+    fn close(&mut self) -> Result<()>;
+
+    /// Waits for an editor that is exiting on its own, then restores the terminal
+    fn wait(&mut self) -> Result<()>;
+}
@@ -0,0 +1,154 @@
+use crate::application::shuffle::shuffle;
+use crate::application::skill_profiler::MIN_SKILL_CHALLENGES;
+use crate::domain::{Challenge, GauntletSelection, LearningPath, Progress};
+use std::collections::BTreeMap;
+
+/// Challenges in the preset random and per-tag gauntlets
+const PRESET_LEGS: [usize; 2] = [5, 10];
+
+/// Service choosing the challenges of a gauntlet
+pub struct GauntletPlanner;
+
+impl GauntletPlanner {
+    /// Gauntlets offered by default: random runs, one per skill tag and one
+    /// per learning path stage
+    pub fn presets(challenges: &[Challenge], paths: &[LearningPath]) -> Vec<GauntletSelection> {
+        let mut presets: Vec<GauntletSelection> = PRESET_LEGS
+            .iter()
+            .map(|&count| GauntletSelection::Random { count })
+            .collect();
+
+        let mut tag_counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for tag in challenges.iter().flat_map(Challenge::tags) {
+            *tag_counts.entry(tag.as_str()).or_default() += 1;
+        }
+        presets.extend(
+            tag_counts
+                .into_iter()
+                .filter(|(_, count)| *count >= MIN_SKILL_CHALLENGES)
+                .map(|(tag, count)| GauntletSelection::Tag {
+                    tag: tag.to_string(),
+                    count: count.min(PRESET_LEGS[0]),
+                }),
+        );
+
+        presets.extend(paths.iter().flat_map(|path| {
+            path.stages().iter().map(|stage| GauntletSelection::Stage {
+                path: path.name().to_string(),
+                stage: stage.name().to_string(),
+                challenge_ids: stage.challenge_ids().to_vec(),
+            })
+        }));
+        presets
+    }
+
+    /// Challenges for a gauntlet, in the order they are run
+    ///
+    /// Only unlocked challenges are run. Random and tag gauntlets draw from
+    /// them using `seed`; stages run in order, skipping ids that aren't loaded.
+    pub fn pick<'a>(
+        selection: &GauntletSelection,
+        challenges: &'a [Challenge],
+        progress: &Progress,
+        seed: u64,
+    ) -> Vec<&'a Challenge> {
+        let (mut pool, count): (Vec<&Challenge>, usize) = match selection {
+            GauntletSelection::Random { count } => (challenges.iter().collect(), *count),
+            GauntletSelection::Tag { tag, count } => (
+                challenges
+                    .iter()
+                    .filter(|challenge| challenge.tags().contains(tag))
+                    .collect(),
+                *count,
+            ),
+            GauntletSelection::Stage { challenge_ids, .. } => {
+                return challenge_ids
+                    .iter()
+                    .filter_map(|id| challenges.iter().find(|challenge| challenge.id() == id))
+                    .filter(|challenge| !progress.is_locked(challenge, challenges))
+                    .collect();
+            }
+        };
+
+        pool.retain(|challenge| !progress.is_locked(challenge, challenges));
+        shuffle(&mut pool, seed);
+        pool.truncate(count);
+        pool
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{MasteryTier, PathStage};
+
+    fn challenges() -> Vec<Challenge> {
+        (1..=8)
+            .map(|n| {
+                let tag = if n % 2 == 0 { "even" } else { "odd" };
+                Challenge::new(format!("c{}", n), "T", "D", "a", "b", "h")
+                    .with_tags(vec![tag.to_string()])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_presets_cover_random_tags_and_stages() {
+        let paths = vec![LearningPath::new(
+            "Basics",
+            vec![PathStage::new("Warm-up", vec!["c1".to_string(), "c2".to_string()])],
+        )];
+        let names: Vec<String> = GauntletPlanner::presets(&challenges(), &paths)
+            .iter()
+            .map(GauntletSelection::name)
+            .collect();
+        assert_eq!(names, ["Random ×5", "Random ×10", "even ×4", "odd ×4", "Basics: Warm-up"]);
+    }
+
+    #[test]
+    fn test_pick_is_deterministic_per_seed_and_respects_tags() {
+        let challenges = challenges();
+        let progress = Progress::new();
+        let selection = GauntletSelection::Tag { tag: "even".to_string(), count: 3 };
+
+        let ids = |seed| -> Vec<String> {
+            GauntletPlanner::pick(&selection, &challenges, &progress, seed)
+                .iter()
+                .map(|challenge| challenge.id().to_string())
+                .collect()
+        };
+        let first = ids(42);
+        assert_eq!(first, ids(42));
+        assert_eq!(first.len(), 3);
+        assert!(first.iter().all(|id| ["c2", "c4", "c6", "c8"].contains(&id.as_str())));
+    }
+
+    #[test]
+    fn test_pick_skips_locked_and_keeps_stage_order() {
+        let mut challenges = challenges();
+        challenges[0] = challenges[0]
+            .clone()
+            .with_requirements(vec!["c2".to_string()], MasteryTier::Bronze);
+        let progress = Progress::new();
+
+        let random = GauntletPlanner::pick(&GauntletSelection::Random { count: 10 }, &challenges, &progress, 7);
+        assert_eq!(random.len(), 7);
+        assert!(random.iter().all(|challenge| challenge.id() != "c1"));
+
+        let stage = GauntletSelection::Stage {
+            path: "Path".to_string(),
+            stage: "Set".to_string(),
+            challenge_ids: vec![
+                "c4".to_string(),
+                "c1".to_string(),
+                "missing".to_string(),
+                "c3".to_string(),
+            ],
+        };
+        let ids: Vec<&str> = GauntletPlanner::pick(&stage, &challenges, &progress, 7)
+            .iter()
+            .map(|challenge| challenge.id())
+            .collect();
+        assert_eq!(ids, ["c4", "c3"]);
+    }
+}
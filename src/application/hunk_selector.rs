@@ -0,0 +1,224 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::application::validator::SolutionValidator;
+use crate::domain::{Challenge, ChallengeFile, DiffHunk};
+
+/// Most lines a hunk may remove or add to count as one exercise
+const MAX_CHANGED_LINES: usize = 3;
+/// Most lines (context included) a hunk may span
+const MAX_HUNK_LINES: usize = 12;
+/// Longest line that still fits comfortably in the editor
+const MAX_LINE_LENGTH: usize = 100;
+/// Longest commit subject kept in a challenge title
+const MAX_TITLE_LENGTH: usize = 50;
+/// Most exercises taken from one commit, so a run spans several commits
+const MAX_PER_COMMIT: usize = 3;
+
+/// Language tag for each source file extension we turn into exercises
+const LANGUAGES: &[(&str, &str)] = &[
+    ("rs", "rust"),
+    ("py", "python"),
+    ("js", "javascript"),
+    ("jsx", "javascript"),
+    ("ts", "typescript"),
+    ("tsx", "typescript"),
+    ("go", "go"),
+    ("java", "java"),
+    ("kt", "kotlin"),
+    ("cs", "csharp"),
+    ("c", "c"),
+    ("h", "c"),
+    ("cpp", "cpp"),
+    ("hpp", "cpp"),
+    ("rb", "ruby"),
+    ("php", "php"),
+    ("swift", "swift"),
+    ("scala", "scala"),
+    ("sh", "shell"),
+    ("lua", "lua"),
+    ("ex", "elixir"),
+    ("hs", "haskell"),
+    ("sql", "sql"),
+    ("html", "html"),
+    ("css", "css"),
+    ("toml", "toml"),
+    ("yaml", "yaml"),
+    ("yml", "yaml"),
+    ("json", "json"),
+    ("md", "markdown"),
+];
+
+/// Service picking diff hunks that make good editing exercises and turning
+/// them into challenges
+///
+/// A good exercise edits existing text: it changes or deletes at most a few
+/// lines of a source file, has some surrounding context, and makes a change
+/// the validator can see (not just whitespace). Pure additions are skipped
+/// since they test typing rather than editing.
+pub struct HunkSelector;
+
+impl HunkSelector {
+    /// Up to `max` challenges from the hunks, in order, skipping duplicates
+    /// and taking at most a few from each commit
+    pub fn select(hunks: &[DiffHunk], max: usize) -> Vec<Challenge> {
+        let mut seen = HashSet::new();
+        let mut per_commit: HashMap<&str, usize> = HashMap::new();
+
+        hunks
+            .iter()
+            .filter(|hunk| Self::is_exercise(hunk))
+            .filter(|hunk| seen.insert((hunk.before(), hunk.after())))
+            .filter_map(|hunk| {
+                // Number the hunks of each commit for stable ids
+                let index = per_commit.entry(hunk.commit()).or_default();
+                *index += 1;
+                (*index <= MAX_PER_COMMIT).then(|| Self::to_challenge(hunk, *index))
+            })
+            .take(max)
+            .collect()
+    }
+
+    pub fn is_exercise(hunk: &DiffHunk) -> bool {
+        let removed = hunk.removed_count();
+        let added = hunk.added_count();
+        let before = hunk.before();
+        let after = hunk.after();
+
+        Self::language(hunk.path()).is_some()
+            && removed > 0
+            && removed <= MAX_CHANGED_LINES
+            && added <= MAX_CHANGED_LINES
+            && hunk.lines().len() <= MAX_HUNK_LINES
+            && before.lines().chain(after.lines()).all(|line| line.chars().count() <= MAX_LINE_LENGTH)
+            && !SolutionValidator::new().is_valid(&before, &after)
+    }
+
+    pub fn to_challenge(hunk: &DiffHunk, index: usize) -> Challenge {
+        let file_name = Path::new(hunk.path())
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(hunk.path());
+
+        let mut subject: String = hunk.subject().chars().take(MAX_TITLE_LENGTH).collect();
+        if hunk.subject().chars().count() > MAX_TITLE_LENGTH {
+            subject.push('…');
+        }
+        let title = if subject.is_empty() {
+            format!("Edit {}", file_name)
+        } else {
+            format!("{}: {}", file_name, subject)
+        };
+
+        let (kind, difficulty) = match (hunk.added_count(), hunk.removed_count()) {
+            (0, 1) => ("deletion", "beginner"),
+            (0, _) => ("deletion", "intermediate"),
+            (1, 1) => ("change", "beginner"),
+            _ => ("change", "intermediate"),
+        };
+        let mut tags = vec!["git-history".to_string(), kind.to_string()];
+        if let Some(language) = Self::language(hunk.path()) {
+            tags.push(language.to_string());
+        }
+
+        Challenge::new(
+            format!("git-{}-{}", hunk.commit(), index),
+            title,
+            format!(
+                "Make the change from commit {} to {}",
+                hunk.commit(),
+                hunk.path()
+            ),
+            "",
+            "",
+            "Compare the text with the target and repeat the change",
+        )
+        .with_files(vec![ChallengeFile::new(file_name, hunk.before(), hunk.after())])
+        .with_difficulty(difficulty)
+        .with_tags(tags)
+    }
+
+    fn language(path: &str) -> Option<&'static str> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+        LANGUAGES
+            .iter()
+            .find(|(ext, _)| *ext == extension)
+            .map(|(_, language)| *language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::HunkLine;
+
+    fn hunk(commit: &str, path: &str, lines: Vec<HunkLine>) -> DiffHunk {
+        DiffHunk::new(commit, "Rename total to sum", path, lines)
+    }
+
+    fn rename(commit: &str, path: &str) -> DiffHunk {
+        hunk(
+            commit,
+            path,
+            vec![
+                HunkLine::Context("fn main() {".to_string()),
+                HunkLine::Removed("    let total = 1;".to_string()),
+                HunkLine::Added("    let sum = 1;".to_string()),
+                HunkLine::Context("}".to_string()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_is_exercise() {
+        assert!(HunkSelector::is_exercise(&rename("a", "src/lib.rs")));
+        assert!(!HunkSelector::is_exercise(&rename("a", "Cargo.lock")));
+
+        let addition = hunk("a", "lib.rs", vec![HunkLine::Added("fn new() {}".to_string())]);
+        assert!(!HunkSelector::is_exercise(&addition));
+
+        let whitespace = hunk(
+            "a",
+            "lib.rs",
+            vec![
+                HunkLine::Removed("let x = 1;".to_string()),
+                HunkLine::Added("    let x = 1;".to_string()),
+            ],
+        );
+        assert!(!HunkSelector::is_exercise(&whitespace));
+
+        let large = hunk(
+            "a",
+            "lib.rs",
+            (0..5).map(|i| HunkLine::Removed(format!("line {}", i))).collect(),
+        );
+        assert!(!HunkSelector::is_exercise(&large));
+    }
+
+    #[test]
+    fn test_select_numbers_and_dedups() {
+        let hunks = vec![
+            rename("abc1234", "src/lib.rs"),
+            rename("abc1234", "src/main.rs"),
+            hunk(
+                "abc1234",
+                "src/main.rs",
+                vec![
+                    HunkLine::Removed("let a = 1;".to_string()),
+                    HunkLine::Removed("let b = 2;".to_string()),
+                ],
+            ),
+        ];
+        let challenges = HunkSelector::select(&hunks, 10);
+
+        assert_eq!(challenges.len(), 2);
+        assert_eq!(challenges[0].id(), "git-abc1234-1");
+        assert_eq!(challenges[1].id(), "git-abc1234-2");
+        assert_eq!(challenges[0].files()[0].path(), "lib.rs");
+        assert_eq!(challenges[0].tags(), ["git-history", "change", "rust"]);
+        assert_eq!(challenges[1].tags()[1], "deletion");
+        assert_eq!(challenges[1].difficulty(), Some("intermediate"));
+        assert_eq!(challenges[0].title(), "lib.rs: Rename total to sum");
+        assert_eq!(HunkSelector::select(&hunks, 1).len(), 1);
+    }
+}
@@ -1,10 +1,29 @@
 pub mod challenge_runner;
+pub mod editor_process;
 pub mod validator;
 pub mod progress_repository;
 pub mod progress_tracker;
 pub mod achievement_checker;
+pub mod review_scheduler;
+pub mod skill_profiler;
+pub mod gauntlet_planner;
+pub mod daily_picker;
+pub mod shuffle;
+pub mod surface_randomizer;
+pub mod challenge_generator;
+pub mod hunk_selector;
 
-pub use challenge_runner::{ChallengeRunner, EditorSpawner, FileSystem, FileWatcher};
+pub use challenge_runner::{
+    ChallengeRunner, EditorSpawner, FileSystem, FileWatcher, LaunchCommand, Workspace,
+};
+pub use editor_process::{EditorSession, RunnerEvent};
 pub use progress_repository::ProgressRepository;
 pub use progress_tracker::ProgressTracker;
 pub use achievement_checker::AchievementChecker;
+pub use review_scheduler::ReviewScheduler;
+pub use skill_profiler::SkillProfiler;
+pub use gauntlet_planner::GauntletPlanner;
+pub use daily_picker::DailyPicker;
+pub use surface_randomizer::SurfaceRandomizer;
+pub use challenge_generator::ChallengeGenerator;
+pub use hunk_selector::HunkSelector;
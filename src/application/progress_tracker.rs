@@ -1,7 +1,10 @@
-use crate::application::{AchievementChecker, ProgressRepository};
-use crate::domain::{Achievement, ChallengeStats, Progress, Solution};
+use crate::application::{AchievementChecker, ProgressRepository, ReviewScheduler, SkillProfiler};
+use crate::domain::{
+    Achievement, Attempt, BlindMode, Challenge, ChallengeStats, ConfigMode, DailyResult,
+    GauntletResult, Progress, Score, SkillLevel, Solution,
+};
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use std::sync::{Arc, Mutex};
 
 /// Application service for tracking and managing user progress
@@ -26,25 +29,136 @@ impl<R: ProgressRepository> ProgressTracker<R> {
     }
 
     /// Record a challenge attempt
-    pub fn record_solution(&self, challenge_id: &str, solution: &Solution) -> Result<()> {
+    ///
+    /// Attempts made with the user's own editor config are kept separate
+    /// from the vanilla records. Penalties count towards the recorded time.
+    /// Each attempt is scored against the challenge's thresholds, and
+    /// vanilla attempts at completed challenges reschedule their review.
+    pub fn record_solution(&self, challenge: &Challenge, solution: &Solution) -> Result<()> {
+        self.record_variant_solution(challenge, challenge, solution)
+    }
+
+    /// Record an attempt at a variant of a challenge, such as a drill run
+    /// with randomized text
+    ///
+    /// The attempt is scored against the variant it was made on, but stored
+    /// with the content version of the challenge as loaded, so variants
+    /// never make the challenge's earlier results look outdated.
+    pub fn record_variant_solution(
+        &self,
+        challenge: &Challenge,
+        variant: &Challenge,
+        solution: &Solution,
+    ) -> Result<()> {
         let mut progress = self.progress.lock().unwrap();
+        let challenge_id = challenge.id();
+        let thresholds = challenge.tier_thresholds();
+        let attempt = Self::scored_attempt(variant, solution);
+
+        match solution.config_mode() {
+            ConfigMode::Vanilla => {
+                progress.record(challenge_id.to_string(), attempt.clone());
+                let today = attempt.attempted_at().date_naive();
+                ReviewScheduler::record_review(&mut progress, challenge_id, &attempt, &thresholds, today);
+            }
+            ConfigMode::Personal => {
+                progress.record_personal_config(challenge_id.to_string(), attempt)
+            }
+        }
+        progress.set_tier_thresholds(challenge_id, thresholds);
+        progress.set_content_version(challenge_id, &challenge.content_version());
 
-        let keystrokes = solution
-            .recording()
-            .map(|r| r.keystroke_count() as u32);
+        self.repository.save(&progress)?;
+        Ok(())
+    }
+
+    /// Record the day's scored daily challenge attempt
+    ///
+    /// Returns the stored result, or None if the day was already played.
+    pub fn record_daily(
+        &self,
+        challenge: &Challenge,
+        solution: &Solution,
+        date: NaiveDate,
+    ) -> Result<Option<DailyResult>> {
+        let attempt = Self::scored_attempt(challenge, solution);
+        let result = DailyResult::new(
+            date,
+            challenge.id(),
+            attempt.is_completed(),
+            attempt.time(),
+            attempt.keystrokes(),
+            attempt.score().unwrap_or_default(),
+        );
+
+        let mut progress = self.progress.lock().unwrap();
+        if !progress.record_daily(result.clone()) {
+            return Ok(None);
+        }
+        self.repository.save(&progress)?;
+        Ok(Some(result))
+    }
 
-        progress.record_attempt(
-            challenge_id.to_string(),
+    /// Attempt for a solution, scored against the challenge's thresholds
+    ///
+    /// Keystrokes made in an editor the optimal solution wasn't written for
+    /// are left out, so they set no keystroke bests or tiers.
+    fn scored_attempt(challenge: &Challenge, solution: &Solution) -> Attempt {
+        let attempt = Attempt::new(
             solution.is_completed(),
-            solution.elapsed_time(),
-            keystrokes,
+            solution.scored_time(),
+            solution.ranked_keystrokes(),
             Utc::now(),
-        );
+        )
+        .with_blind(solution.is_blind())
+        .with_hints_used(solution.hints_used());
+        let score = Score::calculate(&attempt, &challenge.tier_thresholds());
+        attempt.with_score(score)
+    }
 
+    /// Store a gauntlet run on its leaderboard
+    ///
+    /// Legs are not recorded as challenge attempts, so a gauntlet never
+    /// changes per-challenge bests, tiers or reviews.
+    pub fn record_gauntlet(&self, result: GauntletResult) -> Result<()> {
+        let mut progress = self.progress.lock().unwrap();
+        progress.record_gauntlet(result);
         self.repository.save(&progress)?;
         Ok(())
     }
 
+    /// Challenges due for spaced-repetition review today, most overdue first
+    pub fn reviews_due(&self, challenges: &[Challenge]) -> Vec<Challenge> {
+        let progress = self.progress.lock().unwrap();
+        ReviewScheduler::due_challenges(&progress, challenges, Utc::now().date_naive())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Per-tag skill levels, weakest first
+    pub fn skill_profile(&self, challenges: &[Challenge]) -> Vec<SkillLevel> {
+        let progress = self.progress.lock().unwrap();
+        SkillProfiler::profile(&progress, challenges, Utc::now())
+    }
+
+    /// The weakest skill and up to `limit` challenges to train it
+    pub fn weakest_skill(&self, challenges: &[Challenge], limit: usize) -> Option<(SkillLevel, Vec<Challenge>)> {
+        let progress = self.progress.lock().unwrap();
+        SkillProfiler::weakest_skill_challenges(&progress, challenges, Utc::now(), limit)
+            .map(|(skill, picked)| (skill, picked.into_iter().cloned().collect()))
+    }
+
+    /// Re-tier stored results against the loaded challenge definitions
+    pub fn refresh_tier_thresholds(&self, challenges: &[Challenge]) {
+        self.progress.lock().unwrap().apply_tier_thresholds(challenges);
+    }
+
+    /// Mark bests of challenges whose content changed since as legacy
+    pub fn refresh_content_versions(&self, challenges: &[Challenge]) {
+        self.progress.lock().unwrap().apply_content_versions(challenges);
+    }
+
     /// Get stats for a specific challenge
     pub fn get_challenge_stats(&self, challenge_id: &str) -> Option<ChallengeStats> {
         let progress = self.progress.lock().unwrap();
@@ -74,6 +188,22 @@ impl<R: ProgressRepository> ProgressTracker<R> {
         Ok(())
     }
 
+    /// Set which editor configuration sessions run with
+    pub fn set_config_mode(&self, config_mode: ConfigMode) -> Result<()> {
+        let mut progress = self.progress.lock().unwrap();
+        *progress = progress.clone().set_config_mode(config_mode);
+        self.repository.save(&progress)?;
+        Ok(())
+    }
+
+    /// Set how much of a challenge the brief reveals
+    pub fn set_blind_mode(&self, blind_mode: BlindMode) -> Result<()> {
+        let mut progress = self.progress.lock().unwrap();
+        *progress = progress.clone().set_blind_mode(blind_mode);
+        self.repository.save(&progress)?;
+        Ok(())
+    }
+
     /// Persist current progress to storage
     pub fn save(&self) -> Result<()> {
         let progress = self.progress.lock().unwrap();
@@ -83,7 +213,7 @@ impl<R: ProgressRepository> ProgressTracker<R> {
     /// Check for new achievements and update progress
     pub fn check_achievements(&self, total_challenges: usize) -> Result<Vec<Achievement>> {
         let mut progress = self.progress.lock().unwrap();
-        let newly_unlocked = AchievementChecker::check_achievements(&mut *progress, total_challenges);
+        let newly_unlocked = AchievementChecker::check_achievements(&mut progress, total_challenges);
 
         if !newly_unlocked.is_empty() {
             self.repository.save(&progress)?;
@@ -96,9 +226,15 @@ impl<R: ProgressRepository> ProgressTracker<R> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::Solution;
+    use crate::application::SurfaceRandomizer;
+    use crate::domain::{KeySequence, MasteryTier, Recording, Solution};
+    use std::path::PathBuf;
     use std::time::Duration;
 
+    fn challenge(id: &str) -> Challenge {
+        Challenge::new(id, "Title", "Desc", "a", "b", "hint")
+    }
+
     // Mock repository for testing
     struct MockRepository {
         progress: Mutex<Progress>,
@@ -133,13 +269,61 @@ mod tests {
         let tracker = ProgressTracker::new(repo).unwrap();
 
         let solution = Solution::completed(Duration::from_secs(10));
-        tracker.record_solution("test-1", &solution).unwrap();
+        tracker.record_solution(&challenge("test-1"), &solution).unwrap();
 
         let progress = tracker.get_progress();
         assert_eq!(progress.total_completed(), 1);
         assert_eq!(progress.total_practice_time(), Duration::from_secs(10));
     }
 
+    #[test]
+    fn test_record_personal_config_solution() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+
+        let solution = Solution::completed(Duration::from_secs(10))
+            .with_config_mode(ConfigMode::Personal);
+        tracker.record_solution(&challenge("test-1"), &solution).unwrap();
+
+        let progress = tracker.get_progress();
+        assert_eq!(progress.total_completed(), 0);
+        assert_eq!(progress.personal_config_completed(), 1);
+    }
+
+    #[test]
+    fn test_record_blind_solution_with_penalty() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+
+        let solution = Solution::completed(Duration::from_secs(10))
+            .with_blind(true)
+            .with_time_penalty(Duration::from_secs(15));
+        tracker.record_solution(&challenge("test-1"), &solution).unwrap();
+
+        let progress = tracker.get_progress();
+        let stats = progress.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_time(), None);
+        assert_eq!(stats.blind_best_time(), Some(Duration::from_secs(25)));
+    }
+
+    #[test]
+    fn test_unranked_keystrokes_set_no_keystroke_best() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+
+        let keys = KeySequence::new(vec!["w".to_string(), "d".to_string()]);
+        let solution = Solution::completed(Duration::from_secs(10))
+            .with_recording(Recording::new(PathBuf::from("run.cast"), keys))
+            .with_ranked_keystrokes(false);
+        tracker.record_solution(&challenge("test-1"), &solution).unwrap();
+
+        let progress = tracker.get_progress();
+        let stats = progress.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_time(), Some(Duration::from_secs(10)));
+        assert_eq!(stats.best_keystrokes(), None);
+        assert_eq!(stats.mastery_tier(), Some(MasteryTier::Bronze));
+    }
+
     #[test]
     fn test_is_new_record_first_attempt() {
         let repo = MockRepository::new();
@@ -152,13 +336,32 @@ mod tests {
         assert!(new_ks);
     }
 
+    #[test]
+    fn test_randomized_variants_keep_earlier_bests() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+        let original = Challenge::new("test-1", "Title", "Desc", "fn alpha() {}", "fn beta() {}", "hint");
+
+        let fast = Solution::completed(Duration::from_secs(5));
+        let variant = SurfaceRandomizer::randomize(&original, 1);
+        tracker.record_variant_solution(&original, &variant, &fast).unwrap();
+
+        let slow = Solution::completed(Duration::from_secs(9));
+        let variant = SurfaceRandomizer::randomize(&original, 2);
+        tracker.record_variant_solution(&original, &variant, &slow).unwrap();
+
+        let stats = tracker.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_time(), Some(Duration::from_secs(5)));
+        assert_eq!(stats.attempt_count(), 2);
+    }
+
     #[test]
     fn test_is_new_record_better_time() {
         let repo = MockRepository::new();
         let tracker = ProgressTracker::new(repo).unwrap();
 
         let first = Solution::completed(Duration::from_secs(10));
-        tracker.record_solution("test-1", &first).unwrap();
+        tracker.record_solution(&challenge("test-1"), &first).unwrap();
 
         let second = Solution::completed(Duration::from_secs(8));
         let (new_time, _) = tracker.is_new_record("test-1", &second);
@@ -172,11 +375,86 @@ mod tests {
         let tracker = ProgressTracker::new(repo).unwrap();
 
         let first = Solution::completed(Duration::from_secs(10));
-        tracker.record_solution("test-1", &first).unwrap();
+        tracker.record_solution(&challenge("test-1"), &first).unwrap();
 
         let second = Solution::completed(Duration::from_secs(12));
         let (new_time, _) = tracker.is_new_record("test-1", &second);
 
         assert!(!new_time);
     }
+
+    #[test]
+    fn test_record_solution_uses_challenge_thresholds() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+        let challenge = challenge("test-1").with_par_time(Duration::from_secs(60));
+
+        let solution = Solution::completed(Duration::from_secs(45));
+        tracker.record_solution(&challenge, &solution).unwrap();
+
+        let stats = tracker.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.thresholds(), &challenge.tier_thresholds());
+    }
+
+    #[test]
+    fn test_record_solution_scores_attempt() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+        let challenge = challenge("test-1").with_par_time(Duration::from_secs(20));
+
+        // No recording: full time points, no keystroke points
+        let solution = Solution::completed(Duration::from_secs(20));
+        tracker.record_solution(&challenge, &solution).unwrap();
+
+        let stats = tracker.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_score(), Some(Score::new(500)));
+    }
+
+    #[test]
+    fn test_record_hinted_solution() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+
+        let solution = Solution::completed(Duration::from_secs(10)).with_hints_used(1);
+        tracker.record_solution(&challenge("test-1"), &solution).unwrap();
+
+        let stats = tracker.get_challenge_stats("test-1").unwrap();
+        assert!(stats.is_completed());
+        assert!(!stats.is_solved_without_hints());
+    }
+
+    #[test]
+    fn test_daily_is_scored_once_per_day_apart_from_practice() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+        let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+        let solution = Solution::completed(Duration::from_secs(10));
+        let result = tracker.record_daily(&challenge("test-1"), &solution, today).unwrap();
+        assert!(result.is_some_and(|result| result.score().points() > 0));
+
+        let retry = Solution::completed(Duration::from_secs(5));
+        assert!(tracker.record_daily(&challenge("test-1"), &retry, today).unwrap().is_none());
+        assert_eq!(
+            tracker.get_progress().daily_result(today).unwrap().time(),
+            Duration::from_secs(10)
+        );
+        assert!(tracker.get_challenge_stats("test-1").is_none());
+    }
+
+    #[test]
+    fn test_completed_solution_is_scheduled_for_review() {
+        let repo = MockRepository::new();
+        let tracker = ProgressTracker::new(repo).unwrap();
+        let challenges = vec![challenge("test-1")];
+
+        let solution = Solution::completed(Duration::from_secs(10));
+        tracker.record_solution(&challenges[0], &solution).unwrap();
+
+        let progress = tracker.get_progress();
+        let review = progress.review_state("test-1").unwrap();
+        assert_eq!(review.interval_days(), 1);
+        // Due tomorrow, not today
+        assert!(tracker.reviews_due(&challenges).is_empty());
+    }
 }
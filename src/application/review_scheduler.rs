@@ -0,0 +1,188 @@
+use crate::domain::mastery_tier::HINTED_TIER_CAP;
+use crate::domain::review_state::INITIAL_EASE_FACTOR;
+use crate::domain::{Attempt, Challenge, MasteryTier, Progress, ReviewState, TierThresholds};
+use chrono::{Days, NaiveDate};
+
+/// Lowest SM-2 quality that still counts as a successful review
+const PASSING_QUALITY: u8 = 3;
+
+/// Service scheduling completed challenges for review (SM-2)
+///
+/// Each attempt at a completed challenge is graded by the tier it earned
+/// against the challenge's thresholds: the better the result, the longer
+/// until the challenge comes up again. A failed attempt resets the interval.
+pub struct ReviewScheduler;
+
+impl ReviewScheduler {
+    /// Grade an attempt on SM-2's 0-5 quality scale
+    pub fn quality(attempt: &Attempt, thresholds: &TierThresholds) -> u8 {
+        if !attempt.is_completed() {
+            return 1;
+        }
+
+        let mut tier = thresholds.tier(attempt.time(), attempt.keystrokes());
+        if attempt.hints_used() > 0 {
+            tier = tier.min(HINTED_TIER_CAP);
+        }
+
+        match tier {
+            MasteryTier::Bronze => 3,
+            MasteryTier::Silver => 4,
+            MasteryTier::Gold | MasteryTier::Platinum => 5,
+        }
+    }
+
+    /// Next review state after a review of the given quality
+    pub fn schedule(previous: Option<&ReviewState>, quality: u8, today: NaiveDate) -> ReviewState {
+        let quality = quality.min(5);
+        let (ease_factor, interval_days, repetitions) = previous.map_or(
+            (INITIAL_EASE_FACTOR, 0, 0),
+            |state| (state.ease_factor(), state.interval_days(), state.repetitions()),
+        );
+
+        let (interval_days, repetitions) = if quality < PASSING_QUALITY {
+            (1, 0)
+        } else {
+            let interval = match repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (interval_days as f64 * ease_factor).round() as u32,
+            };
+            (interval, repetitions + 1)
+        };
+
+        let miss = (5 - quality) as f64;
+        let ease_factor = ease_factor + (0.1 - miss * (0.08 + miss * 0.02));
+        let due = today
+            .checked_add_days(Days::new(interval_days as u64))
+            .unwrap_or(today);
+
+        ReviewState::new(ease_factor, interval_days, repetitions, due)
+    }
+
+    /// Reschedule a challenge after an attempt
+    ///
+    /// Challenges enter the schedule once they are completed; attempts at
+    /// challenges never solved are not reviews.
+    pub fn record_review(
+        progress: &mut Progress,
+        challenge_id: &str,
+        attempt: &Attempt,
+        thresholds: &TierThresholds,
+        today: NaiveDate,
+    ) {
+        let completed = progress
+            .get_challenge_stats(challenge_id)
+            .is_some_and(|stats| stats.is_completed());
+        if !completed {
+            return;
+        }
+
+        let quality = Self::quality(attempt, thresholds);
+        let state = Self::schedule(progress.review_state(challenge_id), quality, today);
+        progress.set_review_state(challenge_id.to_string(), state);
+    }
+
+    /// Challenges due for review, most overdue first
+    pub fn due_challenges<'a>(
+        progress: &Progress,
+        challenges: &'a [Challenge],
+        today: NaiveDate,
+    ) -> Vec<&'a Challenge> {
+        let mut due: Vec<(&Challenge, NaiveDate)> = challenges
+            .iter()
+            .filter_map(|challenge| {
+                progress
+                    .review_state(challenge.id())
+                    .filter(|state| state.is_due(today))
+                    .map(|state| (challenge, state.due()))
+            })
+            .collect();
+        due.sort_by_key(|(_, due)| *due);
+        due.into_iter().map(|(challenge, _)| challenge).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, d).unwrap()
+    }
+
+    #[test]
+    fn test_intervals_grow_with_good_reviews() {
+        let first = ReviewScheduler::schedule(None, 5, day(1));
+        assert_eq!(first.interval_days(), 1);
+        assert_eq!(first.due(), day(2));
+
+        let second = ReviewScheduler::schedule(Some(&first), 5, day(2));
+        assert_eq!(second.interval_days(), 6);
+
+        let third = ReviewScheduler::schedule(Some(&second), 5, day(8));
+        assert!(third.interval_days() > 6);
+        assert!(third.ease_factor() > INITIAL_EASE_FACTOR);
+    }
+
+    #[test]
+    fn test_failed_review_resets_interval() {
+        let state = ReviewState::new(2.5, 15, 3, day(1));
+        let next = ReviewScheduler::schedule(Some(&state), 1, day(1));
+        assert_eq!(next.repetitions(), 0);
+        assert_eq!(next.interval_days(), 1);
+        assert!(next.ease_factor() < 2.5);
+    }
+
+    #[test]
+    fn test_quality_follows_tier_and_hints() {
+        let thresholds = TierThresholds::new(Some(10), Some(Duration::from_secs(20)));
+        let at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let gold = Attempt::new(true, Duration::from_secs(10), Some(11), at);
+        assert_eq!(ReviewScheduler::quality(&gold, &thresholds), 5);
+        assert_eq!(ReviewScheduler::quality(&gold.clone().with_hints_used(1), &thresholds), 4);
+
+        let slow = Attempt::new(true, Duration::from_secs(90), Some(40), at);
+        assert_eq!(ReviewScheduler::quality(&slow, &thresholds), 3);
+
+        let failed = Attempt::new(false, Duration::from_secs(90), None, at);
+        assert_eq!(ReviewScheduler::quality(&failed, &thresholds), 1);
+    }
+
+    #[test]
+    fn test_only_completed_challenges_are_scheduled() {
+        let at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let thresholds = TierThresholds::global();
+        let mut progress = Progress::new();
+
+        let failed = Attempt::new(false, Duration::from_secs(30), None, at);
+        progress.record("a".to_string(), failed.clone());
+        ReviewScheduler::record_review(&mut progress, "a", &failed, &thresholds, day(1));
+        assert!(progress.review_state("a").is_none());
+
+        let solved = Attempt::new(true, Duration::from_secs(10), Some(20), at);
+        progress.record("a".to_string(), solved.clone());
+        ReviewScheduler::record_review(&mut progress, "a", &solved, &thresholds, day(1));
+        assert_eq!(progress.review_state("a").unwrap().due(), day(2));
+    }
+
+    #[test]
+    fn test_due_challenges_most_overdue_first() {
+        let challenges: Vec<Challenge> = ["a", "b", "c"]
+            .iter()
+            .map(|id| Challenge::new(*id, "T", "D", "x", "y", "h"))
+            .collect();
+        let mut progress = Progress::new();
+        progress.set_review_state("a".to_string(), ReviewState::new(2.5, 1, 1, day(5)));
+        progress.set_review_state("b".to_string(), ReviewState::new(2.5, 1, 1, day(3)));
+        progress.set_review_state("c".to_string(), ReviewState::new(2.5, 1, 1, day(9)));
+
+        let due: Vec<&str> = ReviewScheduler::due_challenges(&progress, &challenges, day(5))
+            .iter()
+            .map(|challenge| challenge.id())
+            .collect();
+        assert_eq!(due, ["b", "a"]);
+    }
+}
@@ -0,0 +1,39 @@
+/// Deterministic xorshift64 generator
+///
+/// The same seed always gives the same sequence, which keeps seeded picks
+/// reproducible (and testable) without a random number crate.
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// A value in `0..bound` (0 if the bound is 0)
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound.max(1)
+    }
+
+    /// A value in `min..=max`
+    pub fn between(&mut self, min: i64, max: i64) -> i64 {
+        let span = max.abs_diff(min).saturating_add(1);
+        min.min(max).wrapping_add(self.below(span) as i64)
+    }
+}
+
+/// Deterministic Fisher-Yates shuffle driven by xorshift64
+pub fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut rng = SeededRng::new(seed);
+    for i in (1..items.len()).rev() {
+        items.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::domain::{Challenge, ChallengeStats, MasteryTier, Progress, SkillLevel};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+/// Tags shared by fewer challenges are too narrow to count as a skill
+pub const MIN_SKILL_CHALLENGES: usize = 3;
+
+/// Days after which practice counts half as recent
+const RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Service aggregating progress per challenge tag into a skill profile
+pub struct SkillProfiler;
+
+impl SkillProfiler {
+    /// Skill levels of all tags with enough challenges, weakest first
+    pub fn profile(progress: &Progress, challenges: &[Challenge], now: DateTime<Utc>) -> Vec<SkillLevel> {
+        let mut by_tag: BTreeMap<&str, Vec<&Challenge>> = BTreeMap::new();
+        for challenge in challenges {
+            for tag in challenge.tags() {
+                by_tag.entry(tag.as_str()).or_default().push(challenge);
+            }
+        }
+
+        let mut skills: Vec<SkillLevel> = by_tag
+            .into_iter()
+            .filter(|(_, tagged)| tagged.len() >= MIN_SKILL_CHALLENGES)
+            .map(|(tag, tagged)| Self::skill_level(tag, &tagged, progress, now))
+            .collect();
+        skills.sort_by_key(|skill| skill.rating());
+        skills
+    }
+
+    /// The weakest skill and up to `limit` of its challenges to train
+    ///
+    /// Challenges come weakest first and locked challenges are left out; a
+    /// skill whose challenges are all locked is passed over.
+    pub fn weakest_skill_challenges<'a>(
+        progress: &Progress,
+        challenges: &'a [Challenge],
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Option<(SkillLevel, Vec<&'a Challenge>)> {
+        Self::profile(progress, challenges, now)
+            .into_iter()
+            .find_map(|skill| {
+                let mut tagged: Vec<&Challenge> = challenges
+                    .iter()
+                    .filter(|challenge| challenge.tags().iter().any(|tag| tag == skill.tag()))
+                    .filter(|challenge| !progress.is_locked(challenge, challenges))
+                    .collect();
+                if tagged.is_empty() {
+                    return None;
+                }
+
+                tagged.sort_by(|a, b| {
+                    let a_stats = progress.get_challenge_stats(a.id());
+                    let b_stats = progress.get_challenge_stats(b.id());
+                    Self::tier_score(a_stats)
+                        .total_cmp(&Self::tier_score(b_stats))
+                        .then_with(|| Self::last_attempted(a_stats).cmp(&Self::last_attempted(b_stats)))
+                });
+                tagged.truncate(limit);
+                Some((skill, tagged))
+            })
+    }
+
+    fn skill_level(tag: &str, tagged: &[&Challenge], progress: &Progress, now: DateTime<Utc>) -> SkillLevel {
+        let mut tier_total = 0.0;
+        let mut completed = 0;
+        let mut efficiencies = Vec::new();
+        let mut recencies = Vec::new();
+
+        for challenge in tagged {
+            let stats = progress.get_challenge_stats(challenge.id());
+            tier_total += Self::tier_score(stats);
+
+            let Some(stats) = stats.filter(|stats| stats.is_completed()) else {
+                continue;
+            };
+            completed += 1;
+            if let (Some(optimal), Some(best)) = (challenge.optimal_keystrokes(), stats.best_keystrokes()) {
+                efficiencies.push(optimal as f64 / best.max(1) as f64);
+            }
+            if let Some(last) = stats.last_attempted_at() {
+                let days = (now - last).num_seconds().max(0) as f64 / 86_400.0;
+                recencies.push(0.5_f64.powf(days / RECENCY_HALF_LIFE_DAYS));
+            }
+        }
+
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        let efficiency = (!efficiencies.is_empty()).then(|| mean(&efficiencies));
+        let recency = if recencies.is_empty() { 0.0 } else { mean(&recencies) };
+
+        SkillLevel::new(
+            tag,
+            tagged.len(),
+            completed,
+            tier_total / tagged.len() as f64,
+            efficiency,
+            recency,
+        )
+    }
+
+    /// Bronze 0.25 up to platinum 1.0; unsolved challenges score 0
+    fn tier_score(stats: Option<&ChallengeStats>) -> f64 {
+        stats
+            .and_then(ChallengeStats::mastery_tier)
+            .map_or(0.0, |tier| match tier {
+                MasteryTier::Bronze => 0.25,
+                MasteryTier::Silver => 0.5,
+                MasteryTier::Gold => 0.75,
+                MasteryTier::Platinum => 1.0,
+            })
+    }
+
+    fn last_attempted(stats: Option<&ChallengeStats>) -> Option<DateTime<Utc>> {
+        stats.and_then(ChallengeStats::last_attempted_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Attempt;
+    use chrono::{Duration as ChronoDuration, TimeZone};
+    use std::time::Duration;
+
+    fn challenge(id: &str, tags: &[&str]) -> Challenge {
+        Challenge::new(id, "T", "D", "a", "b", "h")
+            .with_tags(tags.iter().map(|tag| tag.to_string()).collect())
+            .with_optimal_solution("x", 10)
+    }
+
+    fn challenges() -> Vec<Challenge> {
+        vec![
+            challenge("d1", &["deletion"]),
+            challenge("d2", &["deletion"]),
+            challenge("d3", &["deletion", "rare"]),
+            challenge("m1", &["movement"]),
+            challenge("m2", &["movement"]),
+            challenge("m3", &["movement"]),
+        ]
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    fn solve(progress: &mut Progress, id: &str, keystrokes: u32, at: DateTime<Utc>) {
+        let thresholds = challenges()
+            .iter()
+            .find(|c| c.id() == id)
+            .unwrap()
+            .tier_thresholds();
+        progress.record(id.to_string(), Attempt::new(true, Duration::from_secs(5), Some(keystrokes), at));
+        progress.set_tier_thresholds(id, thresholds);
+    }
+
+    #[test]
+    fn test_profile_rates_practised_skills_higher() {
+        let mut progress = Progress::new();
+        for id in ["m1", "m2", "m3"] {
+            solve(&mut progress, id, 10, now());
+        }
+        solve(&mut progress, "d1", 20, now() - ChronoDuration::days(60));
+
+        let profile = SkillProfiler::profile(&progress, &challenges(), now());
+        let tags: Vec<&str> = profile.iter().map(|skill| skill.tag()).collect();
+        // "rare" has too few challenges to be a skill
+        assert_eq!(tags, ["deletion", "movement"]);
+
+        let movement = &profile[1];
+        assert_eq!(movement.rating(), 100);
+        assert_eq!(movement.completed_count(), 3);
+
+        let deletion = &profile[0];
+        assert_eq!(deletion.efficiency(), Some(0.5));
+        assert!((deletion.recency() - 0.25).abs() < 1e-9);
+        assert!(deletion.rating() < 30);
+    }
+
+    #[test]
+    fn test_weakest_skill_challenges_start_with_unsolved() {
+        let mut progress = Progress::new();
+        for id in ["m1", "m2", "m3", "d2"] {
+            solve(&mut progress, id, 10, now());
+        }
+
+        let challenges = challenges();
+        let (skill, picked) =
+            SkillProfiler::weakest_skill_challenges(&progress, &challenges, now(), 2).unwrap();
+        assert_eq!(skill.tag(), "deletion");
+        let ids: Vec<&str> = picked.iter().map(|challenge| challenge.id()).collect();
+        assert_eq!(ids, ["d1", "d3"]);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::application::shuffle::shuffle;
+use crate::domain::{Challenge, ChallengeFile};
+
+const VOWELS: &[u8] = b"aeiou";
+const CONSONANTS: &[u8] = b"bcdfghjklmnpqrstvwxyz";
+
+/// Service giving a challenge new surface text for drills
+///
+/// Letters are swapped through one seeded substitution (vowels for vowels,
+/// consonants for consonants, case kept) applied to every starting and
+/// target text alike. Everything else stays put, so the edit and its
+/// keystroke count are unchanged; only the letters to find or type differ.
+pub struct SurfaceRandomizer;
+
+impl SurfaceRandomizer {
+    pub fn randomize(challenge: &Challenge, seed: u64) -> Challenge {
+        let substitution = Self::substitution(seed);
+        let files = challenge
+            .files()
+            .iter()
+            .map(|file| {
+                ChallengeFile::new(
+                    file.path(),
+                    Self::apply(&substitution, file.starting_content()),
+                    Self::apply(&substitution, file.target_content()),
+                )
+            })
+            .collect();
+        challenge.clone().with_files(files)
+    }
+
+    /// Replacement for each lowercase letter, indexed from 'a'
+    fn substitution(seed: u64) -> [u8; 26] {
+        let mut vowels = VOWELS.to_vec();
+        let mut consonants = CONSONANTS.to_vec();
+        shuffle(&mut vowels, seed);
+        shuffle(&mut consonants, seed.rotate_left(32));
+
+        let mut substitution = [0; 26];
+        for (from, to) in VOWELS.iter().zip(&vowels).chain(CONSONANTS.iter().zip(&consonants)) {
+            substitution[(from - b'a') as usize] = *to;
+        }
+        substitution
+    }
+
+    fn apply(substitution: &[u8; 26], text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                'a'..='z' => substitution[c as usize - 'a' as usize] as char,
+                'A'..='Z' => {
+                    (substitution[c as usize - 'A' as usize] as char).to_ascii_uppercase()
+                }
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_randomize_keeps_structure_and_consistency() {
+        let challenge = Challenge::new(
+            "c",
+            "T",
+            "D",
+            "let Total = total + 1;",
+            "let Sum = total + 1;",
+            "h",
+        );
+        let variant = SurfaceRandomizer::randomize(&challenge, 99);
+        let starting = variant.starting_content();
+        let target = variant.target_content();
+
+        assert_ne!(starting, challenge.starting_content());
+        assert_eq!(starting.len(), challenge.starting_content().len());
+        // Same substitution everywhere: the shared suffix still matches
+        assert_eq!(&starting[9..], &target[7..]);
+        assert!(starting.ends_with(" + 1;"));
+        assert!(starting[4..5].chars().all(|c| c.is_ascii_uppercase()));
+        assert_eq!(
+            SurfaceRandomizer::randomize(&challenge, 99).starting_content(),
+            starting
+        );
+    }
+
+    #[test]
+    fn test_substitution_keeps_vowels_vowels() {
+        let substitution = SurfaceRandomizer::substitution(7);
+        for vowel in VOWELS {
+            assert!(VOWELS.contains(&substitution[(vowel - b'a') as usize]));
+        }
+        let mut letters = substitution.to_vec();
+        letters.sort();
+        letters.dedup();
+        assert_eq!(letters.len(), 26);
+    }
+}
@@ -0,0 +1,211 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+/// Directory under the data directory that `from-git` writes to; loaded
+/// next to the built-in challenges
+pub const GIT_CHALLENGES_DIR: &str = "git";
+
+/// Directory under the data directory of challenge files layered over the
+/// built-in challenges
+pub const CHALLENGES_DIR: &str = "challenges";
+
+/// Practise editor motions on small, timed editing challenges
+///
+/// Without a command, opens the interactive menu.
+#[derive(Debug, Parser)]
+#[command(name = "editor-dojo", version, about)]
+pub struct Cli {
+    /// Editor to use for this run instead of the one saved in Settings
+    /// (helix, vim, neovim or emacs)
+    #[arg(long, global = true)]
+    pub editor: Option<String>,
+    /// Directory of challenge files layered over the built-in challenges
+    /// [default: challenges/ in the data directory]
+    #[arg(long, global = true)]
+    pub challenges_dir: Option<PathBuf>,
+    /// Don't record keystrokes with asciinema
+    #[arg(long, global = true)]
+    pub no_record: bool,
+    /// Keep progress in a separate profile (progress-<name>.json)
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Start a challenge by id, skipping the menu
+    Run {
+        /// Challenge id, as shown by `list`
+        id: String,
+    },
+    /// List the challenges with their difficulty and your best tier
+    List {
+        /// Only challenges with this tag; repeat for any of several
+        #[arg(long)]
+        tag: Vec<String>,
+        /// Only challenges of this difficulty
+        #[arg(long)]
+        difficulty: Option<String>,
+        /// Only challenges not completed yet
+        #[arg(long)]
+        incomplete: bool,
+    },
+    /// Print a summary of your progress
+    Stats {
+        /// Print JSON, with per-challenge bests, for scripts
+        #[arg(long)]
+        json: bool,
+    },
+    /// Start a random unlocked challenge
+    Random,
+    /// Run the challenges due for review, most overdue first
+    Review,
+    /// Turn small edits from a git repository's history into challenges
+    FromGit {
+        /// Path to the local git repository
+        repo: PathBuf,
+        /// Directory to write the challenge files to
+        /// [default: git/ in the data directory]
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Number of recent commits to scan
+        #[arg(long, default_value_t = 200)]
+        commits: usize,
+        /// Most challenges to write
+        #[arg(long, default_value_t = 20)]
+        max: usize,
+    },
+    /// Create a challenge by doing it once in the recorded editor
+    ///
+    /// Edit the file into its target and quit; the final buffer becomes the
+    /// target and the recorded keys up to the last save the optimal solution.
+    Author {
+        /// File holding the starting text
+        starting: PathBuf,
+        /// Directory to write the challenge file to
+        /// [default: the challenges directory, see --challenges-dir]
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Check every challenge file in a directory and report all problems
+    ///
+    /// Exits with an error if any problem is an error rather than a warning.
+    Lint {
+        /// Directory of challenge files to check
+        /// [default: the challenges directory, see --challenges-dir]
+        dir: Option<PathBuf>,
+    },
+    /// Write the built-in challenges to a directory for editing
+    ///
+    /// Challenges in the --challenges-dir directory replace built-in ones
+    /// with the same id, so edits to the exported files take effect there.
+    ExportBuiltin {
+        /// Directory to write the challenge files to
+        /// [default: the challenges directory, see --challenges-dir]
+        out: Option<PathBuf>,
+        /// Overwrite files that already exist
+        #[arg(long)]
+        force: bool,
+    },
+    /// Install, list or remove challenge packs
+    Pack {
+        #[command(subcommand)]
+        command: PackCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PackCommand {
+    /// Install a pack from a directory or .tar.gz archive with a pack.toml
+    ///
+    /// Installing a pack that is already installed replaces it.
+    Install {
+        /// Pack directory or .tar.gz archive
+        source: PathBuf,
+    },
+    /// List the installed packs
+    List,
+    /// Remove an installed pack
+    Remove {
+        /// Pack name, as shown by `pack list`
+        name: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_from_git() {
+        let cli = Cli::try_parse_from(["editor-dojo", "from-git", "../app", "--max", "5"]).unwrap();
+        match cli.command {
+            Some(Command::FromGit { repo, out, commits, max }) => {
+                assert_eq!(repo, PathBuf::from("../app"));
+                assert_eq!(out, None);
+                assert_eq!((commits, max), (200, 5));
+            }
+            other => panic!("expected from-git, got {:?}", other),
+        }
+        assert!(Cli::try_parse_from(["editor-dojo"]).unwrap().command.is_none());
+    }
+
+    #[test]
+    fn test_parse_global_flags_after_subcommand() {
+        let cli = Cli::try_parse_from([
+            "editor-dojo", "run", "delete-word-01", "--editor", "vim", "--no-record", "--profile", "work",
+        ])
+        .unwrap();
+        assert!(matches!(cli.command, Some(Command::Run { ref id }) if id == "delete-word-01"));
+        assert_eq!(cli.editor.as_deref(), Some("vim"));
+        assert!(cli.no_record);
+        assert_eq!(cli.profile.as_deref(), Some("work"));
+        assert_eq!(cli.challenges_dir, None);
+    }
+
+    #[test]
+    fn test_parse_list_filters() {
+        let cli = Cli::try_parse_from([
+            "editor-dojo", "list", "--tag", "word", "--tag", "line", "--difficulty", "beginner", "--incomplete",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::List { tag, difficulty, incomplete }) => {
+                assert_eq!(tag, ["word", "line"]);
+                assert_eq!(difficulty.as_deref(), Some("beginner"));
+                assert!(incomplete);
+            }
+            other => panic!("expected list, got {:?}", other),
+        }
+        assert!(Cli::try_parse_from(["editor-dojo", "run"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_lint() {
+        let cli = Cli::try_parse_from(["editor-dojo", "lint"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Lint { dir: None })));
+
+        let cli = Cli::try_parse_from(["editor-dojo", "--challenges-dir", "mine", "lint", "other"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Lint { dir: Some(dir) }) if dir.as_os_str() == "other"));
+        assert_eq!(cli.challenges_dir, Some(PathBuf::from("mine")));
+    }
+
+    #[test]
+    fn test_parse_export_builtin() {
+        let cli = Cli::try_parse_from(["editor-dojo", "export-builtin", "--force"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::ExportBuiltin { out: None, force: true })
+        ));
+    }
+
+    #[test]
+    fn test_parse_pack_commands() {
+        let cli = Cli::try_parse_from(["editor-dojo", "pack", "remove", "rust"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Pack { command: PackCommand::Remove { name } }) if name == "rust"));
+        assert!(Cli::try_parse_from(["editor-dojo", "pack", "install"]).is_err());
+    }
+}
@@ -184,7 +184,7 @@ pub fn check_fast_completion(time: Duration, threshold_secs: u64) -> bool {
 }
 
 pub fn check_efficient_completion(keystrokes: Option<u32>, threshold: u32) -> bool {
-    keystrokes.map_or(false, |ks| ks < threshold)
+    keystrokes.is_some_and(|ks| ks < threshold)
 }
 
 #[cfg(test)]
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+use crate::domain::Score;
+
+/// Value object describing a single recorded challenge attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    completed: bool,
+    time: Duration,
+    keystrokes: Option<u32>,
+    attempted_at: DateTime<Utc>,
+    blind: bool,
+    hints_used: u32,
+    score: Option<Score>,
+}
+
+impl Attempt {
+    pub fn new(
+        completed: bool,
+        time: Duration,
+        keystrokes: Option<u32>,
+        attempted_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            completed,
+            time,
+            keystrokes,
+            attempted_at,
+            blind: false,
+            hints_used: 0,
+            score: None,
+        }
+    }
+
+    /// Marks the attempt as made without seeing the target text
+    pub fn with_blind(mut self, blind: bool) -> Self {
+        self.blind = blind;
+        self
+    }
+
+    /// Records how many hint levels were revealed during the attempt
+    pub fn with_hints_used(mut self, hints_used: u32) -> Self {
+        self.hints_used = hints_used;
+        self
+    }
+
+    /// Attaches the composite score calculated for the attempt
+    pub fn with_score(mut self, score: Score) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn keystrokes(&self) -> Option<u32> {
+        self.keystrokes
+    }
+
+    pub fn attempted_at(&self) -> DateTime<Utc> {
+        self.attempted_at
+    }
+
+    pub fn is_blind(&self) -> bool {
+        self.blind
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.hints_used
+    }
+
+    pub fn score(&self) -> Option<Score> {
+        self.score
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long the brief waits before revealing the target in `RevealAfterDelay`
+pub const REVEAL_DELAY: Duration = Duration::from_secs(30);
+
+/// Time added to an attempt whose target was revealed on demand
+pub const REVEAL_PENALTY: Duration = Duration::from_secs(15);
+
+/// How much of a challenge is shown before the editor opens
+///
+/// In the blind modes only the natural-language description is shown, so the
+/// goal has to be translated into commands instead of copied from a picture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BlindMode {
+    /// Starting and target text are shown
+    #[default]
+    Off,
+    /// The target is never shown
+    Hidden,
+    /// The target appears after `REVEAL_DELAY`; starting after that is not blind
+    RevealAfterDelay,
+    /// The target can be revealed at any time for a `REVEAL_PENALTY` time penalty
+    RevealOnDemand,
+}
+
+impl BlindMode {
+    /// Cycle to the next mode
+    pub fn next(self) -> Self {
+        match self {
+            BlindMode::Off => BlindMode::Hidden,
+            BlindMode::Hidden => BlindMode::RevealAfterDelay,
+            BlindMode::RevealAfterDelay => BlindMode::RevealOnDemand,
+            BlindMode::RevealOnDemand => BlindMode::Off,
+        }
+    }
+
+    /// Cycle to the previous mode
+    pub fn previous(self) -> Self {
+        match self {
+            BlindMode::Off => BlindMode::RevealOnDemand,
+            BlindMode::Hidden => BlindMode::Off,
+            BlindMode::RevealAfterDelay => BlindMode::Hidden,
+            BlindMode::RevealOnDemand => BlindMode::RevealAfterDelay,
+        }
+    }
+
+    pub fn is_blind(&self) -> bool {
+        *self != BlindMode::Off
+    }
+
+    /// Whether the target can appear on the brief, so the clock starts there
+    pub fn reveals_on_brief(&self) -> bool {
+        matches!(self, BlindMode::RevealAfterDelay | BlindMode::RevealOnDemand)
+    }
+
+    /// Get the display name of the mode
+    pub fn name(&self) -> String {
+        match self {
+            BlindMode::Off => "Off".to_string(),
+            BlindMode::Hidden => "Blind".to_string(),
+            BlindMode::RevealAfterDelay => {
+                format!("Blind, reveal after {}s", REVEAL_DELAY.as_secs())
+            }
+            BlindMode::RevealOnDemand => {
+                format!("Blind, reveal on demand (+{}s)", REVEAL_PENALTY.as_secs())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_returns_to_off() {
+        let mut mode = BlindMode::Off;
+        for _ in 0..4 {
+            assert_eq!(mode.next().previous(), mode);
+            mode = mode.next();
+            assert_eq!(mode.is_blind(), mode != BlindMode::Off);
+        }
+        assert_eq!(mode, BlindMode::Off);
+    }
+}
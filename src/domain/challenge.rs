@@ -1,20 +1,77 @@
+use std::time::Duration;
+
+use crate::domain::fnv::fnv1a;
+use crate::domain::pack_manifest::namespaced_id;
+use crate::domain::{ChallengeTemplate, MasteryTier, TierThresholds};
+
+/// File name used for challenges that define a single buffer
+pub const DEFAULT_FILE_PATH: &str = "challenge.txt";
+
+/// Difficulty values challenges may declare, easiest first
+pub const DIFFICULTIES: &[&str] = &["beginner", "intermediate", "advanced"];
+
+/// A single file in a challenge workspace
+///
+/// The path is relative to the workspace root (e.g. `src/lib.rs`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeFile {
+    path: String,
+    starting_content: String,
+    target_content: String,
+}
+
+impl ChallengeFile {
+    pub fn new(
+        path: impl Into<String>,
+        starting_content: impl Into<String>,
+        target_content: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            starting_content: starting_content.into(),
+            target_content: target_content.into(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn starting_content(&self) -> &str {
+        &self.starting_content
+    }
+
+    pub fn target_content(&self) -> &str {
+        &self.target_content
+    }
+}
+
 /// Represents an editing challenge in the dojo
 ///
 /// This is a pure domain entity with no external dependencies.
 /// It contains all the information needed to present and validate a challenge.
-#[derive(Debug, Clone)]
+/// A challenge consists of one or more files; it is solved when every file
+/// matches its target.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Challenge {
     id: String,
     title: String,
     description: String,
-    starting_content: String,
-    target_content: String,
+    files: Vec<ChallengeFile>,
     hint: String,
+    /// Helix-specific hint, shown instead of the generic `hint`
+    helix_hint: Option<String>,
     difficulty: Option<String>,
     tags: Vec<String>,
     progressive_hints: Vec<String>,
     optimal_solution: Option<String>,
     optimal_keystrokes: Option<u32>,
+    par_time: Option<Duration>,
+    /// Challenges that must reach `required_tier` before this one unlocks
+    requires: Vec<String>,
+    required_tier: MasteryTier,
+    /// Set for generated challenges; the files are one rendering of it
+    template: Option<ChallengeTemplate>,
 }
 
 impl Challenge {
@@ -30,17 +87,36 @@ impl Challenge {
             id: id.into(),
             title: title.into(),
             description: description.into(),
-            starting_content: starting_content.into(),
-            target_content: target_content.into(),
+            files: vec![ChallengeFile::new(
+                DEFAULT_FILE_PATH,
+                starting_content,
+                target_content,
+            )],
             hint: hint.into(),
+            helix_hint: None,
             difficulty: None,
             tags: Vec::new(),
             progressive_hints: Vec::new(),
             optimal_solution: None,
             optimal_keystrokes: None,
+            par_time: None,
+            requires: Vec::new(),
+            required_tier: MasteryTier::Bronze,
+            template: None,
         }
     }
 
+    /// Replaces the challenge content with a set of files
+    pub fn with_files(mut self, files: Vec<ChallengeFile>) -> Self {
+        self.files = files;
+        self
+    }
+
+    pub fn with_helix_hint(mut self, hint: impl Into<String>) -> Self {
+        self.helix_hint = Some(hint.into());
+        self
+    }
+
     pub fn with_difficulty(mut self, difficulty: impl Into<String>) -> Self {
         self.difficulty = Some(difficulty.into());
         self
@@ -62,10 +138,52 @@ impl Challenge {
         self
     }
 
+    /// Sets the time a practised user should finish in, used for gold
+    pub fn with_par_time(mut self, par_time: Duration) -> Self {
+        self.par_time = Some(par_time);
+        self
+    }
+
+    /// Locks the challenge until every listed challenge reaches `tier`
+    pub fn with_requirements(mut self, requires: Vec<String>, tier: MasteryTier) -> Self {
+        self.requires = requires;
+        self.required_tier = tier;
+        self
+    }
+
+    /// Marks the challenge as generated from a template
+    pub fn with_template(mut self, template: ChallengeTemplate) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Namespaces the id and the prerequisite ids by the pack the challenge
+    /// was installed from
+    pub fn in_pack(mut self, pack: &str) -> Self {
+        self.id = namespaced_id(pack, &self.id);
+        self.requires = self.requires.iter().map(|id| namespaced_id(pack, id)).collect();
+        self
+    }
+
     pub fn id(&self) -> &str {
         &self.id
     }
 
+    /// Hash of the file paths and texts, identifying this version of the
+    /// challenge; bests set on another version are not comparable
+    ///
+    /// Generated challenges hash their template, so every rendering has
+    /// the same version.
+    pub fn content_version(&self) -> String {
+        let files = self.template.as_ref().map_or(self.files.as_slice(), |template| template.files());
+        let bytes = files.iter().flat_map(|file| {
+            [file.path(), file.starting_content(), file.target_content()]
+                .into_iter()
+                .flat_map(|text| text.bytes().chain([0]))
+        });
+        format!("{:016x}", fnv1a(bytes))
+    }
+
     pub fn title(&self) -> &str {
         &self.title
     }
@@ -74,18 +192,37 @@ impl Challenge {
         &self.description
     }
 
+    /// Starting content of the primary (first) file
     pub fn starting_content(&self) -> &str {
-        &self.starting_content
+        self.files.first().map_or("", |f| f.starting_content())
     }
 
+    /// Target content of the primary (first) file
     pub fn target_content(&self) -> &str {
-        &self.target_content
+        self.files.first().map_or("", |f| f.target_content())
+    }
+
+    pub fn files(&self) -> &[ChallengeFile] {
+        &self.files
     }
 
+    pub fn is_multi_file(&self) -> bool {
+        self.files.len() > 1
+    }
+
+    /// The hint to show: the Helix one if there is one
     pub fn hint(&self) -> &str {
+        self.helix_hint.as_deref().unwrap_or(&self.hint)
+    }
+
+    pub fn generic_hint(&self) -> &str {
         &self.hint
     }
 
+    pub fn helix_hint(&self) -> Option<&str> {
+        self.helix_hint.as_deref()
+    }
+
     pub fn difficulty(&self) -> Option<&str> {
         self.difficulty.as_deref()
     }
@@ -109,4 +246,86 @@ impl Challenge {
     pub fn optimal_keystrokes(&self) -> Option<u32> {
         self.optimal_keystrokes
     }
+
+    pub fn par_time(&self) -> Option<Duration> {
+        self.par_time
+    }
+
+    pub fn requires(&self) -> &[String] {
+        &self.requires
+    }
+
+    /// Tier each prerequisite needs; Bronze means completed
+    pub fn required_tier(&self) -> MasteryTier {
+        self.required_tier
+    }
+
+    pub fn template(&self) -> Option<&ChallengeTemplate> {
+        self.template.as_ref()
+    }
+
+    /// Whether the content is generated afresh for every attempt
+    pub fn is_generated(&self) -> bool {
+        self.template.is_some()
+    }
+
+    /// Mastery tier thresholds derived from the optimal solution and par time
+    ///
+    /// Generated challenges are ranked on time alone: the keys a rendering
+    /// takes depend on the values typed, so keystroke limits would move
+    /// with every attempt.
+    pub fn tier_thresholds(&self) -> TierThresholds {
+        if self.template.is_some() {
+            return TierThresholds::time_only(self.par_time);
+        }
+        TierThresholds::new(self.optimal_keystrokes, self.par_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_file_challenge() {
+        let challenge = Challenge::new("id", "Title", "Desc", "start", "target", "hint");
+        assert!(!challenge.is_multi_file());
+        assert_eq!(challenge.files().len(), 1);
+        assert_eq!(challenge.files()[0].path(), DEFAULT_FILE_PATH);
+        assert_eq!(challenge.starting_content(), "start");
+        assert_eq!(challenge.target_content(), "target");
+    }
+
+    #[test]
+    fn test_multi_file_challenge() {
+        let challenge = Challenge::new("id", "Title", "Desc", "", "", "hint").with_files(vec![
+            ChallengeFile::new("src/lib.rs", "fn a() {}", "fn b() {}"),
+            ChallengeFile::new("src/main.rs", "a();", "b();"),
+        ]);
+        assert!(challenge.is_multi_file());
+        assert_eq!(challenge.starting_content(), "fn a() {}");
+        assert_eq!(challenge.files()[1].target_content(), "b();");
+    }
+
+    #[test]
+    fn test_tier_thresholds_from_optimal_solution() {
+        let challenge = Challenge::new("id", "Title", "Desc", "a", "b", "hint")
+            .with_optimal_solution("xd", 2)
+            .with_par_time(Duration::from_secs(10));
+        let thresholds = challenge.tier_thresholds();
+        assert_eq!(thresholds.optimal_keystrokes(), Some(2));
+        assert_eq!(thresholds.par_time(), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_content_version_follows_text_only() {
+        let challenge = Challenge::new("id", "Title", "Desc", "ab", "b", "hint");
+        let retitled = Challenge::new("id", "New title", "Desc", "ab", "b", "hint")
+            .with_optimal_solution("x", 1);
+        let edited = Challenge::new("id", "Title", "Desc", "a", "bb", "hint");
+
+        assert_eq!(challenge.content_version(), retitled.content_version());
+        assert_ne!(challenge.content_version(), edited.content_version());
+        assert_eq!(challenge.content_version().len(), 16);
+    }
 }
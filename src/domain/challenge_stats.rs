@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use std::time::Duration;
-use crate::domain::MasteryTier;
+use crate::domain::mastery_tier::HINTED_TIER_CAP;
+use crate::domain::{Attempt, MasteryTier, Score, TierThresholds};
 
 /// Value object representing statistics for a single challenge
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,6 +13,23 @@ pub struct ChallengeStats {
     first_completed_at: Option<DateTime<Utc>>,
     last_attempted_at: Option<DateTime<Utc>>,
     attempt_count: u32,
+    /// Separate mastery category for attempts made without seeing the target
+    blind_best_time: Option<Duration>,
+    blind_best_keystrokes: Option<u32>,
+    best_score: Option<Score>,
+    /// Score of every scored attempt with when it was made, oldest first
+    scores: Vec<(DateTime<Utc>, Score)>,
+    /// Bests of attempts completed without revealing any hints
+    unassisted_best_time: Option<Duration>,
+    unassisted_best_keystrokes: Option<u32>,
+    /// Thresholds of the challenge the stats belong to; not persisted, the
+    /// tiers are derived from the best performance every time
+    thresholds: TierThresholds,
+    /// `Challenge::content_version` the bests were set on
+    content_version: Option<String>,
+    /// Whether the bests were set on an earlier version of the challenge;
+    /// the next completion replaces them instead of competing with them
+    legacy: bool,
 }
 
 impl ChallengeStats {
@@ -25,6 +43,15 @@ impl ChallengeStats {
             first_completed_at: None,
             last_attempted_at: None,
             attempt_count: 0,
+            blind_best_time: None,
+            blind_best_keystrokes: None,
+            best_score: None,
+            scores: Vec::new(),
+            unassisted_best_time: None,
+            unassisted_best_keystrokes: None,
+            thresholds: TierThresholds::global(),
+            content_version: None,
+            legacy: false,
         }
     }
 
@@ -43,9 +70,72 @@ impl ChallengeStats {
             first_completed_at: Some(completed_at),
             last_attempted_at: Some(completed_at),
             attempt_count: 1,
+            blind_best_time: None,
+            blind_best_keystrokes: None,
+            best_score: None,
+            scores: Vec::new(),
+            unassisted_best_time: Some(time),
+            unassisted_best_keystrokes: keystrokes,
+            thresholds: TierThresholds::global(),
+            content_version: None,
+            legacy: false,
         }
     }
 
+    /// Restore the best blind performance (e.g. when loading from storage)
+    pub fn with_blind_best(mut self, time: Duration, keystrokes: Option<u32>) -> Self {
+        self.blind_best_time = Some(time);
+        self.blind_best_keystrokes = keystrokes;
+        self
+    }
+
+    /// Restore the best performance without hints (e.g. when loading from storage)
+    ///
+    /// `None` means every completion so far used hints.
+    pub fn with_unassisted_best(mut self, best: Option<(Duration, Option<u32>)>) -> Self {
+        self.unassisted_best_time = best.map(|(time, _)| time);
+        self.unassisted_best_keystrokes = best.and_then(|(_, keystrokes)| keystrokes);
+        self
+    }
+
+    /// Restore the best composite score (e.g. when loading from storage)
+    pub fn with_best_score(mut self, score: Score) -> Self {
+        self.best_score = Some(score);
+        self
+    }
+
+    /// Restore the per-attempt scores (e.g. when loading from storage)
+    pub fn with_scores(mut self, scores: Vec<(DateTime<Utc>, Score)>) -> Self {
+        self.scores = scores;
+        self
+    }
+
+    /// Restore the content version and legacy flag (e.g. when loading from storage)
+    pub fn with_version_state(mut self, content_version: Option<String>, legacy: bool) -> Self {
+        self.content_version = content_version;
+        self.legacy = legacy;
+        self
+    }
+
+    /// Ties the stats to the current version of the challenge content
+    ///
+    /// Stats without a version (recorded before versioning, or by a first
+    /// attempt) adopt it. If the bests were set on a different version they
+    /// become legacy bests.
+    pub fn with_content_version(mut self, version: &str) -> Self {
+        if self.content_version.as_deref().is_some_and(|current| current != version) && self.completed {
+            self.legacy = true;
+        }
+        self.content_version = Some(version.to_string());
+        self
+    }
+
+    /// Use a challenge's own tier thresholds instead of the global rule
+    pub fn with_thresholds(mut self, thresholds: TierThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
     /// Update stats with a new attempt
     pub fn record_attempt(
         &self,
@@ -54,40 +144,104 @@ impl ChallengeStats {
         keystrokes: Option<u32>,
         attempted_at: DateTime<Utc>,
     ) -> Self {
+        self.record(&Attempt::new(completed, time, keystrokes, attempted_at))
+    }
+
+    /// Update stats with a new attempt
+    ///
+    /// A completed blind attempt counts as a completion, but its time and
+    /// keystrokes only go into the blind bests.
+    pub fn record(&self, attempt: &Attempt) -> Self {
+        let completed = attempt.is_completed();
+        let time = attempt.time();
+        let keystrokes = attempt.keystrokes();
+        let attempted_at = attempt.attempted_at();
+
         let mut updated = self.clone();
         updated.attempt_count += 1;
         updated.last_attempted_at = Some(attempted_at);
+        if let Some(score) = attempt.score() {
+            updated.scores.push((attempted_at, score));
+        }
 
         if completed {
             updated.completed = true;
 
+            // Bests from an earlier version are replaced, not competed with
+            if updated.legacy {
+                updated.clear_bests();
+            }
+
             if updated.first_completed_at.is_none() {
                 updated.first_completed_at = Some(attempted_at);
             }
 
+            if let Some(score) = attempt.score() {
+                if updated.best_score.is_none_or(|best| score > best) {
+                    updated.best_score = Some(score);
+                }
+            }
+
+            if attempt.is_blind() {
+                if updated.blind_best_time.is_none_or(|best| time < best) {
+                    updated.blind_best_time = Some(time);
+                }
+                if let Some(new_keystrokes) = keystrokes {
+                    if updated.blind_best_keystrokes.is_none_or(|best| new_keystrokes < best) {
+                        updated.blind_best_keystrokes = Some(new_keystrokes);
+                    }
+                }
+                return updated;
+            }
+
             // Update best time if this is better
-            let is_better_time = updated.best_time.map_or(true, |best| time < best);
+            let is_better_time = updated.best_time.is_none_or(|best| time < best);
             if is_better_time {
                 updated.best_time = Some(time);
             }
 
             // Update best keystrokes if this is better
             if let Some(new_keystrokes) = keystrokes {
-                let is_better_keystrokes = updated.best_keystrokes.map_or(true, |best| new_keystrokes < best);
+                let is_better_keystrokes = updated.best_keystrokes.is_none_or(|best| new_keystrokes < best);
                 if is_better_keystrokes {
                     updated.best_keystrokes = Some(new_keystrokes);
                 }
             }
+
+            if attempt.hints_used() == 0 {
+                if updated.unassisted_best_time.is_none_or(|best| time < best) {
+                    updated.unassisted_best_time = Some(time);
+                }
+                if let Some(new_keystrokes) = keystrokes {
+                    if updated.unassisted_best_keystrokes.is_none_or(|best| new_keystrokes < best) {
+                        updated.unassisted_best_keystrokes = Some(new_keystrokes);
+                    }
+                }
+            }
         }
 
         updated
     }
 
+    fn clear_bests(&mut self) {
+        self.best_time = None;
+        self.best_keystrokes = None;
+        self.blind_best_time = None;
+        self.blind_best_keystrokes = None;
+        self.best_score = None;
+        self.unassisted_best_time = None;
+        self.unassisted_best_keystrokes = None;
+        self.legacy = false;
+    }
+
     /// Check if this attempt beats any personal record
     pub fn is_new_record(&self, time: Duration, keystrokes: Option<u32>) -> (bool, bool) {
-        let new_time_record = self.best_time.map_or(true, |best| time < best);
-        let new_keystroke_record = keystrokes.map_or(false, |new_ks| {
-            self.best_keystrokes.map_or(true, |best| new_ks < best)
+        if self.legacy {
+            return (true, keystrokes.is_some());
+        }
+        let new_time_record = self.best_time.is_none_or(|best| time < best);
+        let new_keystroke_record = keystrokes.is_some_and(|new_ks| {
+            self.best_keystrokes.is_none_or(|best| new_ks < best)
         });
         (new_time_record, new_keystroke_record)
     }
@@ -121,13 +275,77 @@ impl ChallengeStats {
         self.attempt_count
     }
 
+    pub fn content_version(&self) -> Option<&str> {
+        self.content_version.as_deref()
+    }
+
+    /// Whether the bests were set on an earlier version of the challenge
+    pub fn has_legacy_bests(&self) -> bool {
+        self.legacy
+    }
+
     /// Get mastery tier for this challenge based on best performance
+    ///
+    /// Results achieved with hints count at most `HINTED_TIER_CAP`; only
+    /// hint-free results can earn the tiers above it. A challenge only ever
+    /// completed blind is bronze here; its blind tier is separate.
     pub fn mastery_tier(&self) -> Option<MasteryTier> {
         if !self.completed {
             return None;
         }
 
-        self.best_time.map(|time| MasteryTier::calculate(time, self.best_keystrokes))
+        let overall = self
+            .best_time
+            .map(|time| self.thresholds.tier(time, self.best_keystrokes).min(HINTED_TIER_CAP));
+        let unassisted = self
+            .unassisted_best_time
+            .map(|time| self.thresholds.tier(time, self.unassisted_best_keystrokes));
+        Some(overall.max(unassisted).unwrap_or(MasteryTier::Bronze))
+    }
+
+    /// Whether the challenge was ever completed without revealing a hint
+    pub fn is_solved_without_hints(&self) -> bool {
+        self.unassisted_best_time.is_some()
+    }
+
+    pub fn unassisted_best_time(&self) -> Option<Duration> {
+        self.unassisted_best_time
+    }
+
+    pub fn unassisted_best_keystrokes(&self) -> Option<u32> {
+        self.unassisted_best_keystrokes
+    }
+
+    /// Best composite score over all scored attempts
+    pub fn best_score(&self) -> Option<Score> {
+        self.best_score
+    }
+
+    /// Score of every scored attempt, oldest first
+    pub fn scores(&self) -> &[(DateTime<Utc>, Score)] {
+        &self.scores
+    }
+
+    pub fn thresholds(&self) -> &TierThresholds {
+        &self.thresholds
+    }
+
+    pub fn is_blind_completed(&self) -> bool {
+        self.blind_best_time.is_some()
+    }
+
+    pub fn blind_best_time(&self) -> Option<Duration> {
+        self.blind_best_time
+    }
+
+    pub fn blind_best_keystrokes(&self) -> Option<u32> {
+        self.blind_best_keystrokes
+    }
+
+    /// Get mastery tier for this challenge in blind mode
+    pub fn blind_mastery_tier(&self) -> Option<MasteryTier> {
+        self.blind_best_time
+            .map(|time| self.thresholds.tier(time, self.blind_best_keystrokes))
     }
 }
 
@@ -163,6 +381,32 @@ mod tests {
         assert_eq!(stats.first_completed_at(), Some(now));
     }
 
+    #[test]
+    fn test_changed_content_makes_bests_legacy() {
+        let now = Utc::now();
+        let stats = ChallengeStats::completed("test-1".to_string(), Duration::from_secs(5), Some(4), now)
+            .with_content_version("aaaa");
+        assert!(!stats.clone().with_content_version("aaaa").has_legacy_bests());
+
+        let changed = stats.with_content_version("bbbb");
+        assert!(changed.has_legacy_bests());
+        assert_eq!(changed.content_version(), Some("bbbb"));
+        assert_eq!(changed.is_new_record(Duration::from_secs(9), Some(9)), (true, true));
+
+        // A slower run on the new version replaces the legacy bests
+        let updated = changed.record_attempt(true, Duration::from_secs(9), Some(9), now);
+        assert!(!updated.has_legacy_bests());
+        assert_eq!(updated.best_time(), Some(Duration::from_secs(9)));
+        assert_eq!(updated.best_keystrokes(), Some(9));
+        assert_eq!(updated.attempt_count(), 2);
+    }
+
+    #[test]
+    fn test_unversioned_stats_adopt_version() {
+        let stats = ChallengeStats::completed("test-1".to_string(), Duration::from_secs(5), None, Utc::now());
+        assert!(!stats.with_content_version("aaaa").has_legacy_bests());
+    }
+
     #[test]
     fn test_record_better_attempt() {
         let now = Utc::now();
@@ -233,4 +477,81 @@ mod tests {
         assert!(new_time);
         assert!(!new_ks);
     }
+
+    #[test]
+    fn test_blind_attempt_tracked_as_separate_category() {
+        let now = Utc::now();
+        let stats = ChallengeStats::new("test-1".to_string())
+            .record(&Attempt::new(true, Duration::from_secs(10), Some(20), now))
+            .record(&Attempt::new(true, Duration::from_secs(40), Some(60), now).with_blind(true));
+
+        assert_eq!(stats.attempt_count(), 2);
+        assert_eq!(stats.mastery_tier(), Some(MasteryTier::Gold));
+        assert!(stats.is_blind_completed());
+        assert_eq!(stats.blind_best_time(), Some(Duration::from_secs(40)));
+        assert_eq!(stats.blind_mastery_tier(), Some(MasteryTier::Bronze));
+    }
+
+    #[test]
+    fn test_blind_attempt_keeps_regular_bests() {
+        let now = Utc::now();
+        let stats = ChallengeStats::new("test-1".to_string())
+            .record(&Attempt::new(true, Duration::from_secs(5), Some(10), now).with_blind(true));
+
+        assert!(stats.is_completed());
+        assert_eq!(stats.best_time(), None);
+        assert_eq!(stats.best_keystrokes(), None);
+        assert_eq!(stats.mastery_tier(), Some(MasteryTier::Bronze));
+        assert_eq!(stats.blind_best_time(), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_failed_blind_attempt_does_not_complete_blind() {
+        let stats = ChallengeStats::new("test-1".to_string()).record(
+            &Attempt::new(false, Duration::from_secs(40), None, Utc::now()).with_blind(true),
+        );
+        assert!(!stats.is_blind_completed());
+        assert!(stats.blind_mastery_tier().is_none());
+    }
+
+    #[test]
+    fn test_thresholds_change_tier_without_new_attempt() {
+        let stats = ChallengeStats::completed(
+            "test-1".to_string(),
+            Duration::from_secs(50),
+            Some(90),
+            Utc::now(),
+        );
+        assert_eq!(stats.mastery_tier(), Some(MasteryTier::Bronze));
+
+        let thresholds = TierThresholds::new(Some(80), Some(Duration::from_secs(60)));
+        let stats = stats.with_thresholds(thresholds);
+        assert_eq!(stats.mastery_tier(), Some(MasteryTier::Gold));
+    }
+
+    #[test]
+    fn test_best_score_keeps_highest() {
+        let now = Utc::now();
+        let stats = ChallengeStats::new("test-1".to_string())
+            .record(&Attempt::new(true, Duration::from_secs(10), Some(20), now).with_score(Score::new(700)))
+            .record(&Attempt::new(true, Duration::from_secs(30), Some(40), now).with_score(Score::new(400)));
+        assert_eq!(stats.best_score(), Some(Score::new(700)));
+        let points: Vec<u32> = stats.scores().iter().map(|(_, score)| score.points()).collect();
+        assert_eq!(points, [700, 400]);
+    }
+
+    #[test]
+    fn test_hints_cap_tier() {
+        let now = Utc::now();
+        let hinted = Attempt::new(true, Duration::from_secs(5), Some(10), now).with_hints_used(1);
+        let stats = ChallengeStats::new("test-1".to_string()).record(&hinted);
+        assert_eq!(stats.mastery_tier(), Some(HINTED_TIER_CAP));
+        assert!(!stats.is_solved_without_hints());
+
+        // A slower hint-free solve lifts the tier past the cap
+        let stats = stats.record(&Attempt::new(true, Duration::from_secs(12), Some(25), now));
+        assert_eq!(stats.mastery_tier(), Some(MasteryTier::Gold));
+        assert!(stats.is_solved_without_hints());
+        assert_eq!(stats.best_time(), Some(Duration::from_secs(5)));
+    }
 }
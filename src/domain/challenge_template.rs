@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::domain::ChallengeFile;
+
+/// How generated identifiers are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierCase {
+    /// `user_count`
+    Snake,
+    /// `userCount`
+    Camel,
+    /// `UserCount`
+    Pascal,
+}
+
+impl IdentifierCase {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "snake" => Some(Self::Snake),
+            "camel" => Some(Self::Camel),
+            "pascal" => Some(Self::Pascal),
+            _ => None,
+        }
+    }
+
+    /// Joins lowercase words in this case
+    pub fn join(&self, words: &[&str]) -> String {
+        match self {
+            Self::Snake => words.join("_"),
+            Self::Camel | Self::Pascal => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 && *self == Self::Camel {
+                        word.to_string()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// What a template parameter expands to
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamKind {
+    /// A two-word identifier, distinct from the template's other identifiers
+    Identifier { case: IdentifierCase },
+    /// An integer in `min..=max`
+    Number { min: i64, max: i64 },
+    /// `min..=max` identifiers joined by `separator`
+    List {
+        min: usize,
+        max: usize,
+        separator: String,
+    },
+    /// `min..=max` lines of filler code
+    Lorem { min: usize, max: usize },
+}
+
+/// A named placeholder, written `{{name}}` in template text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateParam {
+    name: String,
+    kind: ParamKind,
+}
+
+impl TemplateParam {
+    pub fn new(name: impl Into<String>, kind: ParamKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &ParamKind {
+        &self.kind
+    }
+}
+
+/// Parameterised challenge text that is filled in afresh for every attempt
+///
+/// The files hold `{{name}}` placeholders. `optimal_keystrokes` counts the
+/// keys of the optimal solution outside its placeholders; each placeholder
+/// in the solution adds one keystroke per character it expands to, so
+/// typed parameters are scored against the text actually generated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChallengeTemplate {
+    params: Vec<TemplateParam>,
+    files: Vec<ChallengeFile>,
+    optimal_solution: Option<(String, u32)>,
+}
+
+impl ChallengeTemplate {
+    pub fn new(params: Vec<TemplateParam>, files: Vec<ChallengeFile>) -> Self {
+        Self {
+            params,
+            files,
+            optimal_solution: None,
+        }
+    }
+
+    pub fn with_optimal_solution(mut self, solution: impl Into<String>, keystrokes: u32) -> Self {
+        self.optimal_solution = Some((solution.into(), keystrokes));
+        self
+    }
+
+    pub fn params(&self) -> &[TemplateParam] {
+        &self.params
+    }
+
+    pub fn files(&self) -> &[ChallengeFile] {
+        &self.files
+    }
+
+    /// Placeholders in the text that no parameter defines
+    pub fn unknown_placeholders(&self) -> Vec<String> {
+        let texts = self
+            .files
+            .iter()
+            .flat_map(|file| [file.path(), file.starting_content(), file.target_content()])
+            .chain(self.optimal_solution.iter().map(|(solution, _)| solution.as_str()));
+
+        let mut unknown: Vec<String> = texts
+            .flat_map(placeholders)
+            .filter(|name| !self.params.iter().any(|param| param.name() == *name))
+            .map(str::to_string)
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+        unknown
+    }
+
+    /// The files with every placeholder replaced by its value
+    pub fn render_files(&self, values: &HashMap<String, String>) -> Vec<ChallengeFile> {
+        self.files
+            .iter()
+            .map(|file| {
+                ChallengeFile::new(
+                    render(file.path(), values),
+                    render(file.starting_content(), values),
+                    render(file.target_content(), values),
+                )
+            })
+            .collect()
+    }
+
+    /// The optimal solution and its keystroke count for these values
+    pub fn render_solution(&self, values: &HashMap<String, String>) -> Option<(String, u32)> {
+        let (solution, keystrokes) = self.optimal_solution.as_ref()?;
+        let typed: usize = placeholders(solution)
+            .filter_map(|name| values.get(name))
+            .map(|value| value.chars().count())
+            .sum();
+        Some((render(solution, values), keystrokes + typed as u32))
+    }
+}
+
+/// Names of the `{{name}}` placeholders in a text, in order
+fn placeholders(text: &str) -> impl Iterator<Item = &str> {
+    text.split("{{")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("}}"))
+        .map(|(name, _)| name.trim())
+}
+
+fn render(text: &str, values: &HashMap<String, String>) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(length) = rest[start..].find("}}") else {
+            break;
+        };
+        rendered.push_str(&rest[..start]);
+        let name = rest[start + 2..start + length].trim();
+        match values.get(name) {
+            Some(value) => rendered.push_str(value),
+            None => rendered.push_str(&rest[start..start + length + 2]),
+        }
+        rest = &rest[start + length + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> HashMap<String, String> {
+        HashMap::from([
+            ("old".to_string(), "user_count".to_string()),
+            ("new".to_string(), "total".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_render_replaces_placeholders() {
+        let template = ChallengeTemplate::new(
+            vec![
+                TemplateParam::new("old", ParamKind::Identifier { case: IdentifierCase::Snake }),
+                TemplateParam::new("new", ParamKind::Identifier { case: IdentifierCase::Snake }),
+            ],
+            vec![ChallengeFile::new("a.rs", "let {{old}} = 1; {{ old }}", "let {{new}} = 1; {{old}}")],
+        )
+        .with_optimal_solution("miwc{{new}}<esc>", 5);
+
+        let files = template.render_files(&values());
+        assert_eq!(files[0].starting_content(), "let user_count = 1; user_count");
+        assert_eq!(files[0].target_content(), "let total = 1; user_count");
+        assert_eq!(
+            template.render_solution(&values()),
+            Some(("miwctotal<esc>".to_string(), 10))
+        );
+        assert!(template.unknown_placeholders().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_placeholders_are_reported() {
+        let template = ChallengeTemplate::new(
+            Vec::new(),
+            vec![ChallengeFile::new("a.rs", "{{count}} {{", "{{count}}")],
+        );
+        assert_eq!(template.unknown_placeholders(), ["count"]);
+    }
+
+    #[test]
+    fn test_identifier_cases() {
+        let words = ["user", "count"];
+        assert_eq!(IdentifierCase::Snake.join(&words), "user_count");
+        assert_eq!(IdentifierCase::Camel.join(&words), "userCount");
+        assert_eq!(IdentifierCase::Pascal.join(&words), "UserCount");
+    }
+}
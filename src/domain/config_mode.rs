@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Editor configuration a challenge session runs with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ConfigMode {
+    /// Editor defaults only, isolated from the user's own config files.
+    /// Optimal keystrokes and hints assume this mode.
+    #[default]
+    Vanilla,
+    /// The user's personal configuration. Remapped keys make results
+    /// incomparable, so these attempts are tracked separately.
+    Personal,
+}
+
+impl ConfigMode {
+    /// Get the display name of the mode
+    pub fn name(&self) -> &str {
+        match self {
+            ConfigMode::Vanilla => "Vanilla",
+            ConfigMode::Personal => "My config",
+        }
+    }
+
+    /// Switch to the other mode
+    pub fn toggle(self) -> Self {
+        match self {
+            ConfigMode::Vanilla => ConfigMode::Personal,
+            ConfigMode::Personal => ConfigMode::Vanilla,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_vanilla() {
+        assert_eq!(ConfigMode::default(), ConfigMode::Vanilla);
+    }
+
+    #[test]
+    fn test_toggle() {
+        assert_eq!(ConfigMode::Vanilla.toggle(), ConfigMode::Personal);
+        assert_eq!(ConfigMode::Personal.toggle(), ConfigMode::Vanilla);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::score::Score;
+use chrono::NaiveDate;
+use std::time::Duration;
+
+/// The one scored attempt at a day's daily challenge
+///
+/// Kept apart from free practice: daily results never change a challenge's
+/// bests or tiers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyResult {
+    date: NaiveDate,
+    challenge_id: String,
+    completed: bool,
+    time: Duration,
+    keystrokes: Option<u32>,
+    score: Score,
+}
+
+impl DailyResult {
+    pub fn new(
+        date: NaiveDate,
+        challenge_id: impl Into<String>,
+        completed: bool,
+        time: Duration,
+        keystrokes: Option<u32>,
+        score: Score,
+    ) -> Self {
+        Self {
+            date,
+            challenge_id: challenge_id.into(),
+            completed,
+            time,
+            keystrokes,
+            score,
+        }
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn challenge_id(&self) -> &str {
+        &self.challenge_id
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn keystrokes(&self) -> Option<u32> {
+        self.keystrokes
+    }
+
+    pub fn score(&self) -> Score {
+        self.score
+    }
+}
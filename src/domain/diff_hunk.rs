@@ -0,0 +1,114 @@
+/// One line of a diff hunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HunkLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+/// A contiguous change to one file in one commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    commit: String,
+    subject: String,
+    path: String,
+    lines: Vec<HunkLine>,
+}
+
+impl DiffHunk {
+    pub fn new(
+        commit: impl Into<String>,
+        subject: impl Into<String>,
+        path: impl Into<String>,
+        lines: Vec<HunkLine>,
+    ) -> Self {
+        Self {
+            commit: commit.into(),
+            subject: subject.into(),
+            path: path.into(),
+            lines,
+        }
+    }
+
+    /// Abbreviated commit hash
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+
+    /// First line of the commit message
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Path of the changed file, relative to the repository root
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn lines(&self) -> &[HunkLine] {
+        &self.lines
+    }
+
+    /// The text before the change: context and removed lines
+    pub fn before(&self) -> String {
+        self.side(|line| match line {
+            HunkLine::Context(text) | HunkLine::Removed(text) => Some(text),
+            HunkLine::Added(_) => None,
+        })
+    }
+
+    /// The text after the change: context and added lines
+    pub fn after(&self) -> String {
+        self.side(|line| match line {
+            HunkLine::Context(text) | HunkLine::Added(text) => Some(text),
+            HunkLine::Removed(_) => None,
+        })
+    }
+
+    pub fn removed_count(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| matches!(line, HunkLine::Removed(_)))
+            .count()
+    }
+
+    pub fn added_count(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| matches!(line, HunkLine::Added(_)))
+            .count()
+    }
+
+    fn side(&self, pick: impl Fn(&HunkLine) -> Option<&String>) -> String {
+        self.lines
+            .iter()
+            .filter_map(pick)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_before_and_after() {
+        let hunk = DiffHunk::new(
+            "abc1234",
+            "Rename total",
+            "src/lib.rs",
+            vec![
+                HunkLine::Context("fn main() {".to_string()),
+                HunkLine::Removed("    let total = 1;".to_string()),
+                HunkLine::Added("    let sum = 1;".to_string()),
+                HunkLine::Context("}".to_string()),
+            ],
+        );
+
+        assert_eq!(hunk.before(), "fn main() {\n    let total = 1;\n}");
+        assert_eq!(hunk.after(), "fn main() {\n    let sum = 1;\n}");
+        assert_eq!((hunk.removed_count(), hunk.added_count()), (1, 1));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::domain::{MasteryTier, TierThresholds};
+use std::time::Duration;
+
+/// One run of a drilled challenge
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrillRun {
+    time: Duration,
+    keystrokes: Option<u32>,
+    /// None if the run was left unsolved
+    tier: Option<MasteryTier>,
+}
+
+impl DrillRun {
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn keystrokes(&self) -> Option<u32> {
+        self.keystrokes
+    }
+
+    pub fn tier(&self) -> Option<MasteryTier> {
+        self.tier
+    }
+}
+
+/// Repeating one challenge until a tier is reached several runs in a row
+#[derive(Debug, Clone)]
+pub struct Drill {
+    target: MasteryTier,
+    required_streak: u32,
+    thresholds: TierThresholds,
+    runs: Vec<DrillRun>,
+    streak: u32,
+}
+
+impl Drill {
+    pub fn new(target: MasteryTier, required_streak: u32, thresholds: TierThresholds) -> Self {
+        Self {
+            target,
+            required_streak: required_streak.max(1),
+            thresholds,
+            runs: Vec::new(),
+            streak: 0,
+        }
+    }
+
+    /// Record a run; any run below the target tier resets the streak
+    pub fn record(&mut self, completed: bool, time: Duration, keystrokes: Option<u32>) -> &DrillRun {
+        let tier = completed.then(|| self.thresholds.tier(time, keystrokes));
+        if tier.is_some_and(|tier| tier >= self.target) {
+            self.streak += 1;
+        } else {
+            self.streak = 0;
+        }
+
+        self.runs.push(DrillRun {
+            time,
+            keystrokes,
+            tier,
+        });
+        self.runs.last().unwrap()
+    }
+
+    pub fn target(&self) -> MasteryTier {
+        self.target
+    }
+
+    pub fn required_streak(&self) -> u32 {
+        self.required_streak
+    }
+
+    /// Runs in a row at or above the target tier
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    pub fn runs(&self) -> &[DrillRun] {
+        &self.runs
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.streak >= self.required_streak
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streak_resets_below_target() {
+        let thresholds = TierThresholds::new(Some(10), None);
+        let mut drill = Drill::new(MasteryTier::Gold, 2, thresholds);
+
+        drill.record(true, Duration::from_secs(5), Some(11));
+        assert_eq!(drill.streak(), 1);
+        // Silver keystrokes break the streak
+        assert_eq!(drill.record(true, Duration::from_secs(5), Some(14)).tier(), Some(MasteryTier::Silver));
+        assert_eq!(drill.streak(), 0);
+        assert_eq!(drill.record(false, Duration::from_secs(40), None).tier(), None);
+
+        drill.record(true, Duration::from_secs(5), Some(10));
+        assert!(!drill.is_complete());
+        drill.record(true, Duration::from_secs(4), Some(12));
+        assert!(drill.is_complete());
+        assert_eq!(drill.runs().len(), 5);
+    }
+}
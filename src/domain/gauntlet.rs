@@ -0,0 +1,172 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Which challenges a gauntlet chains together
+///
+/// The key of a selection identifies its leaderboard.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GauntletSelection {
+    /// Random challenges from the whole set
+    Random { count: usize },
+    /// Random challenges sharing a tag
+    Tag { tag: String, count: usize },
+    /// A learning path stage, run in order
+    Stage {
+        path: String,
+        stage: String,
+        challenge_ids: Vec<String>,
+    },
+}
+
+impl GauntletSelection {
+    pub fn name(&self) -> String {
+        match self {
+            GauntletSelection::Random { count } => format!("Random ×{}", count),
+            GauntletSelection::Tag { tag, count } => format!("{} ×{}", tag, count),
+            GauntletSelection::Stage { path, stage, .. } => format!("{}: {}", path, stage),
+        }
+    }
+
+    /// Leaderboard key, distinct for stages sharing a name across paths
+    pub fn key(&self) -> String {
+        match self {
+            GauntletSelection::Random { count } => format!("random/{}", count),
+            GauntletSelection::Tag { tag, count } => format!("tag/{}/{}", tag, count),
+            GauntletSelection::Stage { path, stage, .. } => format!("stage/{}/{}", path, stage),
+        }
+    }
+}
+
+/// One leg of a gauntlet
+#[derive(Debug, Clone, PartialEq)]
+pub struct GauntletSplit {
+    challenge_id: String,
+    time: Duration,
+    keystrokes: Option<u32>,
+    completed: bool,
+}
+
+impl GauntletSplit {
+    pub fn new(
+        challenge_id: impl Into<String>,
+        time: Duration,
+        keystrokes: Option<u32>,
+        completed: bool,
+    ) -> Self {
+        Self {
+            challenge_id: challenge_id.into(),
+            time,
+            keystrokes,
+            completed,
+        }
+    }
+
+    pub fn challenge_id(&self) -> &str {
+        &self.challenge_id
+    }
+
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn keystrokes(&self) -> Option<u32> {
+        self.keystrokes
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+}
+
+/// Result of a gauntlet run, kept on its own leaderboard
+///
+/// `total_time` is the global clock from the first leg's start to the last
+/// leg's end, including switching between challenges. A gauntlet ends at
+/// the first leg left unsolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GauntletResult {
+    key: String,
+    name: String,
+    finished_at: DateTime<Utc>,
+    total_time: Duration,
+    legs: usize,
+    splits: Vec<GauntletSplit>,
+}
+
+impl GauntletResult {
+    pub fn new(
+        key: impl Into<String>,
+        name: impl Into<String>,
+        finished_at: DateTime<Utc>,
+        total_time: Duration,
+        legs: usize,
+        splits: Vec<GauntletSplit>,
+    ) -> Self {
+        Self {
+            key: key.into(),
+            name: name.into(),
+            finished_at,
+            total_time,
+            legs,
+            splits,
+        }
+    }
+
+    /// Key of the selection the run was made from
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn finished_at(&self) -> DateTime<Utc> {
+        self.finished_at
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.total_time
+    }
+
+    /// Number of challenges the gauntlet was made of
+    pub fn legs(&self) -> usize {
+        self.legs
+    }
+
+    pub fn splits(&self) -> &[GauntletSplit] {
+        &self.splits
+    }
+
+    /// Whether every leg was solved
+    pub fn is_completed(&self) -> bool {
+        self.legs > 0
+            && self.splits.len() == self.legs
+            && self.splits.iter().all(GauntletSplit::is_completed)
+    }
+
+    /// Total keystrokes, if every leg was recorded
+    pub fn total_keystrokes(&self) -> Option<u32> {
+        self.splits.iter().map(GauntletSplit::keystrokes).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(id: &str, completed: bool) -> GauntletSplit {
+        GauntletSplit::new(id, Duration::from_secs(10), Some(5), completed)
+    }
+
+    #[test]
+    fn test_completed_only_when_every_leg_solved() {
+        let at = Utc::now();
+        let done = GauntletResult::new("g", "g", at, Duration::from_secs(25), 2, vec![split("a", true), split("b", true)]);
+        assert!(done.is_completed());
+        assert_eq!(done.total_keystrokes(), Some(10));
+
+        let abandoned = GauntletResult::new("g", "g", at, Duration::from_secs(25), 3, vec![split("a", true), split("b", false)]);
+        assert!(!abandoned.is_completed());
+    }
+}
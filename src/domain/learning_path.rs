@@ -0,0 +1,97 @@
+use crate::domain::Progress;
+
+/// One stage of a learning path: a named, ordered group of challenges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathStage {
+    name: String,
+    challenge_ids: Vec<String>,
+}
+
+impl PathStage {
+    pub fn new(name: impl Into<String>, challenge_ids: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            challenge_ids,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn challenge_ids(&self) -> &[String] {
+        &self.challenge_ids
+    }
+
+    /// Number of the stage's challenges completed so far
+    pub fn completed_count(&self, progress: &Progress) -> usize {
+        self.challenge_ids
+            .iter()
+            .filter(|id| {
+                progress
+                    .get_challenge_stats(id)
+                    .is_some_and(|stats| stats.is_completed())
+            })
+            .count()
+    }
+
+    pub fn is_complete(&self, progress: &Progress) -> bool {
+        self.completed_count(progress) == self.challenge_ids.len()
+    }
+}
+
+/// An ordered curriculum through a challenge pack
+/// (e.g. "Movement basics → Selections → Refactoring")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LearningPath {
+    name: String,
+    stages: Vec<PathStage>,
+}
+
+impl LearningPath {
+    pub fn new(name: impl Into<String>, stages: Vec<PathStage>) -> Self {
+        Self {
+            name: name.into(),
+            stages,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn stages(&self) -> &[PathStage] {
+        &self.stages
+    }
+
+    /// The first stage that still has incomplete challenges
+    pub fn current_stage(&self, progress: &Progress) -> Option<&PathStage> {
+        self.stages.iter().find(|stage| !stage.is_complete(progress))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stage_completion() {
+        let path = LearningPath::new(
+            "Basics",
+            vec![
+                PathStage::new("Movement", vec!["a".to_string(), "b".to_string()]),
+                PathStage::new("Editing", vec!["c".to_string()]),
+            ],
+        );
+        let mut progress = Progress::new();
+        progress.record_attempt("a".to_string(), true, Duration::from_secs(5), None, Utc::now());
+
+        assert_eq!(path.stages()[0].completed_count(&progress), 1);
+        assert_eq!(path.current_stage(&progress).unwrap().name(), "Movement");
+
+        progress.record_attempt("b".to_string(), true, Duration::from_secs(5), None, Utc::now());
+        assert_eq!(path.current_stage(&progress).unwrap().name(), "Editing");
+    }
+}
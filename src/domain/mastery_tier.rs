@@ -6,38 +6,167 @@ pub enum MasteryTier {
     Bronze,
     Silver,
     Gold,
+    /// Gold while matching or beating the challenge's optimal keystrokes
+    Platinum,
+}
+
+/// Highest tier a result achieved with hints can earn
+pub const HINTED_TIER_CAP: MasteryTier = MasteryTier::Silver;
+
+/// Keystrokes within this percentage of optimal earn gold
+const GOLD_KEYSTROKE_PERCENT: u32 = 120;
+/// Keystrokes within this percentage of optimal earn silver
+const SILVER_KEYSTROKE_PERCENT: u32 = 150;
+/// Time within this multiple of par earns silver (gold needs par)
+const SILVER_PAR_MULTIPLIER: u32 = 2;
+
+/// Global thresholds used when a challenge defines no per-challenge values
+const GLOBAL_GOLD_SECS: u64 = 15;
+const GLOBAL_GOLD_KEYSTROKES: u32 = 30;
+const GLOBAL_SILVER_SECS: u64 = 30;
+const GLOBAL_SILVER_KEYSTROKES: u32 = 50;
+
+/// Per-challenge thresholds for mastery tiers
+///
+/// Keystroke limits are relative to the challenge's `optimal_keystrokes`
+/// (gold within 120%, silver within 150%, rounded up, with silver always
+/// allowing more keys than gold) and time limits to its `par_time`
+/// (gold within par, silver within twice par). Either dimension falls back
+/// to the global rule when the challenge doesn't define it. Time-only
+/// thresholds ignore keystrokes and top out at gold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TierThresholds {
+    optimal_keystrokes: Option<u32>,
+    par_time: Option<Duration>,
+    time_only: bool,
+}
+
+impl TierThresholds {
+    pub fn new(optimal_keystrokes: Option<u32>, par_time: Option<Duration>) -> Self {
+        Self {
+            optimal_keystrokes,
+            par_time,
+            time_only: false,
+        }
+    }
+
+    /// Thresholds that rank on time alone
+    pub fn time_only(par_time: Option<Duration>) -> Self {
+        Self {
+            optimal_keystrokes: None,
+            par_time,
+            time_only: true,
+        }
+    }
+
+    /// Thresholds that apply the global rule to every challenge
+    pub fn global() -> Self {
+        Self::default()
+    }
+
+    pub fn optimal_keystrokes(&self) -> Option<u32> {
+        self.optimal_keystrokes
+    }
+
+    pub fn par_time(&self) -> Option<Duration> {
+        self.par_time
+    }
+
+    /// Whether keystrokes count toward tiers and scores
+    pub fn ranks_keystrokes(&self) -> bool {
+        !self.time_only
+    }
+
+    /// Maximum keystrokes for gold and silver
+    fn keystroke_limits(&self) -> (u32, u32) {
+        match self.optimal_keystrokes {
+            Some(optimal) => {
+                // Rounding down would make the limits equal on short solutions
+                let gold = (optimal * GOLD_KEYSTROKE_PERCENT).div_ceil(100);
+                let silver = (optimal * SILVER_KEYSTROKE_PERCENT).div_ceil(100).max(gold + 1);
+                (gold, silver)
+            }
+            // The global rule is strict ("under 30"), so subtract one
+            None => (GLOBAL_GOLD_KEYSTROKES - 1, GLOBAL_SILVER_KEYSTROKES - 1),
+        }
+    }
+
+    fn meets_time(&self, time: Duration, gold: bool) -> bool {
+        match self.par_time {
+            Some(par) if gold => time <= par,
+            Some(par) => time <= par * SILVER_PAR_MULTIPLIER,
+            None if gold => time.as_secs() < GLOBAL_GOLD_SECS,
+            None => time.as_secs() < GLOBAL_SILVER_SECS,
+        }
+    }
+
+    /// Calculate the tier earned by a completed attempt
+    ///
+    /// Platinum needs gold time with at most the optimal keystrokes, so it
+    /// only exists for challenges that define `optimal_keystrokes`. Gold,
+    /// silver and platinum require a keystroke count; without one (no
+    /// recording) a completion is bronze, unless the thresholds are time-only.
+    pub fn tier(&self, time: Duration, keystrokes: Option<u32>) -> MasteryTier {
+        if self.time_only {
+            return if self.meets_time(time, true) {
+                MasteryTier::Gold
+            } else if self.meets_time(time, false) {
+                MasteryTier::Silver
+            } else {
+                MasteryTier::Bronze
+            };
+        }
+        let Some(keystrokes) = keystrokes else {
+            return MasteryTier::Bronze;
+        };
+        let (gold_keystrokes, silver_keystrokes) = self.keystroke_limits();
+
+        let meets_gold_time = self.meets_time(time, true);
+        if meets_gold_time && self.optimal_keystrokes.is_some_and(|optimal| keystrokes <= optimal) {
+            MasteryTier::Platinum
+        } else if meets_gold_time && keystrokes <= gold_keystrokes {
+            MasteryTier::Gold
+        } else if self.meets_time(time, false) && keystrokes <= silver_keystrokes {
+            MasteryTier::Silver
+        } else {
+            MasteryTier::Bronze
+        }
+    }
 }
 
 impl MasteryTier {
-    /// Calculate mastery tier based on time and keystrokes
+    /// Calculate mastery tier based on time and keystrokes using the global rule
     ///
     /// Tiers:
     /// - 🥉 Bronze: Complete the challenge
     /// - 🥈 Silver: Complete under 30s and 50 keystrokes
     /// - 🥇 Gold: Complete under 15s and 30 keystrokes
+    ///
+    /// Platinum requires an optimal keystroke count, so the global rule
+    /// never awards it.
     pub fn calculate(time: Duration, keystrokes: Option<u32>) -> Self {
-        let time_secs = time.as_secs();
-
-        // Gold tier requirements: under 15s AND under 30 keystrokes
-        if time_secs < 15 {
-            if let Some(ks) = keystrokes {
-                if ks < 30 {
-                    return MasteryTier::Gold;
-                }
-            }
-        }
+        TierThresholds::global().tier(time, keystrokes)
+    }
 
-        // Silver tier requirements: under 30s AND under 50 keystrokes
-        if time_secs < 30 {
-            if let Some(ks) = keystrokes {
-                if ks < 50 {
-                    return MasteryTier::Silver;
-                }
-            }
-        }
+    /// Calculate mastery tier using a challenge's own thresholds
+    pub fn calculate_with(
+        time: Duration,
+        keystrokes: Option<u32>,
+        thresholds: &TierThresholds,
+    ) -> Self {
+        thresholds.tier(time, keystrokes)
+    }
 
-        // Bronze tier: just completed
-        MasteryTier::Bronze
+    /// Parse a tier from its name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            MasteryTier::Bronze,
+            MasteryTier::Silver,
+            MasteryTier::Gold,
+            MasteryTier::Platinum,
+        ]
+        .into_iter()
+        .find(|tier| tier.name().eq_ignore_ascii_case(name))
     }
 
     /// Get the display name of the tier
@@ -46,15 +175,17 @@ impl MasteryTier {
             MasteryTier::Bronze => "Bronze",
             MasteryTier::Silver => "Silver",
             MasteryTier::Gold => "Gold",
+            MasteryTier::Platinum => "Platinum",
         }
     }
 
     /// Get the emoji representation of the tier
-    pub fn emoji(&self) -> &str {
+    pub fn emoji(&self) -> &'static str {
         match self {
             MasteryTier::Bronze => "🥉",
             MasteryTier::Silver => "🥈",
             MasteryTier::Gold => "🥇",
+            MasteryTier::Platinum => "💎",
         }
     }
 }
@@ -87,8 +218,78 @@ mod tests {
         assert_eq!(tier, MasteryTier::Bronze);
     }
 
+    #[test]
+    fn test_time_only_thresholds_ignore_keystrokes() {
+        let thresholds = TierThresholds::time_only(Some(Duration::from_secs(10)));
+        assert_eq!(thresholds.tier(Duration::from_secs(10), None), MasteryTier::Gold);
+        assert_eq!(thresholds.tier(Duration::from_secs(5), Some(500)), MasteryTier::Gold);
+        assert_eq!(thresholds.tier(Duration::from_secs(20), Some(1)), MasteryTier::Silver);
+        assert_eq!(thresholds.tier(Duration::from_secs(21), Some(1)), MasteryTier::Bronze);
+    }
+
+    #[test]
+    fn test_relative_keystroke_thresholds() {
+        // 10 optimal keystrokes: gold up to 12, silver up to 15
+        let thresholds = TierThresholds::new(Some(10), None);
+        let time = Duration::from_secs(5);
+        assert_eq!(MasteryTier::calculate_with(time, Some(10), &thresholds), MasteryTier::Platinum);
+        assert_eq!(MasteryTier::calculate_with(time, Some(12), &thresholds), MasteryTier::Gold);
+        assert_eq!(MasteryTier::calculate_with(time, Some(15), &thresholds), MasteryTier::Silver);
+        assert_eq!(MasteryTier::calculate_with(time, Some(16), &thresholds), MasteryTier::Bronze);
+    }
+
+    #[test]
+    fn test_short_solutions_keep_every_tier() {
+        // 2 optimal keystrokes: gold up to 3, silver up to 4
+        let thresholds = TierThresholds::new(Some(2), None);
+        let time = Duration::from_secs(5);
+        assert_eq!(MasteryTier::calculate_with(time, Some(2), &thresholds), MasteryTier::Platinum);
+        assert_eq!(MasteryTier::calculate_with(time, Some(3), &thresholds), MasteryTier::Gold);
+        assert_eq!(MasteryTier::calculate_with(time, Some(4), &thresholds), MasteryTier::Silver);
+        assert_eq!(MasteryTier::calculate_with(time, Some(5), &thresholds), MasteryTier::Bronze);
+    }
+
+    #[test]
+    fn test_long_refactor_can_be_gold() {
+        let thresholds = TierThresholds::new(Some(80), Some(Duration::from_secs(60)));
+        let tier = MasteryTier::calculate_with(Duration::from_secs(55), Some(90), &thresholds);
+        assert_eq!(tier, MasteryTier::Gold);
+        assert_eq!(MasteryTier::calculate(Duration::from_secs(55), Some(90)), MasteryTier::Bronze);
+    }
+
+    #[test]
+    fn test_trivial_challenge_is_not_trivially_gold() {
+        let thresholds = TierThresholds::new(Some(3), None);
+        let tier = MasteryTier::calculate_with(Duration::from_secs(5), Some(10), &thresholds);
+        assert_eq!(tier, MasteryTier::Bronze);
+    }
+
+    #[test]
+    fn test_par_time_falls_back_to_global_keystrokes() {
+        let thresholds = TierThresholds::new(None, Some(Duration::from_secs(60)));
+        let tier = MasteryTier::calculate_with(Duration::from_secs(90), Some(40), &thresholds);
+        assert_eq!(tier, MasteryTier::Silver);
+    }
+
+    #[test]
+    fn test_platinum_needs_gold_time() {
+        let thresholds = TierThresholds::new(Some(10), Some(Duration::from_secs(20)));
+        let tier = MasteryTier::calculate_with(Duration::from_secs(25), Some(9), &thresholds);
+        assert_eq!(tier, MasteryTier::Silver);
+        // No optimal solution, no platinum
+        assert_eq!(MasteryTier::calculate(Duration::from_secs(1), Some(1)), MasteryTier::Gold);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(MasteryTier::from_name("silver"), Some(MasteryTier::Silver));
+        assert_eq!(MasteryTier::from_name("Platinum"), Some(MasteryTier::Platinum));
+        assert_eq!(MasteryTier::from_name("diamond"), None);
+    }
+
     #[test]
     fn test_tier_ordering() {
+        assert!(MasteryTier::Platinum > MasteryTier::Gold);
         assert!(MasteryTier::Gold > MasteryTier::Silver);
         assert!(MasteryTier::Silver > MasteryTier::Bronze);
     }
@@ -6,12 +6,39 @@ pub mod challenge_stats;
 pub mod progress;
 pub mod mastery_tier;
 pub mod achievement;
+pub mod config_mode;
+pub mod attempt;
+pub mod blind_mode;
+pub mod score;
+pub mod review_state;
+pub mod learning_path;
+pub mod skill_level;
+pub mod gauntlet;
+pub mod daily_result;
+pub mod drill;
+pub mod challenge_template;
+pub mod diff_hunk;
+pub mod pack_manifest;
+pub mod fnv;
 
-pub use challenge::Challenge;
+pub use challenge::{Challenge, ChallengeFile};
 pub use solution::Solution;
 pub use key_sequence::KeySequence;
 pub use recording::Recording;
 pub use challenge_stats::ChallengeStats;
 pub use progress::Progress;
-pub use mastery_tier::MasteryTier;
+pub use mastery_tier::{MasteryTier, TierThresholds};
 pub use achievement::{Achievement, AchievementId, UnlockedAchievement};
+pub use config_mode::ConfigMode;
+pub use attempt::Attempt;
+pub use blind_mode::BlindMode;
+pub use score::Score;
+pub use review_state::ReviewState;
+pub use learning_path::{LearningPath, PathStage};
+pub use skill_level::SkillLevel;
+pub use gauntlet::{GauntletResult, GauntletSelection, GauntletSplit};
+pub use daily_result::DailyResult;
+pub use drill::Drill;
+pub use diff_hunk::{DiffHunk, HunkLine};
+pub use pack_manifest::PackManifest;
+pub use challenge_template::{ChallengeTemplate, IdentifierCase, ParamKind, TemplateParam};
@@ -0,0 +1,139 @@
+use std::cmp::Ordering;
+
+/// Separates the pack name from the challenge id in namespaced ids
+/// (`rust-refactors/rename-01`)
+pub const PACK_SEPARATOR: char = '/';
+
+/// Id of a challenge from an installed pack, so packs never collide with
+/// each other or with the bundled challenges
+pub fn namespaced_id(pack: &str, id: &str) -> String {
+    format!("{}{}{}", pack, PACK_SEPARATOR, id)
+}
+
+/// Description of a challenge pack, read from its `pack.toml`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackManifest {
+    name: String,
+    version: String,
+    author: Option<String>,
+    /// Editors the challenges were written for (e.g. `helix`); empty means any
+    editors: Vec<String>,
+    license: Option<String>,
+    /// Oldest editor-dojo version that can load the pack
+    min_tool_version: Option<String>,
+}
+
+impl PackManifest {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            author: None,
+            editors: Vec::new(),
+            license: None,
+            min_tool_version: None,
+        }
+    }
+
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    pub fn with_editors(mut self, editors: Vec<String>) -> Self {
+        self.editors = editors;
+        self
+    }
+
+    pub fn with_license(mut self, license: impl Into<String>) -> Self {
+        self.license = Some(license.into());
+        self
+    }
+
+    pub fn with_min_tool_version(mut self, version: impl Into<String>) -> Self {
+        self.min_tool_version = Some(version.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    pub fn editors(&self) -> &[String] {
+        &self.editors
+    }
+
+    pub fn license(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+
+    pub fn min_tool_version(&self) -> Option<&str> {
+        self.min_tool_version.as_deref()
+    }
+
+    /// Whether a pack name can serve as a directory name and id namespace:
+    /// lowercase letters, digits, `-` and `_`
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    }
+
+    /// Whether editor-dojo `tool_version` is new enough for the pack
+    pub fn supports_tool_version(&self, tool_version: &str) -> bool {
+        self.min_tool_version
+            .as_deref()
+            .is_none_or(|min| compare_versions(tool_version, min) != Ordering::Less)
+    }
+}
+
+/// Compares dotted version numbers (`0.10.2` > `0.9`), ignoring any
+/// pre-release suffix; missing components count as 0
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |version: &str| -> Vec<u64> {
+        version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or("")
+            .split('.')
+            .map(|part| part.trim().parse().unwrap_or(0))
+            .collect()
+    };
+    let (a, b) = (parts(a), parts(b));
+    (0..a.len().max(b.len()))
+        .map(|i| a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supports_tool_version() {
+        let manifest = PackManifest::new("rust", "1.0.0").with_min_tool_version("0.10");
+        assert!(manifest.supports_tool_version("0.10.0"));
+        assert!(manifest.supports_tool_version("1.0.0-beta"));
+        assert!(!manifest.supports_tool_version("0.9.7"));
+        assert!(PackManifest::new("rust", "1.0.0").supports_tool_version("0.1.0"));
+    }
+
+    #[test]
+    fn test_valid_names() {
+        assert!(PackManifest::is_valid_name("rust-refactors_2"));
+        assert!(!PackManifest::is_valid_name(""));
+        assert!(!PackManifest::is_valid_name("../evil"));
+        assert!(!PackManifest::is_valid_name("Rust"));
+        assert_eq!(namespaced_id("rust", "rename-01"), "rust/rename-01");
+    }
+}
@@ -1,7 +1,15 @@
+use crate::domain::attempt::Attempt;
+use crate::domain::challenge::Challenge;
+use crate::domain::mastery_tier::TierThresholds;
+use crate::domain::daily_result::DailyResult;
+use crate::domain::gauntlet::GauntletResult;
+use crate::domain::review_state::ReviewState;
+use crate::domain::blind_mode::BlindMode;
 use crate::domain::challenge_stats::ChallengeStats;
 use crate::domain::achievement::{AchievementId, UnlockedAchievement};
+use crate::domain::config_mode::ConfigMode;
 use chrono::{DateTime, NaiveDate, Utc};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::Duration;
 
 /// Entity representing user's overall progress
@@ -13,6 +21,17 @@ pub struct Progress {
     longest_streak: u32,
     editor_preference: Option<String>,
     unlocked_achievements: HashMap<AchievementId, UnlockedAchievement>,
+    config_mode: ConfigMode,
+    blind_mode: BlindMode,
+    /// Stats for attempts made with the user's own editor config, kept apart
+    /// from the vanilla records they are not comparable with
+    personal_config_stats: HashMap<String, ChallengeStats>,
+    /// Spaced-repetition schedule of completed challenges
+    reviews: HashMap<String, ReviewState>,
+    /// Every gauntlet run, finished or not, oldest first
+    gauntlets: Vec<GauntletResult>,
+    /// The scored daily challenge attempt of each day played
+    daily_results: BTreeMap<NaiveDate, DailyResult>,
 }
 
 impl Progress {
@@ -25,6 +44,12 @@ impl Progress {
             longest_streak: 0,
             editor_preference: None,
             unlocked_achievements: HashMap::new(),
+            config_mode: ConfigMode::Vanilla,
+            blind_mode: BlindMode::Off,
+            personal_config_stats: HashMap::new(),
+            reviews: HashMap::new(),
+            gauntlets: Vec::new(),
+            daily_results: BTreeMap::new(),
         }
     }
 
@@ -44,15 +69,59 @@ impl Progress {
             longest_streak,
             editor_preference,
             unlocked_achievements,
+            config_mode: ConfigMode::Vanilla,
+            blind_mode: BlindMode::Off,
+            personal_config_stats: HashMap::new(),
+            reviews: HashMap::new(),
+            gauntlets: Vec::new(),
+            daily_results: BTreeMap::new(),
         }
     }
 
+    /// Restore stats recorded with the user's own editor config
+    pub fn with_personal_config_stats(mut self, stats: HashMap<String, ChallengeStats>) -> Self {
+        self.personal_config_stats = stats;
+        self
+    }
+
+    /// Restore the review schedule
+    pub fn with_reviews(mut self, reviews: HashMap<String, ReviewState>) -> Self {
+        self.reviews = reviews;
+        self
+    }
+
+    /// Restore past gauntlet runs
+    pub fn with_gauntlets(mut self, gauntlets: Vec<GauntletResult>) -> Self {
+        self.gauntlets = gauntlets;
+        self
+    }
+
+    pub fn with_daily_results(mut self, results: Vec<DailyResult>) -> Self {
+        self.daily_results = results
+            .into_iter()
+            .map(|result| (result.date(), result))
+            .collect();
+        self
+    }
+
     /// Set editor preference
     pub fn set_editor_preference(mut self, editor: String) -> Self {
         self.editor_preference = Some(editor);
         self
     }
 
+    /// Set which editor configuration sessions run with
+    pub fn set_config_mode(mut self, config_mode: ConfigMode) -> Self {
+        self.config_mode = config_mode;
+        self
+    }
+
+    /// Set how much of a challenge the brief reveals
+    pub fn set_blind_mode(mut self, blind_mode: BlindMode) -> Self {
+        self.blind_mode = blind_mode;
+        self
+    }
+
     /// Get stats for a specific challenge
     pub fn get_challenge_stats(&self, challenge_id: &str) -> Option<&ChallengeStats> {
         self.challenge_stats.get(challenge_id)
@@ -67,30 +136,27 @@ impl Progress {
         keystrokes: Option<u32>,
         attempted_at: DateTime<Utc>,
     ) {
-        let attempted_date = attempted_at.date_naive();
+        self.record(challenge_id, Attempt::new(completed, time, keystrokes, attempted_at));
+    }
+
+    /// Update stats for a challenge after an attempt
+    pub fn record(&mut self, challenge_id: String, attempt: Attempt) {
+        let attempted_date = attempt.attempted_at().date_naive();
 
         // Update or create challenge stats
-        let updated_stats = if let Some(existing) = self.challenge_stats.get(&challenge_id) {
-            existing.record_attempt(completed, time, keystrokes, attempted_at)
-        } else {
-            if completed {
-                ChallengeStats::completed(challenge_id.clone(), time, keystrokes, attempted_at)
-            } else {
-                let stats = ChallengeStats::new(challenge_id.clone());
-                stats.record_attempt(completed, time, keystrokes, attempted_at)
-            }
-        };
+        let updated_stats =
+            Self::updated_stats(self.challenge_stats.get(&challenge_id), &challenge_id, &attempt);
 
         self.challenge_stats.insert(challenge_id, updated_stats);
 
         // Update total practice time
-        self.total_practice_time += time;
+        self.total_practice_time += attempt.time();
 
         // Update last practice date
         self.last_practice_date = Some(attempted_date);
 
         // Update streak if this is a completion
-        if completed {
+        if attempt.is_completed() {
             let current_streak = self.calculate_current_streak(attempted_date);
             if current_streak > self.longest_streak {
                 self.longest_streak = current_streak;
@@ -98,6 +164,189 @@ impl Progress {
         }
     }
 
+    /// Update stats for an attempt made with the user's own editor config
+    ///
+    /// Only practice time is shared with the vanilla records; streaks,
+    /// completions and achievements are based on vanilla attempts.
+    pub fn record_personal_config(&mut self, challenge_id: String, attempt: Attempt) {
+        let updated_stats = Self::updated_stats(
+            self.personal_config_stats.get(&challenge_id),
+            &challenge_id,
+            &attempt,
+        );
+
+        self.personal_config_stats.insert(challenge_id, updated_stats);
+        self.total_practice_time += attempt.time();
+        self.last_practice_date = Some(attempt.attempted_at().date_naive());
+    }
+
+    /// Prerequisites of a challenge that haven't reached its required tier yet
+    pub fn unmet_requirements<'a>(&self, challenge: &'a Challenge) -> Vec<&'a str> {
+        challenge
+            .requires()
+            .iter()
+            .filter(|id| {
+                self.get_challenge_stats(id)
+                    .and_then(ChallengeStats::mastery_tier)
+                    .is_none_or(|tier| tier < challenge.required_tier())
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Whether a challenge has unmet prerequisites among `challenges`
+    ///
+    /// Prerequisites that aren't among them never lock a challenge.
+    pub fn is_locked(&self, challenge: &Challenge, challenges: &[Challenge]) -> bool {
+        self.unmet_requirements(challenge)
+            .iter()
+            .any(|id| challenges.iter().any(|c| c.id() == *id))
+    }
+
+    /// Replace the review schedule of a challenge
+    pub fn set_review_state(&mut self, challenge_id: String, state: ReviewState) {
+        self.reviews.insert(challenge_id, state);
+    }
+
+    pub fn review_state(&self, challenge_id: &str) -> Option<&ReviewState> {
+        self.reviews.get(challenge_id)
+    }
+
+    pub fn reviews(&self) -> &HashMap<String, ReviewState> {
+        &self.reviews
+    }
+
+    pub fn record_gauntlet(&mut self, result: GauntletResult) {
+        self.gauntlets.push(result);
+    }
+
+    pub fn gauntlets(&self) -> &[GauntletResult] {
+        &self.gauntlets
+    }
+
+    /// Completed runs of the gauntlet with `key`, fastest first
+    pub fn gauntlet_leaderboard(&self, key: &str) -> Vec<&GauntletResult> {
+        let mut runs: Vec<&GauntletResult> = self
+            .gauntlets
+            .iter()
+            .filter(|run| run.key() == key && run.is_completed())
+            .collect();
+        runs.sort_by_key(|run| (run.total_time(), run.finished_at()));
+        runs
+    }
+
+    /// Record the daily challenge result of its day
+    ///
+    /// Only the first attempt of a day is scored; returns false and keeps
+    /// the earlier result if the day was already played.
+    pub fn record_daily(&mut self, result: DailyResult) -> bool {
+        if self.daily_results.contains_key(&result.date()) {
+            return false;
+        }
+        self.daily_results.insert(result.date(), result);
+        true
+    }
+
+    pub fn daily_result(&self, date: NaiveDate) -> Option<&DailyResult> {
+        self.daily_results.get(&date)
+    }
+
+    /// Daily results, oldest first
+    pub fn daily_results(&self) -> impl DoubleEndedIterator<Item = &DailyResult> {
+        self.daily_results.values()
+    }
+
+    /// Consecutive days with a solved daily challenge, up to today
+    ///
+    /// A streak stays alive until the end of the day after its last daily.
+    pub fn daily_streak(&self, today: NaiveDate) -> u32 {
+        let solved = |date: NaiveDate| {
+            self.daily_result(date)
+                .is_some_and(DailyResult::is_completed)
+        };
+
+        let mut check_date = if solved(today) {
+            today
+        } else {
+            match today.pred_opt() {
+                Some(yesterday) => yesterday,
+                None => return 0,
+            }
+        };
+
+        let mut streak = 0;
+        while solved(check_date) {
+            streak += 1;
+            match check_date.pred_opt() {
+                Some(previous) => check_date = previous,
+                None => break,
+            }
+        }
+        streak
+    }
+
+    /// Longest run of consecutive days with a solved daily challenge
+    pub fn longest_daily_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut current = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for result in self.daily_results.values().filter(|result| result.is_completed()) {
+            let consecutive = previous.and_then(|date| date.succ_opt()) == Some(result.date());
+            current = if consecutive { current + 1 } else { 1 };
+            longest = longest.max(current);
+            previous = Some(result.date());
+        }
+        longest
+    }
+
+    /// Use a challenge's own tier thresholds for its vanilla and personal stats
+    pub fn set_tier_thresholds(&mut self, challenge_id: &str, thresholds: TierThresholds) {
+        for stats_map in [&mut self.challenge_stats, &mut self.personal_config_stats] {
+            if let Some(stats) = stats_map.remove(challenge_id) {
+                stats_map.insert(challenge_id.to_string(), stats.with_thresholds(thresholds));
+            }
+        }
+    }
+
+    /// Ties a challenge's stats to the current version of its content; see
+    /// `ChallengeStats::with_content_version`
+    pub fn set_content_version(&mut self, challenge_id: &str, version: &str) {
+        for stats_map in [&mut self.challenge_stats, &mut self.personal_config_stats] {
+            if let Some(stats) = stats_map.remove(challenge_id) {
+                stats_map.insert(challenge_id.to_string(), stats.with_content_version(version));
+            }
+        }
+    }
+
+    /// Marks bests set on an earlier version of a challenge as legacy
+    pub fn apply_content_versions(&mut self, challenges: &[Challenge]) {
+        for challenge in challenges {
+            self.set_content_version(challenge.id(), &challenge.content_version());
+        }
+    }
+
+    /// Recompute stored tiers against the current challenge definitions
+    ///
+    /// Tiers are derived from the best time and keystrokes, so changing a
+    /// challenge's `optimal_keystrokes` or `par_time` re-tiers past results.
+    pub fn apply_tier_thresholds(&mut self, challenges: &[Challenge]) {
+        for challenge in challenges {
+            self.set_tier_thresholds(challenge.id(), challenge.tier_thresholds());
+        }
+    }
+
+    /// Apply an attempt to existing stats, or create stats for a first attempt
+    fn updated_stats(
+        existing: Option<&ChallengeStats>,
+        challenge_id: &str,
+        attempt: &Attempt,
+    ) -> ChallengeStats {
+        existing
+            .cloned()
+            .unwrap_or_else(|| ChallengeStats::new(challenge_id.to_string()))
+            .record(attempt)
+    }
+
     /// Calculate current streak based on last practice date
     pub fn calculate_current_streak(&self, today: NaiveDate) -> u32 {
         if self.last_practice_date.is_none() {
@@ -172,6 +421,45 @@ impl Progress {
         self.editor_preference.as_deref()
     }
 
+    /// Get which editor configuration sessions run with
+    pub fn config_mode(&self) -> ConfigMode {
+        self.config_mode
+    }
+
+    /// Get how much of a challenge the brief reveals
+    pub fn blind_mode(&self) -> BlindMode {
+        self.blind_mode
+    }
+
+    /// Get number of challenges completed without seeing the target
+    pub fn total_blind_completed(&self) -> usize {
+        self.challenge_stats
+            .values()
+            .filter(|stats| stats.is_blind_completed())
+            .count()
+    }
+
+    /// Number of challenges completed at least once without hints
+    pub fn total_solved_without_hints(&self) -> usize {
+        self.challenge_stats
+            .values()
+            .filter(|stats| stats.is_solved_without_hints())
+            .count()
+    }
+
+    /// Get stats recorded with the user's own editor config
+    pub fn personal_config_stats(&self) -> &HashMap<String, ChallengeStats> {
+        &self.personal_config_stats
+    }
+
+    /// Get number of challenges completed with the user's own editor config
+    pub fn personal_config_completed(&self) -> usize {
+        self.personal_config_stats
+            .values()
+            .filter(|stats| stats.is_completed())
+            .count()
+    }
+
     /// Get total attempts across all challenges
     pub fn total_attempts(&self) -> u32 {
         self.challenge_stats
@@ -192,9 +480,8 @@ impl Progress {
             return None;
         }
 
-        let total_secs: u64 = completed.iter().map(|d| d.as_secs()).sum();
-        let avg_secs = total_secs / completed.len() as u64;
-        Some(Duration::from_secs(avg_secs))
+        let total: Duration = completed.iter().sum();
+        Some(total / completed.len() as u32)
     }
 
     /// Get average keystrokes for completed challenges
@@ -231,9 +518,7 @@ impl Progress {
 
     /// Unlock an achievement
     pub fn unlock_achievement(&mut self, id: AchievementId, unlocked_at: DateTime<Utc>) {
-        if !self.unlocked_achievements.contains_key(&id) {
-            self.unlocked_achievements.insert(id, UnlockedAchievement::new(id, unlocked_at));
-        }
+        self.unlocked_achievements.entry(id).or_insert_with(|| UnlockedAchievement::new(id, unlocked_at));
     }
 
     /// Check if an achievement is unlocked
@@ -385,6 +670,58 @@ mod tests {
         assert_eq!(progress.longest_streak(), 1);
     }
 
+    #[test]
+    fn test_personal_config_attempts_tracked_separately() {
+        let mut progress = Progress::new();
+        let now = Utc::now();
+
+        progress.record_personal_config(
+            "test-1".to_string(),
+            Attempt::new(true, Duration::from_secs(5), Some(4), now),
+        );
+
+        assert_eq!(progress.total_completed(), 0);
+        assert!(progress.get_challenge_stats("test-1").is_none());
+        assert_eq!(progress.personal_config_completed(), 1);
+        assert_eq!(progress.total_practice_time(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_apply_tier_thresholds_recomputes_stored_tiers() {
+        use crate::domain::MasteryTier;
+
+        let mut progress = Progress::new();
+        progress.record_attempt("test-1".to_string(), true, Duration::from_secs(10), Some(20), Utc::now());
+        assert_eq!(
+            progress.get_challenge_stats("test-1").unwrap().mastery_tier(),
+            Some(MasteryTier::Gold)
+        );
+
+        let challenge = Challenge::new("test-1", "Title", "Desc", "a", "b", "hint")
+            .with_optimal_solution("dd", 5);
+        progress.apply_tier_thresholds(&[challenge]);
+
+        assert_eq!(
+            progress.get_challenge_stats("test-1").unwrap().mastery_tier(),
+            Some(MasteryTier::Bronze)
+        );
+    }
+
+    #[test]
+    fn test_unmet_requirements() {
+        use crate::domain::MasteryTier;
+
+        let challenge = Challenge::new("c", "T", "D", "a", "b", "h")
+            .with_requirements(vec!["a".to_string(), "b".to_string()], MasteryTier::Silver);
+        let mut progress = Progress::new();
+        assert_eq!(progress.unmet_requirements(&challenge), ["a", "b"]);
+
+        // Silver for "a", only bronze for "b"
+        progress.record_attempt("a".to_string(), true, Duration::from_secs(20), Some(40), Utc::now());
+        progress.record_attempt("b".to_string(), true, Duration::from_secs(90), Some(40), Utc::now());
+        assert_eq!(progress.unmet_requirements(&challenge), ["b"]);
+    }
+
     #[test]
     fn test_average_calculations() {
         let mut progress = Progress::new();
@@ -409,4 +746,48 @@ mod tests {
         assert_eq!(progress.average_solve_time(), Some(Duration::from_secs(15)));
         assert_eq!(progress.average_keystrokes(), Some(20));
     }
+
+    #[test]
+    fn test_gauntlet_leaderboard_fastest_completed_first() {
+        use crate::domain::gauntlet::GauntletSplit;
+
+        let run = |secs, completed| {
+            let split = GauntletSplit::new("a", Duration::from_secs(secs), None, completed);
+            GauntletResult::new("random/1", "Random ×1", Utc::now(), Duration::from_secs(secs), 1, vec![split])
+        };
+        let mut progress = Progress::new();
+        progress.record_gauntlet(run(30, true));
+        progress.record_gauntlet(run(10, false));
+        progress.record_gauntlet(run(20, true));
+
+        let times: Vec<u64> = progress
+            .gauntlet_leaderboard("random/1")
+            .iter()
+            .map(|run| run.total_time().as_secs())
+            .collect();
+        assert_eq!(times, [20, 30]);
+        assert!(progress.gauntlet_leaderboard("Random ×1").is_empty());
+    }
+
+    #[test]
+    fn test_one_daily_result_per_day_and_streaks() {
+        use crate::domain::Score;
+
+        let day = |d| NaiveDate::from_ymd_opt(2025, 6, d).unwrap();
+        let daily = |d, completed| {
+            DailyResult::new(day(d), "a", completed, Duration::from_secs(10), None, Score::new(500))
+        };
+        let mut progress = Progress::new();
+        for (d, completed) in [(1, true), (2, true), (3, true), (5, false), (6, true), (7, true)] {
+            assert!(progress.record_daily(daily(d, completed)));
+        }
+        assert!(!progress.record_daily(daily(7, false)));
+        assert!(progress.daily_result(day(7)).unwrap().is_completed());
+
+        assert_eq!(progress.daily_streak(day(7)), 2);
+        // Today's daily not played yet keeps yesterday's streak
+        assert_eq!(progress.daily_streak(day(8)), 2);
+        assert_eq!(progress.daily_streak(day(9)), 0);
+        assert_eq!(progress.longest_daily_streak(), 3);
+    }
 }
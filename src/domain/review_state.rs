@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+
+/// Ease factor a challenge starts with (SM-2's default)
+pub const INITIAL_EASE_FACTOR: f64 = 2.5;
+
+/// Lowest ease factor SM-2 allows
+pub const MIN_EASE_FACTOR: f64 = 1.3;
+
+/// Spaced-repetition state of a single challenge
+///
+/// Value object holding the SM-2 parameters: how easy the challenge has
+/// proven (`ease_factor`), the current gap between reviews and the date
+/// the next review is due.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewState {
+    ease_factor: f64,
+    interval_days: u32,
+    repetitions: u32,
+    due: NaiveDate,
+}
+
+impl ReviewState {
+    pub fn new(ease_factor: f64, interval_days: u32, repetitions: u32, due: NaiveDate) -> Self {
+        Self {
+            ease_factor: ease_factor.max(MIN_EASE_FACTOR),
+            interval_days,
+            repetitions,
+            due,
+        }
+    }
+
+    pub fn ease_factor(&self) -> f64 {
+        self.ease_factor
+    }
+
+    pub fn interval_days(&self) -> u32 {
+        self.interval_days
+    }
+
+    /// Successful reviews in a row
+    pub fn repetitions(&self) -> u32 {
+        self.repetitions
+    }
+
+    pub fn due(&self) -> NaiveDate {
+        self.due
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due <= today
+    }
+}
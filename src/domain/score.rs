@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use crate::domain::{Attempt, TierThresholds};
+
+/// Points available for speed and for keystroke efficiency each
+const COMPONENT_POINTS: f64 = 500.0;
+
+/// Reference time when a challenge has no `par_time` (the global gold limit)
+const DEFAULT_REFERENCE_TIME: Duration = Duration::from_secs(15);
+
+/// Reference keystrokes when a challenge has no `optimal_keystrokes`
+const DEFAULT_REFERENCE_KEYSTROKES: u32 = 30;
+
+/// Share of the score lost per hint level used
+const HINT_PENALTY: f64 = 0.1;
+
+/// Largest share of the score hints can take away
+const MAX_HINT_PENALTY: f64 = 0.5;
+
+/// Multiplier for attempts made without seeing the target
+const BLIND_BONUS: f64 = 1.2;
+
+/// Composite score for a single attempt
+///
+/// Finer-grained than the mastery tiers: up to 500 points for speed relative
+/// to the challenge's par time and 500 for keystrokes relative to its
+/// optimal solution, reduced for hints and increased for blind attempts.
+/// Failed attempts score zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Score(u32);
+
+impl Score {
+    pub fn new(points: u32) -> Self {
+        Self(points)
+    }
+
+    /// Calculate the score of an attempt against a challenge's thresholds
+    pub fn calculate(attempt: &Attempt, thresholds: &TierThresholds) -> Self {
+        if !attempt.is_completed() {
+            return Self(0);
+        }
+
+        let reference_time = thresholds.par_time().unwrap_or(DEFAULT_REFERENCE_TIME);
+        let time_factor = ratio(reference_time.as_secs_f64(), attempt.time().as_secs_f64());
+
+        // Without a recording there is nothing to measure efficiency against;
+        // time-only thresholds score on time alone
+        let reference_keystrokes = thresholds
+            .optimal_keystrokes()
+            .unwrap_or(DEFAULT_REFERENCE_KEYSTROKES);
+        let keystroke_factor = if thresholds.ranks_keystrokes() {
+            attempt.keystrokes().map_or(0.0, |keystrokes| {
+                ratio(reference_keystrokes as f64, keystrokes as f64)
+            })
+        } else {
+            time_factor
+        };
+
+        let hint_factor =
+            1.0 - (attempt.hints_used() as f64 * HINT_PENALTY).min(MAX_HINT_PENALTY);
+        let mode_factor = if attempt.is_blind() { BLIND_BONUS } else { 1.0 };
+
+        let points = COMPONENT_POINTS * (time_factor + keystroke_factor) * hint_factor * mode_factor;
+        Self(points.round() as u32)
+    }
+
+    pub fn points(&self) -> u32 {
+        self.0
+    }
+}
+
+/// `reference / actual`, capped at 1 (beating the reference earns no extra)
+fn ratio(reference: f64, actual: f64) -> f64 {
+    if actual <= 0.0 {
+        1.0
+    } else {
+        (reference / actual).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn attempt(secs: u64, keystrokes: Option<u32>) -> Attempt {
+        Attempt::new(true, Duration::from_secs(secs), keystrokes, Utc::now())
+    }
+
+    #[test]
+    fn test_perfect_attempt_scores_maximum() {
+        let thresholds = TierThresholds::new(Some(10), Some(Duration::from_secs(20)));
+        assert_eq!(Score::calculate(&attempt(20, Some(10)), &thresholds).points(), 1000);
+        assert_eq!(Score::calculate(&attempt(5, Some(8)), &thresholds).points(), 1000);
+    }
+
+    #[test]
+    fn test_score_falls_with_time_and_keystrokes() {
+        let thresholds = TierThresholds::new(Some(10), Some(Duration::from_secs(20)));
+        // 500 * (20/40) + 500 * (10/20)
+        assert_eq!(Score::calculate(&attempt(40, Some(20)), &thresholds).points(), 500);
+    }
+
+    #[test]
+    fn test_time_only_thresholds_score_time_alone() {
+        let thresholds = TierThresholds::time_only(Some(Duration::from_secs(20)));
+        assert_eq!(Score::calculate(&attempt(20, Some(500)), &thresholds).points(), 1000);
+        assert_eq!(Score::calculate(&attempt(40, None), &thresholds).points(), 500);
+    }
+
+    #[test]
+    fn test_hints_and_blind_mode_adjust_score() {
+        let thresholds = TierThresholds::new(Some(10), Some(Duration::from_secs(20)));
+        let hinted = attempt(20, Some(10)).with_hints_used(2);
+        assert_eq!(Score::calculate(&hinted, &thresholds).points(), 800);
+
+        let blind = attempt(20, Some(10)).with_blind(true);
+        assert_eq!(Score::calculate(&blind, &thresholds).points(), 1200);
+    }
+
+    #[test]
+    fn test_failed_attempt_scores_zero() {
+        let failed = Attempt::new(false, Duration::from_secs(5), Some(3), Utc::now());
+        assert_eq!(Score::calculate(&failed, &TierThresholds::global()), Score::new(0));
+    }
+}
@@ -0,0 +1,94 @@
+/// Weight of the average tier in a skill rating
+const TIER_WEIGHT: f64 = 0.5;
+
+/// Weight of keystroke efficiency against the optimal solutions
+const EFFICIENCY_WEIGHT: f64 = 0.3;
+
+/// Weight of how recently the skill was practised
+const RECENCY_WEIGHT: f64 = 0.2;
+
+/// Aggregated performance on the challenges sharing one tag
+///
+/// Each component is between 0 and 1:
+/// - `tier`: average mastery tier, unsolved challenges counting as 0
+/// - `efficiency`: optimal keystrokes over best keystrokes, for solved
+///   challenges that define an optimal solution
+/// - `recency`: how recently the solved challenges were last attempted
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillLevel {
+    tag: String,
+    challenge_count: usize,
+    completed_count: usize,
+    tier: f64,
+    efficiency: Option<f64>,
+    recency: f64,
+}
+
+impl SkillLevel {
+    pub fn new(
+        tag: impl Into<String>,
+        challenge_count: usize,
+        completed_count: usize,
+        tier: f64,
+        efficiency: Option<f64>,
+        recency: f64,
+    ) -> Self {
+        Self {
+            tag: tag.into(),
+            challenge_count,
+            completed_count,
+            tier: tier.clamp(0.0, 1.0),
+            efficiency: efficiency.map(|e| e.clamp(0.0, 1.0)),
+            recency: recency.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn challenge_count(&self) -> usize {
+        self.challenge_count
+    }
+
+    pub fn completed_count(&self) -> usize {
+        self.completed_count
+    }
+
+    pub fn tier(&self) -> f64 {
+        self.tier
+    }
+
+    pub fn efficiency(&self) -> Option<f64> {
+        self.efficiency
+    }
+
+    pub fn recency(&self) -> f64 {
+        self.recency
+    }
+
+    /// Overall rating from 0 to 100
+    ///
+    /// Without efficiency data the tier stands in for it.
+    pub fn rating(&self) -> u32 {
+        let efficiency = self.efficiency.unwrap_or(self.tier);
+        let rating = TIER_WEIGHT * self.tier
+            + EFFICIENCY_WEIGHT * efficiency
+            + RECENCY_WEIGHT * self.recency;
+        (rating * 100.0).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_weights_components() {
+        assert_eq!(SkillLevel::new("word", 4, 4, 1.0, Some(1.0), 1.0).rating(), 100);
+        assert_eq!(SkillLevel::new("word", 4, 0, 0.0, None, 0.0).rating(), 0);
+        assert_eq!(SkillLevel::new("word", 4, 2, 0.5, Some(1.0), 0.0).rating(), 55);
+        // Tier stands in for missing efficiency
+        assert_eq!(SkillLevel::new("word", 4, 2, 0.5, None, 0.0).rating(), 40);
+    }
+}
@@ -1,4 +1,5 @@
 use std::time::Duration;
+use super::config_mode::ConfigMode;
 use super::recording::Recording;
 
 /// Represents the result of completing a challenge
@@ -9,6 +10,11 @@ pub struct Solution {
     completed: bool,
     elapsed_time: Duration,
     recording: Option<Recording>,
+    config_mode: ConfigMode,
+    blind: bool,
+    time_penalty: Duration,
+    hints_used: u32,
+    keystrokes_ranked: bool,
 }
 
 impl Solution {
@@ -17,6 +23,11 @@ impl Solution {
             completed: true,
             elapsed_time,
             recording: None,
+            config_mode: ConfigMode::Vanilla,
+            blind: false,
+            time_penalty: Duration::ZERO,
+            hints_used: 0,
+            keystrokes_ranked: true,
         }
     }
 
@@ -25,6 +36,11 @@ impl Solution {
             completed: false,
             elapsed_time,
             recording: None,
+            config_mode: ConfigMode::Vanilla,
+            blind: false,
+            time_penalty: Duration::ZERO,
+            hints_used: 0,
+            keystrokes_ranked: true,
         }
     }
 
@@ -33,6 +49,36 @@ impl Solution {
         self
     }
 
+    pub fn with_config_mode(mut self, config_mode: ConfigMode) -> Self {
+        self.config_mode = config_mode;
+        self
+    }
+
+    /// Marks the solution as made without seeing the target text
+    pub fn with_blind(mut self, blind: bool) -> Self {
+        self.blind = blind;
+        self
+    }
+
+    /// Adds a penalty on top of the measured time (e.g. for revealing the target)
+    pub fn with_time_penalty(mut self, penalty: Duration) -> Self {
+        self.time_penalty += penalty;
+        self
+    }
+
+    /// Records how many progressive hint levels were revealed
+    pub fn with_hints_used(mut self, hints_used: u32) -> Self {
+        self.hints_used = hints_used;
+        self
+    }
+
+    /// Marks whether the keystrokes can be compared to the challenge's
+    /// optimal solution, which only holds in the editor it was written for
+    pub fn with_ranked_keystrokes(mut self, ranked: bool) -> Self {
+        self.keystrokes_ranked = ranked;
+        self
+    }
+
     pub fn is_completed(&self) -> bool {
         self.completed
     }
@@ -45,7 +91,40 @@ impl Solution {
         self.elapsed_time.as_secs()
     }
 
+    pub fn elapsed_millis(&self) -> u128 {
+        self.elapsed_time.as_millis()
+    }
+
     pub fn recording(&self) -> Option<&Recording> {
         self.recording.as_ref()
     }
+
+    /// Recorded keystrokes, if they count towards tiers and bests
+    pub fn ranked_keystrokes(&self) -> Option<u32> {
+        self.recording
+            .as_ref()
+            .filter(|_| self.keystrokes_ranked)
+            .map(|r| r.keystroke_count() as u32)
+    }
+
+    pub fn config_mode(&self) -> ConfigMode {
+        self.config_mode
+    }
+
+    pub fn is_blind(&self) -> bool {
+        self.blind
+    }
+
+    pub fn time_penalty(&self) -> Duration {
+        self.time_penalty
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.hints_used
+    }
+
+    /// Time used for records: elapsed time plus any penalties
+    pub fn scored_time(&self) -> Duration {
+        self.elapsed_time + self.time_penalty
+    }
 }
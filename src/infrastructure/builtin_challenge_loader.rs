@@ -0,0 +1,170 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use include_dir::{include_dir, Dir, DirEntry};
+
+use crate::domain::{Challenge, LearningPath};
+use crate::infrastructure::challenge_loader::{
+    is_challenge_name, parse_file, parse_paths, ChallengeLoader, LoadFailure, LoadReport,
+    TomlChallenge, PATHS_FILE,
+};
+use crate::infrastructure::template_challenge_loader::parse_template;
+
+/// The bundled challenges, compiled into the executable so an installed
+/// binary works from any directory
+static BUILTIN_CHALLENGES: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/challenges/helix");
+
+/// Directory of the built-in templates, as in a challenges directory
+const TEMPLATES_DIR: &str = "templates";
+
+/// Shown in place of a directory in errors about built-in files
+const BUILTIN_PREFIX: &str = "<built-in>";
+
+/// Loads the challenges, templates and learning paths compiled into the
+/// executable
+pub struct BuiltinChallengeLoader {
+    dir: &'static Dir<'static>,
+}
+
+impl BuiltinChallengeLoader {
+    pub fn new() -> Self {
+        Self {
+            dir: &BUILTIN_CHALLENGES,
+        }
+    }
+
+    /// Writes every built-in file to `out`, keeping the layout of a
+    /// challenges directory, and returns the written paths
+    ///
+    /// Existing files are only replaced with `overwrite`, so edits are never
+    /// lost by accident.
+    pub fn export(&self, out: &Path, overwrite: bool) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        collect_files(self.dir, &mut files);
+        let targets: Vec<PathBuf> = files.iter().map(|file| out.join(file.path())).collect();
+
+        if !overwrite {
+            let existing = targets.iter().filter(|target| target.exists()).count();
+            if existing > 0 {
+                anyhow::bail!(
+                    "{} of the files already exist in {}; pass --force to overwrite them",
+                    existing,
+                    out.display()
+                );
+            }
+        }
+
+        for (file, target) in files.iter().zip(&targets) {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+            fs::write(target, file.contents())
+                .with_context(|| format!("Failed to write {}", target.display()))?;
+        }
+        Ok(targets)
+    }
+
+    /// Parses every challenge file directly in `dir` with `parse`
+    fn load_dir(
+        dir: &Dir<'static>,
+        parse: fn(&Path, &str) -> std::result::Result<Challenge, LoadFailure>,
+    ) -> LoadReport {
+        let mut files: Vec<_> = dir.files().filter(|file| is_challenge_name(file.path())).collect();
+        files.sort_by_key(|file| file.path());
+
+        let mut challenges = Vec::new();
+        let mut failures = Vec::new();
+        for file in files {
+            let path = Path::new(BUILTIN_PREFIX).join(file.path());
+            let parsed = match file.contents_utf8() {
+                Some(content) => parse(&path, content),
+                None => Err(LoadFailure::new(&path, None, "File is not valid UTF-8")),
+            };
+            match parsed {
+                Ok(challenge) => challenges.push(challenge),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        LoadReport::new(challenges, failures)
+    }
+}
+
+impl Default for BuiltinChallengeLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Every file under `dir`, in any subdirectory
+fn collect_files(dir: &'static Dir<'static>, files: &mut Vec<&'static include_dir::File<'static>>) {
+    for entry in dir.entries() {
+        match entry {
+            DirEntry::Dir(subdir) => collect_files(subdir, files),
+            DirEntry::File(file) => files.push(file),
+        }
+    }
+}
+
+impl ChallengeLoader for BuiltinChallengeLoader {
+    fn load(&self) -> Result<LoadReport> {
+        let mut report = Self::load_dir(self.dir, |path, content| {
+            parse_file(path, content, TomlChallenge::into_domain)
+        });
+        if let Some(templates) = self.dir.get_dir(TEMPLATES_DIR) {
+            report.extend(Self::load_dir(templates, parse_template));
+        }
+        Ok(report)
+    }
+
+    fn load_paths(&self) -> Result<Vec<LearningPath>> {
+        match self.dir.get_file(PATHS_FILE).and_then(|file| file.contents_utf8()) {
+            Some(content) => parse_paths(&Path::new(BUILTIN_PREFIX).join(PATHS_FILE), content),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_matches_bundled_directory() {
+        let bundled = concat!(env!("CARGO_MANIFEST_DIR"), "/challenges/helix");
+        let builtin = BuiltinChallengeLoader::new();
+        let report = builtin.load().unwrap();
+        assert!(report.failures().is_empty(), "{:?}", report.failures());
+
+        let mut on_disk = crate::infrastructure::FileChallengeLoader::new(bundled).load().unwrap();
+        on_disk.extend(
+            crate::infrastructure::TemplateChallengeLoader::new(format!("{}/templates", bundled))
+                .load()
+                .unwrap(),
+        );
+        let ids = |challenges: &[Challenge]| -> Vec<String> {
+            challenges.iter().map(|challenge| challenge.id().to_string()).collect()
+        };
+        assert_eq!(ids(report.challenges()), ids(on_disk.challenges()));
+        assert!(!builtin.load_paths().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_refuses_to_overwrite_without_force() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let builtin = BuiltinChallengeLoader::new();
+
+        let written = builtin.export(dir.path(), false).unwrap();
+        assert!(written.contains(&dir.path().join(PATHS_FILE)));
+        assert!(dir.path().join(TEMPLATES_DIR).is_dir());
+
+        fs::write(dir.path().join(PATHS_FILE), "# edited").unwrap();
+        assert!(builtin.export(dir.path(), false).is_err());
+        assert_eq!(fs::read_to_string(dir.path().join(PATHS_FILE)).unwrap(), "# edited");
+
+        builtin.export(dir.path(), true).unwrap();
+        assert!(!builtin.load_paths().unwrap().is_empty());
+        assert_ne!(fs::read_to_string(dir.path().join(PATHS_FILE)).unwrap(), "# edited");
+    }
+}
@@ -3,6 +3,7 @@ use serde_json::Value;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
+use std::time::Duration;
 
 use crate::domain::KeySequence;
 
@@ -17,6 +18,51 @@ impl CastParser {
     ///
     /// Returns a KeySequence containing all input events in order.
     pub fn parse(file_path: &Path) -> Result<KeySequence> {
+        Self::parse_until(file_path, None)
+    }
+
+    /// Like `parse`, but ignores input events recorded after `cutoff`
+    /// (measured from the start of the recording).
+    pub fn parse_until(file_path: &Path, cutoff: Option<Duration>) -> Result<KeySequence> {
+        let keys = Self::inputs_until(file_path, cutoff)?
+            .iter()
+            .map(|data| Self::parse_input_data(data))
+            .collect();
+        Ok(KeySequence::new(keys))
+    }
+
+    /// The input exactly as typed up to `cutoff`, escape characters and all
+    ///
+    /// This is the form challenge files use for `optimal_solution`.
+    pub fn raw_input_until(file_path: &Path, cutoff: Option<Duration>) -> Result<String> {
+        Ok(Self::inputs_until(file_path, cutoff)?.concat())
+    }
+
+    /// Number of keys in raw input such as an `optimal_solution`
+    ///
+    /// Every character is one key, except that an escape sequence sent by a
+    /// single key (e.g. `ESC [ A` for Up) counts once.
+    pub fn key_count(raw: &str) -> usize {
+        let mut count = 0;
+        let mut chars = raw.chars().peekable();
+        while let Some(ch) = chars.next() {
+            count += 1;
+            if ch == '\x1b' && chars.peek() == Some(&'[') {
+                // Parameters run up to the final byte (@ through ~)
+                chars.next();
+                for ch in chars.by_ref() {
+                    if ('@'..='~').contains(&ch) {
+                        break;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    /// Raw data of each input event no later than `cutoff`
+    fn inputs_until(file_path: &Path, cutoff: Option<Duration>) -> Result<Vec<String>> {
+        let cutoff_secs = cutoff.map(|d| d.as_secs_f64());
         let file = File::open(file_path)
             .with_context(|| format!("Failed to open cast file: {}", file_path.display()))?;
 
@@ -39,7 +85,7 @@ impl CastParser {
             }
 
             // Parse the event line
-            match Self::parse_event(&line) {
+            match Self::parse_event(&line, cutoff_secs) {
                 Ok(Some(key)) => keys.push(key),
                 Ok(None) => {
                     // Not an input event, skip
@@ -51,13 +97,14 @@ impl CastParser {
             }
         }
 
-        Ok(KeySequence::new(keys))
+        Ok(keys)
     }
 
     /// Parses a single event line from the .cast file.
     ///
-    /// Returns Some(key) if this is an input event, None otherwise.
-    fn parse_event(line: &str) -> Result<Option<String>> {
+    /// Returns the raw input data if this is an input event no later than
+    /// the cutoff, None otherwise.
+    fn parse_event(line: &str, cutoff_secs: Option<f64>) -> Result<Option<String>> {
         let event: Value = serde_json::from_str(line)
             .with_context(|| "Failed to parse event JSON")?;
 
@@ -79,12 +126,18 @@ impl CastParser {
             return Ok(None);
         }
 
+        let timestamp = event_array[0]
+            .as_f64()
+            .context("Event timestamp is not a number")?;
+        if cutoff_secs.is_some_and(|cutoff| timestamp > cutoff) {
+            return Ok(None);
+        }
+
         let data = event_array[2]
             .as_str()
             .context("Event data is not a string")?;
 
-        // Parse the input data into human-readable keys
-        Ok(Some(Self::parse_input_data(data)))
+        Ok(Some(data.to_string()))
     }
 
     /// Converts raw input data into human-readable key representation.
@@ -160,7 +213,7 @@ impl CastParser {
         // If we can't parse it as a known sequence, handle it character by character
         // and join the results
         data.chars()
-            .map(|ch| Self::char_to_key_name(ch))
+            .map(Self::char_to_key_name)
             .collect::<Vec<_>>()
             .join(" ")
     }
@@ -196,6 +249,36 @@ mod tests {
         assert_eq!(CastParser::parse_escape_sequence("\x1bf"), "Alt-f");
     }
 
+    #[test]
+    fn test_parse_until_ignores_later_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.cast");
+        std::fs::write(
+            &path,
+            "{\"version\": 2, \"width\": 80, \"height\": 24}\n\
+             [0.5, \"i\", \"x\"]\n\
+             [0.6, \"o\", \"x\"]\n\
+             [1.2, \"i\", \"\\u001b\"]\n",
+        )
+        .unwrap();
+
+        let all = CastParser::parse(&path).unwrap();
+        assert_eq!(all.count(), 2);
+
+        let until = CastParser::parse_until(&path, Some(Duration::from_secs(1))).unwrap();
+        assert_eq!(until.count(), 1);
+
+        assert_eq!(CastParser::raw_input_until(&path, None).unwrap(), "x\x1b");
+    }
+
+    #[test]
+    fn test_key_count() {
+        assert_eq!(CastParser::key_count("wd"), 2);
+        assert_eq!(CastParser::key_count("ihi\x1b"), 4);
+        assert_eq!(CastParser::key_count("\x1b[A\x1b[3~x"), 3);
+        assert_eq!(CastParser::key_count(""), 0);
+    }
+
     #[test]
     fn test_parse_input_data() {
         assert_eq!(CastParser::parse_input_data("w"), "w");
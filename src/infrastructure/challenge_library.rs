@@ -0,0 +1,256 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::application::{FileWatcher, RunnerEvent};
+use crate::domain::{Challenge, LearningPath};
+use crate::infrastructure::challenge_loader::{LoadFailure, LoadReport, PATHS_FILE};
+use crate::infrastructure::{
+    BuiltinChallengeLoader, ChallengeLoader, FileChallengeLoader, FileChangeWatcher,
+    PackChallengeLoader, PackStore, TemplateChallengeLoader,
+};
+
+/// How long to let a burst of file events (an editor saving) settle before
+/// reloading
+const RELOAD_SETTLE_TIME: Duration = Duration::from_millis(100);
+
+/// Every challenge the app can offer: the built-in challenges with an
+/// on-disk challenges directory layered on top, challenges from git
+/// history and installed packs
+///
+/// The directories are watched, so challenge files edited while the app
+/// runs are picked up by `reload_if_changed` without a restart.
+pub struct ChallengeLibrary {
+    challenges_dir: PathBuf,
+    git_dir: PathBuf,
+    packs: PackStore,
+    challenges: Vec<Challenge>,
+    paths: Vec<LearningPath>,
+    failures: Vec<LoadFailure>,
+    watchers: Vec<FileChangeWatcher>,
+    changes: mpsc::Receiver<RunnerEvent>,
+}
+
+impl ChallengeLibrary {
+    /// Loads every source and starts watching the directories that exist
+    ///
+    /// Broken files are reported in `failures` rather than failing.
+    pub fn open(
+        challenges_dir: impl Into<PathBuf>,
+        git_dir: impl Into<PathBuf>,
+        packs: PackStore,
+    ) -> Result<Self> {
+        let (tx, changes) = mpsc::channel();
+        let mut library = Self {
+            challenges_dir: challenges_dir.into(),
+            git_dir: git_dir.into(),
+            packs,
+            challenges: Vec::new(),
+            paths: Vec::new(),
+            failures: Vec::new(),
+            watchers: Vec::new(),
+            changes,
+        };
+        library.reload()?;
+
+        for dir in [&library.challenges_dir, &library.git_dir, library.packs.packs_dir()] {
+            if dir.exists() {
+                let mut watcher = FileChangeWatcher::new();
+                // Without a watcher the library still works, just without live reload
+                if watcher.watch(dir, tx.clone()).is_ok() {
+                    library.watchers.push(watcher);
+                }
+            }
+        }
+        Ok(library)
+    }
+
+    pub fn challenges(&self) -> &[Challenge] {
+        &self.challenges
+    }
+
+    pub fn paths(&self) -> &[LearningPath] {
+        &self.paths
+    }
+
+    /// Files that failed to load in the latest reload
+    pub fn failures(&self) -> &[LoadFailure] {
+        &self.failures
+    }
+
+    /// Reloads everything if a watched file changed since the last call
+    ///
+    /// Returns whether it reloaded. If a source can no longer be read, the
+    /// previous challenges stay and the error becomes the only failure.
+    pub fn reload_if_changed(&mut self) -> bool {
+        if self.changes.try_recv().is_err() {
+            return false;
+        }
+        thread::sleep(RELOAD_SETTLE_TIME);
+        while self.changes.try_recv().is_ok() {}
+
+        if let Err(e) = self.reload() {
+            self.failures = vec![LoadFailure::new(&self.challenges_dir, None, format!("{:#}", e))];
+        }
+        true
+    }
+
+    fn reload(&mut self) -> Result<()> {
+        let builtin = BuiltinChallengeLoader::new();
+        let mut report = builtin.load()?;
+        let mut paths = builtin.load_paths()?;
+        let mut path_failures = Vec::new();
+
+        // An on-disk challenges directory (e.g. from `export-builtin`)
+        // replaces built-in challenges with the same id and adds the rest
+        if self.challenges_dir.exists() {
+            let loader = FileChallengeLoader::new(&self.challenges_dir);
+            report.overlay(Self::lenient(&self.challenges_dir, loader.load()));
+            let templates_dir = self.challenges_dir.join("templates");
+            let templates = TemplateChallengeLoader::new(&templates_dir).load();
+            report.overlay(Self::lenient(&templates_dir, templates));
+            if self.challenges_dir.join(PATHS_FILE).exists() {
+                match loader.load_paths() {
+                    Ok(on_disk) => paths = on_disk,
+                    Err(e) => path_failures.push(LoadFailure::new(
+                        self.challenges_dir.join(PATHS_FILE),
+                        None,
+                        format!("{:#}", e),
+                    )),
+                }
+            }
+        }
+
+        if self.git_dir.exists() {
+            report.extend(Self::lenient(&self.git_dir, FileChallengeLoader::new(&self.git_dir).load()));
+        }
+
+        let packs_dir = self.packs.packs_dir();
+        let packs = PackChallengeLoader::new(PackStore::new(packs_dir));
+        // Unreadable packs are one failure, not one for challenges and one for paths
+        match packs.load().and_then(|loaded| Ok((loaded, packs.load_paths_report()?))) {
+            Ok((loaded, (pack_paths, pack_path_failures))) => {
+                report.extend(loaded);
+                paths.extend(pack_paths);
+                path_failures.extend(pack_path_failures);
+            }
+            Err(e) => path_failures.push(LoadFailure::new(packs_dir, None, format!("{:#}", e))),
+        }
+
+        self.failures = report.failures().to_vec();
+        self.failures.extend(path_failures);
+        self.challenges = report.into_challenges();
+        self.paths = paths;
+        Ok(())
+    }
+
+    /// A source that cannot be read at all (e.g. emptied while authoring)
+    /// becomes one failure instead of an error
+    fn lenient(dir: &Path, report: Result<LoadReport>) -> LoadReport {
+        report.unwrap_or_else(|e| {
+            LoadReport::new(Vec::new(), vec![LoadFailure::new(dir, None, format!("{:#}", e))])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn challenge(id: &str, title: &str) -> String {
+        format!(
+            "[metadata]\nid = \"{}\"\ntitle = \"{}\"\ndescription = \"D\"\n\n[hints]\ngeneric = \"h\"\n\n[content]\nstarting = \"a\"\ntarget = \"b\"\n",
+            id, title
+        )
+    }
+
+    #[test]
+    fn test_reloads_edited_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let challenges_dir = dir.path().join("helix");
+        fs::create_dir(&challenges_dir).unwrap();
+        fs::write(challenges_dir.join("rename-01.toml"), challenge("rename-01", "Rename")).unwrap();
+        fs::write(challenges_dir.join("01-delete-word.toml"), challenge("delete-word-01", "Mine")).unwrap();
+
+        let mut library = ChallengeLibrary::open(
+            &challenges_dir,
+            dir.path().join("git"),
+            PackStore::new(dir.path().join("packs")),
+        )
+        .unwrap();
+        let title = |library: &ChallengeLibrary| {
+            let challenge = library.challenges().iter().find(|c| c.id() == "rename-01").unwrap();
+            challenge.title().to_string()
+        };
+        assert_eq!(title(&library), "Rename");
+        // The directory is layered over the built-in challenges
+        let builtin = BuiltinChallengeLoader::new().load().unwrap().into_challenges();
+        assert_eq!(library.challenges().len(), builtin.len() + 1);
+        assert_eq!(library.challenges()[0].title(), "Mine");
+        assert!(!library.reload_if_changed());
+
+        fs::write(challenges_dir.join("rename-01.toml"), challenge("rename-01", "Renamed")).unwrap();
+        fs::write(challenges_dir.join("broken-02.toml"), "[metadata\n").unwrap();
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while !library.reload_if_changed() {
+            assert!(std::time::Instant::now() < deadline, "no reload after edits");
+            thread::sleep(Duration::from_millis(20));
+        }
+        // Events of the second write may land after the first reload
+        thread::sleep(RELOAD_SETTLE_TIME);
+        library.reload_if_changed();
+
+        assert_eq!(title(&library), "Renamed");
+        assert_eq!(library.failures().len(), 1);
+        assert_eq!(library.failures()[0].line(), Some(1));
+    }
+
+    #[test]
+    fn test_broken_paths_are_reported_not_fatal() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let challenges_dir = dir.path().join("helix");
+        let pack = dir.path().join("packs/rust");
+        fs::create_dir(&challenges_dir).unwrap();
+        fs::create_dir_all(&pack).unwrap();
+        fs::write(challenges_dir.join("mine-01.toml"), challenge("mine-01", "Mine")).unwrap();
+        fs::write(challenges_dir.join(PATHS_FILE), "[[paths]\n").unwrap();
+        fs::write(pack.join("pack.toml"), "name = \"rust\"\nversion = \"1.0\"\n").unwrap();
+        fs::write(pack.join("rename-01.toml"), challenge("rename-01", "Rename")).unwrap();
+        fs::write(pack.join(PATHS_FILE), "[[paths]]\nname = 1\n").unwrap();
+
+        let library = ChallengeLibrary::open(
+            &challenges_dir,
+            dir.path().join("git"),
+            PackStore::new(dir.path().join("packs")),
+        )
+        .unwrap();
+        let failed: Vec<&Path> = library.failures().iter().map(LoadFailure::path).collect();
+        assert_eq!(failed, [challenges_dir.join(PATHS_FILE), pack.join(PATHS_FILE)]);
+        // The built-in paths stay when the on-disk ones are broken
+        assert!(!library.paths().is_empty());
+        assert!(library.challenges().iter().any(|c| c.id() == "rust/rename-01"));
+    }
+
+    #[test]
+    fn test_unreadable_sources_are_reported_against_themselves() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let challenges_dir = dir.path().join("helix");
+        let packs_dir = dir.path().join("packs");
+        fs::create_dir(&challenges_dir).unwrap();
+        fs::write(challenges_dir.join("mine-01.toml"), challenge("mine-01", "Mine")).unwrap();
+        // Files where directories are expected cannot be listed
+        fs::write(challenges_dir.join("templates"), "").unwrap();
+        fs::write(&packs_dir, "").unwrap();
+
+        let library =
+            ChallengeLibrary::open(&challenges_dir, dir.path().join("git"), PackStore::new(&packs_dir)).unwrap();
+        let failed: Vec<&Path> = library.failures().iter().map(LoadFailure::path).collect();
+        assert_eq!(failed, [challenges_dir.join("templates").as_path(), &packs_dir]);
+        assert!(library.challenges().iter().any(|c| c.id() == "mine-01"));
+    }
+}
@@ -0,0 +1,481 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::application::validator::SolutionValidator;
+use crate::domain::challenge::DIFFICULTIES;
+use crate::domain::Challenge;
+use crate::infrastructure::cast_parser::CastParser;
+use crate::infrastructure::challenge_loader::{
+    is_challenge_file, line_at, parse_document, parse_file, ChallengeLoader, TomlChallenge, FileChallengeLoader,
+    PATHS_FILE, TAGS_FILE,
+};
+
+/// Hint keys checked for blank values
+const HINT_KEYS: &[&str] = &["generic", "helix", "hint_1", "hint_2", "hint_3"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The challenge is broken or cannot be solved as written
+    Error,
+    /// The challenge works but does not follow the pack's conventions
+    Warning,
+}
+
+/// One problem found in a challenge pack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    path: PathBuf,
+    line: Option<usize>,
+    severity: Severity,
+    message: String,
+}
+
+impl LintIssue {
+    fn new(path: &Path, line: Option<usize>, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            line,
+            severity,
+            message: message.into(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 1-based line the problem is on, when it can be pinned to one
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}: {}", self.path.display(), line, severity, self.message),
+            None => write!(f, "{}: {}: {}", self.path.display(), severity, self.message),
+        }
+    }
+}
+
+/// `tags.toml`: the tags a pack's challenges may use
+#[derive(Debug, Deserialize)]
+struct TomlTags {
+    tags: Vec<String>,
+}
+
+/// A challenge file that parsed, with what the checks need from it
+struct LintedFile {
+    path: PathBuf,
+    content: String,
+    challenge: Challenge,
+}
+
+/// Checks every challenge file in a pack and reports all problems found,
+/// rather than stopping at the first like `FileChallengeLoader`
+pub struct ChallengeLinter {
+    challenges_dir: PathBuf,
+}
+
+impl ChallengeLinter {
+    pub fn new(challenges_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            challenges_dir: challenges_dir.into(),
+        }
+    }
+
+    /// All problems in the pack, ordered by file
+    pub fn lint(&self) -> Result<Vec<LintIssue>> {
+        let entries = fs::read_dir(&self.challenges_dir).with_context(|| {
+            format!(
+                "Failed to read challenges directory: {}",
+                self.challenges_dir.display()
+            )
+        })?;
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| is_challenge_file(path))
+            .collect();
+        paths.sort();
+
+        let known_tags = self.load_tags()?;
+        let mut issues = Vec::new();
+        let mut files = Vec::new();
+        for path in paths {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read challenge file: {}", path.display()))?;
+            if let Some(file) = Self::parse(&path, content, &mut issues) {
+                Self::check_file(&file, known_tags.as_ref(), &mut issues);
+                files.push(file);
+            }
+        }
+
+        self.check_pack(&files, &mut issues)?;
+        issues.sort_by(|a, b| a.path.cmp(&b.path).then(a.line.cmp(&b.line)));
+        Ok(issues)
+    }
+
+    /// The tags listed in the pack's `tags.toml`, if it has one
+    fn load_tags(&self) -> Result<Option<HashSet<String>>> {
+        let path = self.challenges_dir.join(TAGS_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read tags: {}", path.display()))?;
+        let tags: TomlTags = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse tags: {}", path.display()))?;
+        Ok(Some(tags.tags.into_iter().collect()))
+    }
+
+    /// Parses a file as the loader would, reporting why it fails if it does
+    fn parse(path: &Path, content: String, issues: &mut Vec<LintIssue>) -> Option<LintedFile> {
+        match parse_file(path, &content, TomlChallenge::into_domain) {
+            Ok(challenge) => Some(LintedFile {
+                path: path.to_path_buf(),
+                content,
+                challenge,
+            }),
+            Err(failure) => {
+                issues.push(LintIssue::new(path, failure.line(), Severity::Error, failure.message()));
+                None
+            }
+        }
+    }
+
+    /// Checks that need only the one file
+    fn check_file(file: &LintedFile, known_tags: Option<&HashSet<String>>, issues: &mut Vec<LintIssue>) {
+        let LintedFile { path, content, challenge } = file;
+        let mut report = |key: &str, nth: usize, severity, message: String| {
+            issues.push(LintIssue::new(path, key_line(content, key, nth), severity, message));
+        };
+
+        if let Some(expected) = expected_id(path) {
+            if challenge.id() != expected {
+                report(
+                    "id",
+                    0,
+                    Severity::Error,
+                    format!("id '{}' does not match the file name; expected '{}'", challenge.id(), expected),
+                );
+            }
+        }
+
+        let validator = SolutionValidator::new();
+        for (index, challenge_file) in challenge.files().iter().enumerate() {
+            let (starting, target) = (challenge_file.starting_content(), challenge_file.target_content());
+            if starting == target {
+                report("target", index, Severity::Error, "target is the same as starting".to_string());
+            } else if validator.is_valid(starting, target) {
+                report(
+                    "target",
+                    index,
+                    Severity::Error,
+                    "target differs from starting only in whitespace the validator ignores".to_string(),
+                );
+            }
+        }
+
+        // Hints are checked on the raw file since the loader fills in defaults
+        let raw: serde_json::Value = parse_document(path, content).unwrap_or_default();
+        let hints = raw.get("hints").and_then(|hints| hints.as_object());
+        let has_hint = |key: &str| hints.and_then(|hints| hints.get(key)).is_some_and(|value| !value.is_null());
+        if !has_hint("generic") && !has_hint("helix") {
+            report("[hints]", 0, Severity::Error, "no generic or helix hint".to_string());
+        }
+        for key in HINT_KEYS {
+            let value = hints.and_then(|hints| hints.get(*key)).and_then(|value| value.as_str());
+            if value.is_some_and(|value| value.trim().is_empty()) {
+                report(key, 0, Severity::Error, format!("hint '{}' is empty", key));
+            }
+        }
+
+        if let Some(difficulty) = challenge.difficulty() {
+            if !DIFFICULTIES.contains(&difficulty) {
+                report(
+                    "difficulty",
+                    0,
+                    Severity::Error,
+                    format!("unknown difficulty '{}'; use {}", difficulty, DIFFICULTIES.join(", ")),
+                );
+            }
+        }
+
+        if let Some(known_tags) = known_tags {
+            for tag in challenge.tags().iter().filter(|tag| !known_tags.contains(*tag)) {
+                report("tags", 0, Severity::Warning, format!("unknown tag '{}'; add it to {}", tag, TAGS_FILE));
+            }
+        }
+
+        if let (Some(solution), Some(keystrokes)) = (challenge.optimal_solution(), challenge.optimal_keystrokes()) {
+            let counted = CastParser::key_count(solution);
+            if counted != keystrokes as usize {
+                report(
+                    "optimal_keystrokes",
+                    0,
+                    Severity::Error,
+                    format!("optimal_keystrokes is {} but optimal_solution has {} keys", keystrokes, counted),
+                );
+            }
+        }
+    }
+
+    /// Checks across the pack: duplicate ids and references to unknown ids
+    fn check_pack(&self, files: &[LintedFile], issues: &mut Vec<LintIssue>) -> Result<()> {
+        let mut first_path: HashMap<&str, &Path> = HashMap::new();
+        for file in files {
+            let id = file.challenge.id();
+            match first_path.get(id) {
+                Some(first) => issues.push(LintIssue::new(
+                    &file.path,
+                    key_line(&file.content, "id", 0),
+                    Severity::Error,
+                    format!("duplicate id '{}' (also in {})", id, first.display()),
+                )),
+                None => {
+                    first_path.insert(id, &file.path);
+                }
+            }
+        }
+
+        for file in files {
+            for required in file.challenge.requires() {
+                if !first_path.contains_key(required.as_str()) {
+                    issues.push(LintIssue::new(
+                        &file.path,
+                        key_line(&file.content, "requires", 0),
+                        Severity::Error,
+                        format!("requires unknown challenge '{}'", required),
+                    ));
+                }
+            }
+        }
+
+        let paths_file = self.challenges_dir.join(PATHS_FILE);
+        let paths = match FileChallengeLoader::new(&self.challenges_dir).load_paths() {
+            Ok(paths) => paths,
+            Err(e) => {
+                issues.push(LintIssue::new(&paths_file, None, Severity::Error, format!("{:#}", e)));
+                return Ok(());
+            }
+        };
+        let paths_content = fs::read_to_string(&paths_file).unwrap_or_default();
+        for path in &paths {
+            for stage in path.stages() {
+                for id in stage.challenge_ids() {
+                    if !first_path.contains_key(id.as_str()) {
+                        issues.push(LintIssue::new(
+                            &paths_file,
+                            text_line(&paths_content, &format!("\"{}\"", id)),
+                            Severity::Error,
+                            format!("stage '{}' of path '{}' lists unknown challenge '{}'", stage.name(), path.name(), id),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The id a file's name implies: `03-find-replace.toml` holds
+/// `find-replace-03`, any other name holds its stem
+fn expected_id(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    Some(match stem.split_once('-') {
+        Some((number, slug)) if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) => {
+            format!("{}-{}", slug, number)
+        }
+        _ => stem.to_string(),
+    })
+}
+
+/// 1-based line of the `nth` (from 0) line assigning `key` (`key =` in
+/// TOML, `key:` in YAML, `"key":` in JSON), or of a table such as `[hints]`
+/// (its header in TOML, its key elsewhere)
+fn key_line(content: &str, key: &str, nth: usize) -> Option<usize> {
+    let table = key.strip_prefix('[').and_then(|key| key.strip_suffix(']'));
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim_start();
+            if line.starts_with('[') {
+                return table.is_some() && line.starts_with(key);
+            }
+            let key = table.unwrap_or(key);
+            let unquoted = line
+                .strip_prefix('"')
+                .and_then(|line| line.strip_prefix(key))
+                .and_then(|rest| rest.strip_prefix('"'));
+            unquoted
+                .or_else(|| line.strip_prefix(key))
+                .is_some_and(|rest| rest.trim_start().starts_with(['=', ':']))
+        })
+        .nth(nth)
+        .map(|(index, _)| index + 1)
+}
+
+/// 1-based line of the first occurrence of `text`
+fn text_line(content: &str, text: &str) -> Option<usize> {
+    content.find(text).map(|offset| line_at(content, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHALLENGE: &str = r#"[metadata]
+id = "rename-01"
+title = "Rename"
+description = "Rename the function"
+difficulty = "beginner"
+tags = ["rename"]
+
+[hints]
+generic = "Select and change"
+optimal_solution = "wcb\u001b"
+optimal_keystrokes = 4
+
+[content]
+starting = "fn a() {}"
+target = "fn b() {}"
+"#;
+
+    fn lint(files: &[(&str, &str)]) -> Vec<LintIssue> {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (name, content) in files {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+        fs::write(dir.path().join(TAGS_FILE), "tags = [\"rename\"]\n").unwrap();
+        ChallengeLinter::new(dir.path()).lint().unwrap()
+    }
+
+    fn messages(issues: &[LintIssue]) -> Vec<(Option<usize>, &str)> {
+        issues.iter().map(|issue| (issue.line(), issue.message())).collect()
+    }
+
+    #[test]
+    fn test_clean_challenge_has_no_issues() {
+        assert!(lint(&[("01-rename.toml", CHALLENGE)]).is_empty());
+    }
+
+    #[test]
+    fn test_reports_every_problem_with_its_line() {
+        let broken = CHALLENGE
+            .replace("rename-01", "renamed")
+            .replace("\"beginner\"", "\"expert\"")
+            .replace("[\"rename\"]", "[\"rename\", \"typo\"]")
+            .replace("Select and change", " ")
+            .replace("= 4", "= 9")
+            .replace("fn b() {}", "  fn a() {}");
+        let issues = lint(&[("01-rename.toml", &broken)]);
+
+        assert_eq!(
+            messages(&issues),
+            vec![
+                (Some(2), "id 'renamed' does not match the file name; expected 'rename-01'"),
+                (Some(5), "unknown difficulty 'expert'; use beginner, intermediate, advanced"),
+                (Some(6), "unknown tag 'typo'; add it to tags.toml"),
+                (Some(9), "hint 'generic' is empty"),
+                (Some(11), "optimal_keystrokes is 9 but optimal_solution has 4 keys"),
+                (Some(15), "target differs from starting only in whitespace the validator ignores"),
+            ]
+        );
+        assert_eq!(issues[2].severity(), Severity::Warning);
+        assert!(issues[0].to_string().ends_with("01-rename.toml:2: error: id 'renamed' does not match the file name; expected 'rename-01'"));
+    }
+
+    #[test]
+    fn test_hints_are_checked_in_every_format() {
+        let json = r#"{
+  "metadata": {"id": "rename-02", "title": "Rename", "description": "D", "tags": ["rename"]},
+  "hints": {"generic": "Select and change"},
+  "content": {"starting": "a", "target": "b"}
+}
+"#;
+        let yaml = "metadata:\n  id: rename-03\n  title: Rename\n  description: D\n  tags: [rename]\n\
+                    hints:\n  generic: Select and change\n\
+                    content:\n  starting: a\n  target: b\n";
+        let markdown = "---\nmetadata:\n  id: rename-04\n  title: Rename\n  description: D\n  tags: [rename]\n\
+                        hints:\n  generic: Select and change\n---\n\n\
+                        ```rust starting\na\n```\n\n```rust target\nb\n```\n";
+        let files = [("02-rename.json", json), ("03-rename.yaml", yaml), ("04-rename.md", markdown)];
+        assert!(lint(&files).is_empty());
+
+        for (name, content) in files {
+            let blank = content.replace("Select and change", " ");
+            assert_eq!(messages(&lint(&[(name, &blank)])).len(), 1, "{}", name);
+        }
+        // A blank YAML value is null rather than an empty string
+        let issues = lint(&[("03-rename.yaml", &yaml.replace("Select and change", ""))]);
+        assert_eq!(messages(&issues), [(Some(6), "no generic or helix hint")]);
+    }
+
+    #[test]
+    fn test_reports_parse_errors_and_duplicates() {
+        let unparsable = "[metadata]\nid = \"x\"\ntitle = \"Say \"hi\"\"\n";
+        let issues = lint(&[
+            ("01-rename.toml", CHALLENGE),
+            ("02-copy.toml", unparsable),
+            ("rename-01.toml", CHALLENGE),
+        ]);
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].line(), Some(3));
+        assert!(issues[1].message().starts_with("duplicate id 'rename-01'"));
+    }
+
+    #[test]
+    fn test_reports_unsafe_file_paths() {
+        let multi_file = CHALLENGE.replace(
+            "[content]\nstarting = \"fn a() {}\"\ntarget = \"fn b() {}\"\n",
+            "[[files]]\npath = \"../lib.rs\"\nstarting = \"a\"\ntarget = \"b\"\n",
+        );
+        let issues = lint(&[("01-rename.toml", &multi_file)]);
+
+        assert_eq!(messages(&issues), [(None, "File path '../lib.rs' must not contain '..'")]);
+        assert!(issues[0].is_error());
+    }
+
+    #[test]
+    fn test_key_line_in_every_format() {
+        assert_eq!(key_line("[metadata]\nid = \"a\"\n", "id", 0), Some(2));
+        assert_eq!(key_line("metadata:\n  id: a\n", "id", 0), Some(2));
+        assert_eq!(key_line("{\n  \"metadata\": {\n    \"id\": \"a\"", "id", 0), Some(3));
+        assert_eq!(key_line("identity = 1\n", "id", 0), None);
+        assert_eq!(key_line("id = 1\n[hints]\n", "[hints]", 0), Some(2));
+        assert_eq!(key_line("id: 1\nhints:\n", "[hints]", 0), Some(2));
+    }
+
+    #[test]
+    fn test_bundled_pack_has_no_errors() {
+        let issues = ChallengeLinter::new(concat!(env!("CARGO_MANIFEST_DIR"), "/challenges/helix"))
+            .lint()
+            .unwrap();
+        let problems: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+        assert!(problems.is_empty(), "{:#?}", problems);
+    }
+}
@@ -1,37 +1,196 @@
+use std::fmt;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
-use crate::domain::Challenge;
+use crate::domain::challenge::DEFAULT_FILE_PATH;
+use crate::domain::{Challenge, ChallengeFile, LearningPath, MasteryTier, PathStage};
+use crate::infrastructure::markdown_challenge::{self, yaml_message};
+
+/// File in a challenges directory that defines its learning paths
+pub const PATHS_FILE: &str = "paths.toml";
+
+/// File in a challenges directory listing the tags its challenges may use
+pub const TAGS_FILE: &str = "tags.toml";
+
+/// Manifest describing a challenges directory as an installable pack
+pub const MANIFEST_FILE: &str = "pack.toml";
+
+/// Formats a challenge file can be written in, picked by file extension
+///
+/// TOML, JSON and YAML files share one schema (`metadata`, `hints`, and
+/// `content` or `files`). Markdown files put `metadata` and `hints` in YAML
+/// front matter and the text in fenced `starting` and `target` code blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeFormat {
+    Toml,
+    Json,
+    Yaml,
+    Markdown,
+}
+
+impl ChallengeFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|s| s.to_str())? {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            "md" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+/// A challenge file that could not be loaded, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadFailure {
+    path: PathBuf,
+    line: Option<usize>,
+    message: String,
+}
+
+impl LoadFailure {
+    pub fn new(path: impl Into<PathBuf>, line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 1-based line of the problem, for files that are not valid TOML,
+    /// JSON, YAML or Markdown
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for LoadFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.path.display(), line, self.message),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+/// The challenges a source could load, and the files it could not
+#[derive(Debug)]
+pub struct LoadReport {
+    challenges: Vec<Challenge>,
+    failures: Vec<LoadFailure>,
+}
+
+impl LoadReport {
+    pub fn new(challenges: Vec<Challenge>, failures: Vec<LoadFailure>) -> Self {
+        Self { challenges, failures }
+    }
+
+    pub fn challenges(&self) -> &[Challenge] {
+        &self.challenges
+    }
+
+    pub fn failures(&self) -> &[LoadFailure] {
+        &self.failures
+    }
+
+    /// Adds another source's challenges and failures to this report
+    pub fn extend(&mut self, other: LoadReport) {
+        self.challenges.extend(other.challenges);
+        self.failures.extend(other.failures);
+    }
+
+    /// Layers another source's challenges over these, replacing challenges
+    /// with the same id
+    pub fn overlay(&mut self, other: LoadReport) {
+        for challenge in other.challenges {
+            match self.challenges.iter_mut().find(|existing| existing.id() == challenge.id()) {
+                Some(existing) => *existing = challenge,
+                None => self.challenges.push(challenge),
+            }
+        }
+        self.failures.extend(other.failures);
+    }
+
+    /// Namespaces the challenges by the pack they were installed from
+    pub fn in_pack(self, pack: &str) -> Self {
+        Self {
+            challenges: self.challenges.into_iter().map(|challenge| challenge.in_pack(pack)).collect(),
+            failures: self.failures,
+        }
+    }
+
+    pub fn into_challenges(self) -> Vec<Challenge> {
+        self.challenges
+    }
+}
 
 /// Trait for loading challenges from various sources
 pub trait ChallengeLoader {
-    /// Loads all available challenges
-    fn load_all(&self) -> Result<Vec<Challenge>>;
+    /// Loads every challenge that can be loaded and reports the files that
+    /// cannot, so one broken file does not hide the rest
+    fn load(&self) -> Result<LoadReport>;
+
+    /// Loads all available challenges, failing on the first broken file
+    fn load_all(&self) -> Result<Vec<Challenge>> {
+        let report = self.load()?;
+        if let Some(failure) = report.failures().first() {
+            anyhow::bail!("Invalid challenge file {}", failure);
+        }
+        Ok(report.into_challenges())
+    }
+
+    /// Loads the learning paths through the challenges, if the source defines any
+    fn load_paths(&self) -> Result<Vec<LearningPath>> {
+        Ok(Vec::new())
+    }
 }
 
-/// TOML file structure for challenge definitions
-#[derive(Debug, Deserialize)]
-struct TomlChallenge {
+/// TOML file structure for challenge definitions, also used for JSON, YAML
+/// and Markdown challenges
+///
+/// Single-file challenges use a `[content]` table; multi-file challenges
+/// use one `[[files]]` entry per file instead.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct TomlChallenge {
     metadata: Metadata,
     hints: Hints,
-    content: Content,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content: Option<Content>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    files: Vec<FileContent>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct Metadata {
     id: String,
     title: String,
     description: String,
     #[serde(default)]
     difficulty: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     tags: Vec<String>,
+    /// Ids of challenges that unlock this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    requires: Vec<String>,
+    /// Tier the prerequisites need (default: bronze, i.e. completed)
+    #[serde(default)]
+    requires_tier: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct Hints {
     #[serde(default)]
     generic: Option<String>,
@@ -47,31 +206,169 @@ struct Hints {
     optimal_solution: Option<String>,
     #[serde(default)]
     optimal_keystrokes: Option<u32>,
+    /// Seconds a practised user should need; gold requires finishing within it
+    #[serde(default)]
+    par_time: Option<f64>,
+}
+
+/// `paths.toml`: ordered learning paths through the challenges
+#[derive(Debug, Deserialize)]
+struct TomlPaths {
+    #[serde(default)]
+    paths: Vec<TomlPath>,
 }
 
 #[derive(Debug, Deserialize)]
+struct TomlPath {
+    name: String,
+    stages: Vec<TomlStage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlStage {
+    name: String,
+    challenges: Vec<String>,
+}
+
+impl TomlPaths {
+    fn into_domain(self) -> Vec<LearningPath> {
+        self.paths
+            .into_iter()
+            .map(|path| {
+                let stages = path
+                    .stages
+                    .into_iter()
+                    .map(|stage| PathStage::new(stage.name, stage.challenges))
+                    .collect();
+                LearningPath::new(path.name, stages)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 struct Content {
     starting: String,
     target: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct FileContent {
+    path: String,
+    starting: String,
+    target: String,
+}
+
+/// Rejects `[[files]]` paths that would escape the workspace or collide,
+/// since each one is written under the challenge's temp directory
+fn check_file_paths<'a>(paths: impl Iterator<Item = &'a str>) -> Result<()> {
+    let mut seen: Vec<PathBuf> = Vec::new();
+    for path in paths {
+        let relative = Path::new(path);
+        if path.is_empty() || relative.has_root() {
+            anyhow::bail!("File path '{}' must be relative to the workspace", path);
+        }
+        if relative.components().any(|part| !matches!(part, Component::Normal(_) | Component::CurDir)) {
+            anyhow::bail!("File path '{}' must not contain '..'", path);
+        }
+        let normalized: PathBuf = relative.components().filter(|part| *part != Component::CurDir).collect();
+        if seen.contains(&normalized) {
+            anyhow::bail!("File path '{}' is listed more than once", path);
+        }
+        seen.push(normalized);
+    }
+    Ok(())
+}
+
 impl TomlChallenge {
-    fn into_domain(self) -> Challenge {
-        // Prefer helix-specific hint, fall back to generic
+    pub(crate) fn from_domain(challenge: &Challenge) -> Self {
+        let (content, files) = match challenge.files() {
+            [file] if file.path() == DEFAULT_FILE_PATH => (
+                Some(Content {
+                    starting: file.starting_content().to_string(),
+                    target: file.target_content().to_string(),
+                }),
+                Vec::new(),
+            ),
+            files => (
+                None,
+                files
+                    .iter()
+                    .map(|file| FileContent {
+                        path: file.path().to_string(),
+                        starting: file.starting_content().to_string(),
+                        target: file.target_content().to_string(),
+                    })
+                    .collect(),
+            ),
+        };
+        let mut progressive_hints = challenge.progressive_hints().iter().cloned();
+        let requires = challenge.requires().to_vec();
+
+        Self {
+            metadata: Metadata {
+                id: challenge.id().to_string(),
+                title: challenge.title().to_string(),
+                description: challenge.description().to_string(),
+                difficulty: challenge.difficulty().map(str::to_string),
+                tags: challenge.tags().to_vec(),
+                requires_tier: (!requires.is_empty())
+                    .then(|| challenge.required_tier().name().to_lowercase()),
+                requires,
+            },
+            hints: Hints {
+                generic: Some(challenge.generic_hint().to_string()),
+                helix: challenge.helix_hint().map(str::to_string),
+                hint_1: progressive_hints.next(),
+                hint_2: progressive_hints.next(),
+                hint_3: progressive_hints.next(),
+                optimal_solution: challenge.optimal_solution().map(str::to_string),
+                optimal_keystrokes: challenge.optimal_keystrokes(),
+                par_time: challenge.par_time().map(|par| par.as_secs_f64()),
+            },
+            content,
+            files,
+        }
+    }
+
+    pub(crate) fn into_domain(self) -> Result<Challenge> {
+        let files: Vec<ChallengeFile> = match (self.content, self.files.is_empty()) {
+            (Some(_), false) => {
+                anyhow::bail!("Challenge defines both [content] and [[files]]; use only one")
+            }
+            (Some(content), true) => vec![ChallengeFile::new(
+                DEFAULT_FILE_PATH,
+                content.starting,
+                content.target,
+            )],
+            (None, false) => {
+                check_file_paths(self.files.iter().map(|file| file.path.as_str()))?;
+                self.files
+                    .into_iter()
+                    .map(|file| ChallengeFile::new(file.path, file.starting, file.target))
+                    .collect()
+            }
+            (None, true) => anyhow::bail!("Challenge must define either [content] or [[files]]"),
+        };
+
         let hint = self
             .hints
-            .helix
-            .or(self.hints.generic)
+            .generic
             .unwrap_or_else(|| "No hint available".to_string());
 
         let mut challenge = Challenge::new(
             self.metadata.id,
             self.metadata.title,
             self.metadata.description,
-            self.content.starting,
-            self.content.target,
+            "",
+            "",
             hint,
-        );
+        )
+        .with_files(files);
+
+        if let Some(helix_hint) = self.hints.helix {
+            challenge = challenge.with_helix_hint(helix_hint);
+        }
 
         if let Some(difficulty) = self.metadata.difficulty {
             challenge = challenge.with_difficulty(difficulty);
@@ -81,6 +378,19 @@ impl TomlChallenge {
             challenge = challenge.with_tags(self.metadata.tags);
         }
 
+        if !self.metadata.requires.is_empty() {
+            let tier = match self.metadata.requires_tier {
+                Some(name) => MasteryTier::from_name(&name).with_context(|| {
+                    format!(
+                        "Unknown requires_tier '{}'; use bronze, silver, gold or platinum",
+                        name
+                    )
+                })?,
+                None => MasteryTier::Bronze,
+            };
+            challenge = challenge.with_requirements(self.metadata.requires, tier);
+        }
+
         // Collect progressive hints
         let mut progressive_hints = Vec::new();
         if let Some(hint_1) = self.hints.hint_1 {
@@ -102,49 +412,118 @@ impl TomlChallenge {
             challenge = challenge.with_optimal_solution(solution, keystrokes);
         }
 
-        challenge
+        if let Some(par_time) = self.hints.par_time {
+            let par_time = Duration::try_from_secs_f64(par_time)
+                .ok()
+                .filter(|par| !par.is_zero())
+                .with_context(|| format!("par_time must be a positive number of seconds, got {}", par_time))?;
+            challenge = challenge.with_par_time(par_time);
+        }
+
+        Ok(challenge)
     }
 }
 
-/// Loads challenges from TOML files in a directory
-pub struct TomlChallengeLoader {
+/// Parses the content of a challenge file into a challenge; see
+/// `parse_document`
+pub(crate) fn parse_file<T: DeserializeOwned>(
+    path: &Path,
+    content: &str,
+    into_domain: impl FnOnce(T) -> Result<Challenge>,
+) -> std::result::Result<Challenge, LoadFailure> {
+    let parsed: T = parse_document(path, content)?;
+    into_domain(parsed).map_err(|e| LoadFailure::new(path, None, format!("{:#}", e)))
+}
+
+/// Deserializes the content of a challenge file in the format its
+/// extension names, pointing syntax errors at their line
+pub(crate) fn parse_document<T: DeserializeOwned>(
+    path: &Path,
+    content: &str,
+) -> std::result::Result<T, LoadFailure> {
+    Ok(match ChallengeFormat::from_path(path) {
+        Some(ChallengeFormat::Toml) => toml::from_str(content).map_err(|e| {
+            let line = e.span().map(|span| line_at(content, span.start));
+            LoadFailure::new(path, line, e.message().trim())
+        })?,
+        Some(ChallengeFormat::Json) => serde_json::from_str(content).map_err(|e| {
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            LoadFailure::new(path, Some(e.line()).filter(|line| *line > 0), message)
+        })?,
+        Some(ChallengeFormat::Yaml) => serde_yaml::from_str(content).map_err(|e| {
+            LoadFailure::new(path, e.location().map(|location| location.line()), yaml_message(&e))
+        })?,
+        Some(ChallengeFormat::Markdown) => {
+            serde_yaml::from_value(markdown_challenge::to_document(path, content)?)
+                .map_err(|e| LoadFailure::new(path, None, yaml_message(&e)))?
+        }
+        None => return Err(LoadFailure::new(path, None, "Unknown challenge file format")),
+    })
+}
+
+/// Reads and parses a challenge file; see `parse_file`
+pub(crate) fn load_file<T: DeserializeOwned>(
+    path: &Path,
+    into_domain: impl FnOnce(T) -> Result<Challenge>,
+) -> std::result::Result<Challenge, LoadFailure> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| LoadFailure::new(path, None, format!("Failed to read file: {}", e)))?;
+    parse_file(path, &content, into_domain)
+}
+
+/// 1-based line of a byte offset
+pub(crate) fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+/// Whether a path in a challenges directory is a challenge definition
+/// (a `.toml`, `.json`, `.yaml`, `.yml` or `.md` file other than the
+/// paths, tags and manifest files)
+pub fn is_challenge_file(path: &Path) -> bool {
+    path.is_file() && is_challenge_name(path)
+}
+
+/// `is_challenge_file` by name alone, for files not on disk
+pub(crate) fn is_challenge_name(path: &Path) -> bool {
+    let name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+    ChallengeFormat::from_path(path).is_some()
+        && ![PATHS_FILE, TAGS_FILE, MANIFEST_FILE].contains(&name)
+        && !name.eq_ignore_ascii_case("README.md")
+}
+
+/// Parses a `paths.toml`
+pub(crate) fn parse_paths(path: &Path, content: &str) -> Result<Vec<LearningPath>> {
+    let paths: TomlPaths = toml::from_str(content)
+        .with_context(|| format!("Failed to parse learning paths: {}", path.display()))?;
+    Ok(paths.into_domain())
+}
+
+/// Loads challenges from the challenge files in a directory, whatever
+/// their format
+pub struct FileChallengeLoader {
     challenges_dir: PathBuf,
 }
 
-impl TomlChallengeLoader {
+impl FileChallengeLoader {
     pub fn new(challenges_dir: impl Into<PathBuf>) -> Self {
         Self {
             challenges_dir: challenges_dir.into(),
         }
     }
-
-    /// Loads a single TOML file and parses it into a Challenge
-    fn load_toml_file(&self, path: &Path) -> Result<Challenge> {
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read TOML file: {}", path.display()))?;
-
-        let toml_challenge: TomlChallenge = toml::from_str(&content).with_context(|| {
-            format!(
-                "Failed to parse TOML file: {}. Check that all required fields are present.",
-                path.display()
-            )
-        })?;
-
-        Ok(toml_challenge.into_domain())
-    }
 }
 
-impl ChallengeLoader for TomlChallengeLoader {
-    fn load_all(&self) -> Result<Vec<Challenge>> {
+impl ChallengeLoader for FileChallengeLoader {
+    fn load(&self) -> Result<LoadReport> {
         // Check if challenges directory exists
         if !self.challenges_dir.exists() {
             anyhow::bail!(
-                "Challenges directory not found: {}\n\nPlease create the directory and add TOML challenge files.",
+                "Challenges directory not found: {}\n\nPlease create the directory and add challenge files.",
                 self.challenges_dir.display()
             );
         }
 
-        // Read all .toml files from the directory
+        // Read all challenge files from the directory
         let entries = fs::read_dir(&self.challenges_dir).with_context(|| {
             format!(
                 "Failed to read challenges directory: {}",
@@ -153,39 +532,344 @@ impl ChallengeLoader for TomlChallengeLoader {
         })?;
 
         let mut challenges = Vec::new();
-        let mut toml_files: Vec<PathBuf> = Vec::new();
+        let mut failures = Vec::new();
+        let mut challenge_files: Vec<PathBuf> = Vec::new();
 
-        // Collect all .toml files
+        // Collect all challenge files
         for entry in entries {
             let entry = entry?;
             let path = entry.path();
 
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("toml") {
-                toml_files.push(path);
+            if is_challenge_file(&path) {
+                challenge_files.push(path);
             }
         }
 
         // Sort files by name for consistent ordering
-        toml_files.sort();
+        challenge_files.sort();
 
-        // Load each TOML file
-        for path in toml_files {
-            match self.load_toml_file(&path) {
+        // Load each file in the format its extension names
+        for path in challenge_files {
+            match load_file(&path, TomlChallenge::into_domain) {
                 Ok(challenge) => challenges.push(challenge),
-                Err(e) => {
-                    // Fail fast on malformed TOML
-                    return Err(e);
-                }
+                Err(failure) => failures.push(failure),
             }
         }
 
-        if challenges.is_empty() {
+        if challenges.is_empty() && failures.is_empty() {
             anyhow::bail!(
-                "No challenges found in directory: {}\n\nPlease add .toml challenge files to this directory.",
+                "No challenges found in directory: {}\n\nPlease add .toml, .json, .yaml or .md challenge files to this directory.",
                 self.challenges_dir.display()
             );
         }
 
-        Ok(challenges)
+        Ok(LoadReport::new(challenges, failures))
+    }
+
+    fn load_paths(&self) -> Result<Vec<LearningPath>> {
+        let path = self.challenges_dir.join(PATHS_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read learning paths: {}", path.display()))?;
+        parse_paths(&path, &content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"
+[metadata]
+id = "rename-01"
+title = "Rename"
+description = "Rename the function"
+
+[hints]
+generic = "Select and change"
+"#;
+
+    fn parse(body: &str) -> Result<Challenge> {
+        toml::from_str::<TomlChallenge>(&format!("{}{}", METADATA, body))?.into_domain()
+    }
+
+    #[test]
+    fn test_parse_single_file_challenge() {
+        let challenge = parse(
+            r#"
+[content]
+starting = "a"
+target = "b"
+"#,
+        )
+        .unwrap();
+
+        assert!(!challenge.is_multi_file());
+        assert_eq!(challenge.starting_content(), "a");
+        assert_eq!(challenge.target_content(), "b");
+    }
+
+    #[test]
+    fn test_parse_multi_file_challenge() {
+        let challenge = parse(
+            r#"
+[[files]]
+path = "src/lib.rs"
+starting = "fn a() {}"
+target = "fn b() {}"
+
+[[files]]
+path = "src/main.rs"
+starting = "a();"
+target = "b();"
+"#,
+        )
+        .unwrap();
+
+        assert!(challenge.is_multi_file());
+        assert_eq!(challenge.files()[0].path(), "src/lib.rs");
+        assert_eq!(challenge.files()[1].target_content(), "b();");
+    }
+
+    #[test]
+    fn test_unsafe_or_duplicate_file_paths_are_rejected() {
+        let files = |first: &str, second: &str| {
+            format!(
+                "[[files]]\npath = \"{}\"\nstarting = \"a\"\ntarget = \"b\"\n\n\
+                 [[files]]\npath = \"{}\"\nstarting = \"a\"\ntarget = \"b\"\n",
+                first, second
+            )
+        };
+
+        assert!(parse(&files("src/lib.rs", "src/main.rs")).is_ok());
+        for (first, second) in [
+            ("src/lib.rs", "src/lib.rs"),
+            ("src/lib.rs", "./src/lib.rs"),
+            ("/etc/passwd", "src/lib.rs"),
+            ("src/lib.rs", "../lib.rs"),
+            ("src/../../lib.rs", "src/lib.rs"),
+            ("", "src/lib.rs"),
+        ] {
+            assert!(parse(&files(first, second)).is_err(), "{} {}", first, second);
+        }
+    }
+
+    #[test]
+    fn test_missing_content_is_rejected() {
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn test_parse_par_time() {
+        let challenge = parse(
+            r#"optimal_solution = "xd"
+optimal_keystrokes = 2
+par_time = 7.5
+
+[content]
+starting = "a"
+target = "b"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(challenge.par_time(), Some(Duration::from_millis(7500)));
+        assert_eq!(challenge.tier_thresholds().optimal_keystrokes(), Some(2));
+    }
+
+    #[test]
+    fn test_non_positive_par_time_is_rejected() {
+        let body = r#"par_time = 0
+
+[content]
+starting = "a"
+target = "b"
+"#;
+        assert!(parse(body).is_err());
+        assert!(parse(&body.replace("0", "-3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_requirements() {
+        let toml = r#"
+[metadata]
+id = "rename-02"
+title = "Rename again"
+description = "Rename the function"
+requires = ["rename-01"]
+requires_tier = "silver"
+
+[hints]
+
+[content]
+starting = "a"
+target = "b"
+"#;
+        let challenge = toml::from_str::<TomlChallenge>(toml).unwrap().into_domain().unwrap();
+
+        assert_eq!(challenge.requires(), ["rename-01"]);
+        assert_eq!(challenge.required_tier(), MasteryTier::Silver);
+
+        let unknown = toml.replace("silver", "diamond");
+        assert!(toml::from_str::<TomlChallenge>(&unknown).unwrap().into_domain().is_err());
+    }
+
+    #[test]
+    fn test_load_paths_skips_paths_file_when_loading_challenges() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("01-rename.toml"),
+            format!("{}\n[content]\nstarting = \"a\"\ntarget = \"b\"\n", METADATA),
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join(PATHS_FILE),
+            r#"
+[[paths]]
+name = "Basics"
+
+[[paths.stages]]
+name = "Renaming"
+challenges = ["rename-01"]
+"#,
+        )
+        .unwrap();
+
+        let loader = FileChallengeLoader::new(dir.path());
+        assert_eq!(loader.load_all().unwrap().len(), 1);
+
+        let paths = loader.load_paths().unwrap();
+        assert_eq!(paths[0].name(), "Basics");
+        assert_eq!(paths[0].stages()[0].challenge_ids(), ["rename-01"]);
+    }
+
+    #[test]
+    fn test_load_skips_and_reports_broken_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("01-rename.toml"),
+            format!("{}\n[content]\nstarting = \"a\"\ntarget = \"b\"\n", METADATA),
+        )
+        .unwrap();
+        fs::write(dir.path().join("02-typo.toml"), "[metadata]\nid = \"typo-02\"\ntitle = \"Say \"hi\"\"\n").unwrap();
+        fs::write(dir.path().join("03-empty.toml"), METADATA).unwrap();
+
+        let loader = FileChallengeLoader::new(dir.path());
+        let report = loader.load().unwrap();
+        assert_eq!(report.challenges().len(), 1);
+        assert_eq!(report.failures().len(), 2);
+        assert_eq!(report.failures()[0].path(), dir.path().join("02-typo.toml"));
+        assert_eq!(report.failures()[0].line(), Some(3));
+        assert!(report.failures()[1].message().contains("[content] or [[files]]"));
+
+        assert!(loader.load_all().is_err());
+    }
+
+    #[test]
+    fn test_formats_load_identical_challenges() {
+        let toml = r#"
+[metadata]
+id = "rename-01"
+title = "Rename"
+description = "Rename the function"
+difficulty = "beginner"
+tags = ["rename"]
+
+[hints]
+generic = "Select and change"
+hint_1 = "Use w"
+optimal_solution = "wc"
+optimal_keystrokes = 2
+
+[content]
+starting = "fn total() {}"
+target = "fn sum() {}"
+"#;
+        let json = r#"{
+  "metadata": {
+    "id": "rename-01",
+    "title": "Rename",
+    "description": "Rename the function",
+    "difficulty": "beginner",
+    "tags": ["rename"]
+  },
+  "hints": {
+    "generic": "Select and change",
+    "hint_1": "Use w",
+    "optimal_solution": "wc",
+    "optimal_keystrokes": 2
+  },
+  "content": { "starting": "fn total() {}", "target": "fn sum() {}" }
+}
+"#;
+        let front_matter = r#"metadata:
+  id: rename-01
+  title: Rename
+  description: Rename the function
+  difficulty: beginner
+  tags: [rename]
+hints:
+  generic: Select and change
+  hint_1: Use w
+  optimal_solution: wc
+  optimal_keystrokes: 2
+"#;
+        let yaml = format!("{}content:
+  starting: \"fn total() {{}}\"
+  target: \"fn sum() {{}}\"
+", front_matter);
+        let markdown = format!(
+            "---
+{}---
+
+# Rename
+
+```rust starting
+fn total() {{}}
+```
+
+```rust target
+fn sum() {{}}
+```
+",
+            front_matter
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        for (name, content) in [
+            ("01-rename.toml", toml),
+            ("02-rename.json", json),
+            ("03-rename.yaml", yaml.as_str()),
+            ("04-rename.md", markdown.as_str()),
+        ] {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+
+        let challenges = FileChallengeLoader::new(dir.path()).load_all().unwrap();
+        assert_eq!(challenges.len(), 4);
+        assert!(challenges.iter().all(|challenge| *challenge == challenges[0]));
+        assert_eq!(challenges[0].target_content(), "fn sum() {}");
+    }
+
+    #[test]
+    fn test_syntax_errors_point_at_lines() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("01-broken.json"), "{\n  \"metadata\": {\n    \"id\": \n").unwrap();
+        fs::write(dir.path().join("02-broken.yaml"), "metadata:\n  id: rename\n\ttitle: x\n").unwrap();
+
+        let report = FileChallengeLoader::new(dir.path()).load().unwrap();
+        assert_eq!(report.failures()[0].line(), Some(4));
+        assert!(!report.failures()[0].message().contains(" at line "));
+        assert_eq!(report.failures()[1].line(), Some(3));
+    }
+
+    #[test]
+    fn test_bundled_paths_parse() {
+        let loader = FileChallengeLoader::new(concat!(env!("CARGO_MANIFEST_DIR"), "/challenges/helix"));
+        let paths = loader.load_paths().unwrap();
+        assert!(paths.iter().all(|path| !path.stages().is_empty()));
     }
 }
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::domain::Challenge;
+use crate::infrastructure::challenge_loader::TomlChallenge;
+
+/// Writes challenges as TOML files that `FileChallengeLoader` reads back
+pub struct TomlChallengeWriter {
+    challenges_dir: PathBuf,
+}
+
+impl TomlChallengeWriter {
+    pub fn new(challenges_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            challenges_dir: challenges_dir.into(),
+        }
+    }
+
+    /// Whether `id` is safe as a file name: lowercase letters, digits and '-'
+    pub fn is_valid_id(id: &str) -> bool {
+        !id.is_empty()
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+
+    /// Writes `<id>.toml`, replacing any earlier version, and returns its path
+    pub fn write(&self, challenge: &Challenge) -> Result<PathBuf> {
+        if !Self::is_valid_id(challenge.id()) {
+            anyhow::bail!(
+                "Invalid challenge id '{}': use lowercase letters, digits and '-' only",
+                challenge.id()
+            );
+        }
+        fs::create_dir_all(&self.challenges_dir).with_context(|| {
+            format!(
+                "Failed to create challenges directory: {}",
+                self.challenges_dir.display()
+            )
+        })?;
+
+        let toml = toml::to_string_pretty(&TomlChallenge::from_domain(challenge))
+            .with_context(|| format!("Failed to serialize challenge {}", challenge.id()))?;
+        let path = self.challenges_dir.join(format!("{}.toml", challenge.id()));
+        fs::write(&path, toml)
+            .with_context(|| format!("Failed to write challenge file: {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ChallengeFile, MasteryTier};
+    use crate::infrastructure::{ChallengeLoader, FileChallengeLoader};
+    use std::time::Duration;
+
+    #[test]
+    fn test_written_challenges_load_back() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let writer = TomlChallengeWriter::new(dir.path().join("git"));
+
+        let single = Challenge::new("a-1", "Quote \"this\"", "Desc", "x\ty\n\u{1b}", "y", "hint")
+            .with_tags(vec!["rust".to_string()])
+            .with_helix_hint("helix hint")
+            .with_progressive_hints(vec!["first".to_string()])
+            .with_optimal_solution("xd\u{1b}", 3)
+            .with_par_time(Duration::from_millis(2500));
+        let multi = Challenge::new("b-2", "Multi", "Desc", "", "", "hint")
+            .with_files(vec![
+                ChallengeFile::new("lib.rs", "fn a() {}", "fn b() {}"),
+            ])
+            .with_requirements(vec!["a-1".to_string()], MasteryTier::Gold);
+        writer.write(&single).unwrap();
+        writer.write(&multi).unwrap();
+
+        let loaded = FileChallengeLoader::new(dir.path().join("git")).load_all().unwrap();
+        assert_eq!(loaded[0].id(), "a-1");
+        assert_eq!(loaded[0].title(), single.title());
+        assert_eq!(loaded[0].files(), single.files());
+        assert_eq!(loaded[0].generic_hint(), "hint");
+        assert_eq!(loaded[0].hint(), "helix hint");
+        assert_eq!(loaded[0].progressive_hints(), ["first"]);
+        assert_eq!(loaded[0].optimal_solution(), Some("xd\u{1b}"));
+        assert_eq!(loaded[0].par_time(), Some(Duration::from_millis(2500)));
+        assert_eq!(loaded[1].files()[0].path(), "lib.rs");
+        assert_eq!(loaded[1].requires(), ["a-1"]);
+        assert_eq!(loaded[1].required_tier(), MasteryTier::Gold);
+    }
+
+    #[test]
+    fn test_ids_that_leave_the_directory_are_refused() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let writer = TomlChallengeWriter::new(dir.path().join("git"));
+
+        for id in ["../escape", "a/b", "Upper", ""] {
+            let challenge = Challenge::new(id, "T", "D", "a", "b", "hint");
+            assert!(writer.write(&challenge).is_err(), "{:?} was written", id);
+        }
+        assert!(!dir.path().join("escape.toml").exists());
+    }
+}
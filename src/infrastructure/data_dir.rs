@@ -0,0 +1,24 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+/// Directory for the user's editor-dojo data, created if missing
+///
+/// `~/.local/share/editor-dojo` on Linux and macOS, `%APPDATA%/editor-dojo`
+/// on Windows.
+pub fn data_dir() -> Result<PathBuf> {
+    let data_dir = if cfg!(target_os = "windows") {
+        dirs::data_dir()
+            .context("Failed to get APPDATA directory")?
+            .join("editor-dojo")
+    } else {
+        dirs::data_local_dir()
+            .context("Failed to get local data directory")?
+            .join("editor-dojo")
+    };
+
+    fs::create_dir_all(&data_dir)
+        .with_context(|| format!("Failed to create data directory: {}", data_dir.display()))?;
+    Ok(data_dir)
+}
@@ -1,85 +1,242 @@
-use std::cell::RefCell;
-use std::path::Path;
-use std::process::{Child, Command};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::mpsc;
 
 use anyhow::{Context, Result};
+use tempfile::TempDir;
 
-use crate::application::EditorSpawner;
+use super::pty_session::PtySession;
+use crate::application::{EditorSession, EditorSpawner, LaunchCommand, RunnerEvent, Workspace};
+use crate::domain::ConfigMode;
 
-/// Helix editor spawner implementation
-pub struct HelixEditor {
-    process: RefCell<Option<Child>>,
+/// Terminal editors the dojo can launch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorKind {
+    Helix,
+    Vim,
+    Neovim,
+    Emacs,
 }
 
-impl HelixEditor {
-    pub fn new() -> Self {
-        Self {
-            process: RefCell::new(None),
+impl EditorKind {
+    /// Get all supported editors
+    pub fn all() -> Vec<EditorKind> {
+        vec![
+            EditorKind::Helix,
+            EditorKind::Vim,
+            EditorKind::Neovim,
+            EditorKind::Emacs,
+        ]
+    }
+
+    /// Look up an editor by its display name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name) || kind.command() == name)
+    }
+
+    /// Get the display name of the editor
+    pub fn name(&self) -> &'static str {
+        match self {
+            EditorKind::Helix => "Helix",
+            EditorKind::Vim => "Vim",
+            EditorKind::Neovim => "Neovim",
+            EditorKind::Emacs => "Emacs",
         }
     }
 
-    /// Checks if Helix is installed on the system
-    pub fn is_installed() -> bool {
-        Command::new("hx").arg("--version").output().is_ok()
+    /// Get the executable name
+    pub fn command(&self) -> &'static str {
+        match self {
+            EditorKind::Helix => "hx",
+            EditorKind::Vim => "vim",
+            EditorKind::Neovim => "nvim",
+            EditorKind::Emacs => "emacs",
+        }
+    }
+
+    /// Whether keystrokes count towards tiers and bests; the bundled optimal
+    /// solutions are Helix key sequences
+    pub fn ranks_keystrokes(&self) -> bool {
+        matches!(self, EditorKind::Helix)
+    }
+
+    /// Keys that quit the editor without saving, from any mode
+    ///
+    /// Each starts by cancelling whatever is in progress (Esc / C-g) so it
+    /// also works from insert mode or a half-typed command.
+    pub fn quit_sequence(&self) -> &'static [u8] {
+        match self {
+            EditorKind::Helix | EditorKind::Vim | EditorKind::Neovim => b"\x1b\x1b:qa!\r",
+            // C-g C-x C-c; the challenge files are already saved, so no prompt
+            EditorKind::Emacs => b"\x07\x07\x18\x03",
+        }
+    }
+
+    /// Arguments needed in every mode
+    fn base_args(&self) -> &'static [&'static str] {
+        match self {
+            // Keep Emacs in the terminal instead of opening a GUI frame
+            EditorKind::Emacs => &["-nw"],
+            _ => &[],
+        }
     }
-}
 
-impl Default for HelixEditor {
-    fn default() -> Self {
-        Self::new()
+    /// Arguments that make the editor skip user configuration and plugins
+    fn vanilla_args(&self) -> &'static [&'static str] {
+        match self {
+            // Helix has no flag for this; it is isolated via XDG_CONFIG_HOME
+            EditorKind::Helix => &[],
+            EditorKind::Vim => &["-u", "NONE", "-i", "NONE", "-N"],
+            EditorKind::Neovim => &["--clean"],
+            EditorKind::Emacs => &["-Q"],
+        }
     }
 }
 
-impl EditorSpawner for HelixEditor {
-    fn spawn(&mut self, file_path: &Path) -> Result<()> {
-        let child = Command::new("hx")
-            .arg(file_path)
-            .spawn()
-            .context("Failed to spawn Helix editor. Is 'hx' installed?")?;
-
-        *self.process.borrow_mut() = Some(child);
-        Ok(())
-    }
-
-    fn terminate(&mut self) -> Result<()> {
-        if let Some(mut process) = self.process.borrow_mut().take() {
-            // Try to kill gracefully
-            process
-                .kill()
-                .context("Failed to terminate editor process")?;
-            process
-                .wait()
-                .context("Failed to wait for editor process")?;
+/// Editor spawner for terminal editors
+///
+/// In vanilla mode every session gets a freshly generated, empty config
+/// directory (`HOME`/`XDG_CONFIG_HOME` override plus the editor's own
+/// "no user config" flag), so remapped keys never skew keystroke counts.
+/// The directory lives as long as the spawner.
+pub struct TerminalEditor {
+    kind: EditorKind,
+    config_mode: ConfigMode,
+    isolated_config: Option<TempDir>,
+}
+
+impl TerminalEditor {
+    pub fn new(kind: EditorKind) -> Self {
+        Self {
+            kind,
+            config_mode: ConfigMode::Vanilla,
+            isolated_config: None,
         }
-        Ok(())
     }
 
-    fn is_running(&self) -> bool {
-        let mut process_guard = self.process.borrow_mut();
-        if let Some(ref mut process) = *process_guard {
-            // Try to check if process has exited without blocking
-            match process.try_wait() {
-                Ok(Some(_)) => {
-                    // Process has exited
-                    false
-                }
-                Ok(None) => {
-                    // Process is still running
-                    true
-                }
-                Err(_) => {
-                    // Error checking, assume not running
-                    false
+    pub fn with_config_mode(mut self, config_mode: ConfigMode) -> Self {
+        self.config_mode = config_mode;
+        self
+    }
+
+    /// Checks if the editor is installed on the system
+    pub fn is_installed(kind: EditorKind) -> bool {
+        Command::new(kind.command()).arg("--version").output().is_ok()
+    }
+
+    /// Creates an empty home/config directory for this session
+    fn prepare_isolated_config(&mut self) -> Result<PathBuf> {
+        let dir = tempfile::Builder::new()
+            .prefix("editor-dojo-config-")
+            .tempdir()
+            .context("Failed to create isolated editor config directory")?;
+
+        let config_home = dir.path().join(".config");
+        fs::create_dir_all(&config_home).with_context(|| {
+            format!("Failed to create config directory: {}", config_home.display())
+        })?;
+
+        let home = dir.path().to_path_buf();
+        self.isolated_config = Some(dir);
+        Ok(home)
+    }
+
+    /// Helix looks for its runtime (themes, grammars) next to the user config.
+    /// Point it there explicitly so hiding the config doesn't hide the runtime.
+    fn helix_runtime_dir() -> Option<PathBuf> {
+        if std::env::var_os("HELIX_RUNTIME").is_some() {
+            return None;
+        }
+        dirs::config_dir()
+            .map(|dir| dir.join("helix").join("runtime"))
+            .filter(|dir| dir.is_dir())
+    }
+}
+
+impl EditorSpawner for TerminalEditor {
+    fn launch_command(&mut self, workspace: &Workspace) -> Result<LaunchCommand> {
+        // Run from the workspace root so the file picker shows the challenge files
+        let mut command = LaunchCommand::new(self.kind.command(), workspace.root());
+        for arg in self.kind.base_args() {
+            command = command.arg(*arg);
+        }
+
+        if self.config_mode == ConfigMode::Vanilla {
+            for arg in self.kind.vanilla_args() {
+                command = command.arg(*arg);
+            }
+
+            let home = self.prepare_isolated_config()?;
+            if self.kind == EditorKind::Helix {
+                if let Some(runtime) = Self::helix_runtime_dir() {
+                    command = command.env("HELIX_RUNTIME", runtime.display().to_string());
                 }
             }
-        } else {
-            false
+            command = command
+                .env("HOME", home.display().to_string())
+                .env("XDG_CONFIG_HOME", home.join(".config").display().to_string());
+        }
+
+        for file in workspace.files() {
+            command = command.arg(file.display().to_string());
         }
+
+        Ok(command)
+    }
+
+    fn spawn(
+        &mut self,
+        command: &LaunchCommand,
+        events: mpsc::Sender<RunnerEvent>,
+    ) -> Result<Box<dyn EditorSession>> {
+        let session = PtySession::spawn(command, self.kind.quit_sequence(), events)
+            .with_context(|| {
+                format!(
+                    "Failed to spawn {} editor. Is '{}' installed?",
+                    self.kind.name(),
+                    self.kind.command()
+                )
+            })?;
+        Ok(Box::new(session))
     }
 }
 
-impl Drop for HelixEditor {
-    fn drop(&mut self) {
-        let _ = self.terminate();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> Workspace {
+        Workspace::new(PathBuf::from("/tmp/dojo"), vec![PathBuf::from("/tmp/dojo/a.txt")])
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(EditorKind::from_name("Helix"), Some(EditorKind::Helix));
+        assert_eq!(EditorKind::from_name("neovim"), Some(EditorKind::Neovim));
+        assert_eq!(EditorKind::from_name("nvim"), Some(EditorKind::Neovim));
+        assert_eq!(EditorKind::from_name("nano"), None);
+    }
+
+    #[test]
+    fn test_vanilla_launch_isolates_config() {
+        let mut editor = TerminalEditor::new(EditorKind::Vim);
+        let command = editor.launch_command(&workspace()).unwrap();
+
+        assert_eq!(command.program(), "vim");
+        assert_eq!(command.args(), ["-u", "NONE", "-i", "NONE", "-N", "/tmp/dojo/a.txt"]);
+        let home = command.envs().iter().find(|(k, _)| k == "HOME").unwrap();
+        assert!(PathBuf::from(&home.1).join(".config").is_dir());
+    }
+
+    #[test]
+    fn test_personal_launch_uses_user_config() {
+        let mut editor = TerminalEditor::new(EditorKind::Emacs).with_config_mode(ConfigMode::Personal);
+        let command = editor.launch_command(&workspace()).unwrap();
+
+        assert_eq!(command.args(), ["-nw", "/tmp/dojo/a.txt"]);
+        assert!(command.envs().is_empty());
     }
 }
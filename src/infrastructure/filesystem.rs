@@ -1,10 +1,11 @@
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path};
 
 use anyhow::{Context, Result};
 use tempfile::Builder;
 
-use crate::application::FileSystem;
+use crate::application::{FileSystem, Workspace};
+use crate::domain::ChallengeFile;
 
 /// Concrete implementation of FileSystem using the standard library and tempfile
 pub struct LocalFileSystem;
@@ -13,6 +14,23 @@ impl LocalFileSystem {
     pub fn new() -> Self {
         Self
     }
+
+    /// Ensures a challenge file path stays inside the workspace
+    fn validate_relative_path(path: &str) -> Result<()> {
+        let relative = Path::new(path);
+        let is_contained = !path.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if !is_contained {
+            anyhow::bail!(
+                "Invalid challenge file path '{}': paths must be relative and stay inside the workspace",
+                path
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Default for LocalFileSystem {
@@ -22,38 +40,76 @@ impl Default for LocalFileSystem {
 }
 
 impl FileSystem for LocalFileSystem {
-    fn create_temp_file(&self, content: &str) -> Result<PathBuf> {
-        // Create a persistent temp file that won't be auto-deleted
-        let temp_file = Builder::new()
+    fn create_workspace(&self, files: &[ChallengeFile]) -> Result<Workspace> {
+        for file in files {
+            Self::validate_relative_path(file.path())?;
+        }
+
+        // Create a persistent temp directory that won't be auto-deleted
+        let root = Builder::new()
             .prefix("editor-dojo-")
-            .suffix(".txt")
-            .tempfile()
-            .context("Failed to create temporary file")?;
+            .tempdir()
+            .context("Failed to create temporary workspace")?
+            .keep();
 
-        use std::io::Write;
-        let mut file = temp_file.as_file();
-        file.write_all(content.as_bytes())
-            .context("Failed to write content to temporary file")?;
-        file.sync_all().context("Failed to sync temporary file")?;
+        let mut paths = Vec::with_capacity(files.len());
+        for file in files {
+            let path = root.join(file.path());
 
-        // Persist the temp file so it won't be deleted when dropped
-        let (_, path) = temp_file
-            .keep()
-            .context("Failed to persist temporary file")?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).with_context(|| {
+                    format!("Failed to create workspace directory: {}", parent.display())
+                })?;
+            }
+
+            fs::write(&path, file.starting_content())
+                .with_context(|| format!("Failed to write workspace file: {}", path.display()))?;
+            paths.push(path);
+        }
 
-        Ok(path)
+        Ok(Workspace::new(root, paths))
     }
 
     fn read_file(&self, path: &Path) -> Result<String> {
         fs::read_to_string(path).with_context(|| format!("Failed to read file: {}", path.display()))
     }
 
-    fn cleanup(&self, path: &Path) -> Result<()> {
-        // If the file still exists, remove it
-        if path.exists() {
-            fs::remove_file(path)
-                .with_context(|| format!("Failed to remove temporary file: {}", path.display()))?;
+    fn cleanup(&self, workspace: &Workspace) -> Result<()> {
+        // If the workspace still exists, remove it with everything inside
+        let root = workspace.root();
+        if root.exists() {
+            fs::remove_dir_all(root)
+                .with_context(|| format!("Failed to remove temporary workspace: {}", root.display()))?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_and_cleanup_workspace() {
+        let filesystem = LocalFileSystem::new();
+        let files = vec![
+            ChallengeFile::new("src/lib.rs", "pub fn a() {}", "pub fn b() {}"),
+            ChallengeFile::new("src/main.rs", "fn main() {}", "fn main() {}"),
+        ];
+
+        let workspace = filesystem.create_workspace(&files).unwrap();
+        assert_eq!(workspace.files().len(), 2);
+        assert!(workspace.files()[0].starts_with(workspace.root()));
+        assert_eq!(filesystem.read_file(&workspace.files()[0]).unwrap(), "pub fn a() {}");
+
+        filesystem.cleanup(&workspace).unwrap();
+        assert!(!workspace.root().exists());
+    }
+
+    #[test]
+    fn test_rejects_paths_outside_workspace() {
+        let filesystem = LocalFileSystem::new();
+        let files = vec![ChallengeFile::new("../escape.txt", "", "")];
+        assert!(filesystem.create_workspace(&files).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+use crate::domain::{DiffHunk, HunkLine};
+
+/// Marks the start of a commit header in our `git log` format
+const COMMIT_MARKER: char = '\u{1}';
+/// Separates the hash from the subject in a commit header
+const FIELD_SEPARATOR: char = '\u{2}';
+
+/// Reads diff hunks from a local git repository through the `git` CLI
+pub struct GitHistory {
+    repo: PathBuf,
+}
+
+impl GitHistory {
+    pub fn new(repo: impl Into<PathBuf>) -> Self {
+        Self { repo: repo.into() }
+    }
+
+    /// Hunks of the latest `commits` non-merge commits, newest first
+    pub fn hunks(&self, commits: usize) -> Result<Vec<DiffHunk>> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&self.repo)
+            .args(["log", "--no-merges", "--no-color", "--no-ext-diff", "-p", "--unified=2"])
+            .arg(format!("--max-count={}", commits))
+            .arg(format!("--format={}%h{}%s", COMMIT_MARKER, FIELD_SEPARATOR))
+            .output()
+            .context("Failed to run git; is it installed and in PATH?")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "git log failed in {}: {}",
+                self.repo.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(parse_log(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+/// Parses `git log -p` output in the format used by `GitHistory::hunks`
+///
+/// Deleted and binary files yield no hunks.
+fn parse_log(log: &str) -> Vec<DiffHunk> {
+    let mut hunks = Vec::new();
+    let mut commit = "";
+    let mut subject = "";
+    let mut path: Option<&str> = None;
+    let mut lines: Option<Vec<HunkLine>> = None;
+
+    let mut finish = |path: Option<&str>, lines: Option<Vec<HunkLine>>, commit: &str, subject: &str| {
+        if let (Some(path), Some(lines)) = (path, lines) {
+            if !lines.is_empty() {
+                hunks.push(DiffHunk::new(commit, subject, path, lines));
+            }
+        }
+    };
+
+    for line in log.lines() {
+        if let Some(header) = line.strip_prefix(COMMIT_MARKER) {
+            finish(path.take(), lines.take(), commit, subject);
+            (commit, subject) = header.split_once(FIELD_SEPARATOR).unwrap_or((header, ""));
+        } else if line.starts_with("diff --git ") {
+            finish(path.take(), lines.take(), commit, subject);
+        } else if let (None, Some(new_path)) = (&lines, line.strip_prefix("+++ ")) {
+            path = new_path.strip_prefix("b/");
+        } else if line.starts_with("@@") {
+            finish(path, lines.take(), commit, subject);
+            lines = Some(Vec::new());
+        } else if let Some(hunk) = lines.as_mut() {
+            match line.chars().next() {
+                Some(' ') => hunk.push(HunkLine::Context(line[1..].to_string())),
+                Some('-') => hunk.push(HunkLine::Removed(line[1..].to_string())),
+                Some('+') => hunk.push(HunkLine::Added(line[1..].to_string())),
+                // Blank context lines can lose their leading space
+                None => hunk.push(HunkLine::Context(String::new())),
+                // "\ No newline at end of file"
+                _ => {}
+            }
+        }
+    }
+    finish(path, lines, commit, subject);
+
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log() {
+        let log = "\u{1}abc1234\u{2}Rename total
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@ fn main() {
+ fn main() {
+-    let total = 1;
++    let sum = 1;
+ }
+@@ -10,2 +10,1 @@
+ a
+-b
+diff --git a/old.txt b/old.txt
+deleted file mode 100644
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+\u{1}def5678\u{2}Add logo
+diff --git a/logo.png b/logo.png
+Binary files /dev/null and b/logo.png differ
+";
+        let hunks = parse_log(log);
+
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].commit(), "abc1234");
+        assert_eq!(hunks[0].subject(), "Rename total");
+        assert_eq!(hunks[0].path(), "src/lib.rs");
+        assert_eq!(hunks[0].after(), "fn main() {\n    let sum = 1;\n}");
+        assert_eq!(hunks[1].before(), "a\nb");
+        assert_eq!(hunks[1].after(), "a");
+    }
+}
@@ -1,5 +1,9 @@
 use crate::application::ProgressRepository;
-use crate::domain::{AchievementId, ChallengeStats, Progress, UnlockedAchievement};
+use crate::infrastructure::data_dir::data_dir;
+use crate::domain::{
+    AchievementId, Attempt, BlindMode, ChallengeStats, ConfigMode, DailyResult, GauntletResult,
+    GauntletSplit, Progress, ReviewState, Score, UnlockedAchievement,
+};
 use anyhow::{Context, Result};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
@@ -20,6 +24,20 @@ impl JsonProgressRepository {
         Ok(Self { file_path })
     }
 
+    /// Create repository for a named profile, kept apart from the default
+    /// progress in `progress-<name>.json`
+    pub fn for_profile(name: &str) -> Result<Self> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            anyhow::bail!("Invalid profile name '{}'; use letters, digits, '-' and '_'", name);
+        }
+        let file_path = data_dir()?.join(format!("progress-{}.json", name));
+        Ok(Self { file_path })
+    }
+
     /// Create repository with custom path (useful for testing)
     pub fn with_path(file_path: PathBuf) -> Self {
         Self { file_path }
@@ -27,22 +45,7 @@ impl JsonProgressRepository {
 
     /// Get default progress file path based on OS
     fn default_progress_path() -> Result<PathBuf> {
-        let data_dir = if cfg!(target_os = "windows") {
-            // Windows: %APPDATA%/editor-dojo
-            dirs::data_dir()
-                .context("Failed to get APPDATA directory")?
-                .join("editor-dojo")
-        } else {
-            // Linux/Mac: ~/.local/share/editor-dojo
-            dirs::data_local_dir()
-                .context("Failed to get local data directory")?
-                .join("editor-dojo")
-        };
-
-        // Create directory if it doesn't exist
-        fs::create_dir_all(&data_dir)?;
-
-        Ok(data_dir.join("progress.json"))
+        Ok(data_dir()?.join("progress.json"))
     }
 
     /// Backup corrupted progress file
@@ -84,7 +87,7 @@ impl ProgressRepository for JsonProgressRepository {
             }
         };
 
-        Ok(dto.to_domain())
+        Ok(dto.into_domain())
     }
 
     fn save(&self, progress: &Progress) -> Result<()> {
@@ -113,22 +116,113 @@ impl ProgressRepository for JsonProgressRepository {
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct ProgressDto {
     editor_preference: Option<String>,
+    /// Legacy whole-second total, read only when `total_practice_time_ms` is absent
+    #[serde(default, skip_serializing)]
     total_practice_time_secs: u64,
+    #[serde(default)]
+    total_practice_time_ms: Option<u64>,
     last_practice_date: Option<String>,
     longest_streak: u32,
     challenges: HashMap<String, ChallengeStatsDto>,
     #[serde(default)]
     unlocked_achievements: Vec<UnlockedAchievementDto>,
+    #[serde(default)]
+    config_mode: ConfigMode,
+    #[serde(default)]
+    blind_mode: BlindMode,
+    /// Attempts made with the user's own editor config, kept apart from vanilla stats
+    #[serde(default)]
+    personal_config_challenges: HashMap<String, ChallengeStatsDto>,
+    /// Spaced-repetition schedule by challenge id
+    #[serde(default)]
+    reviews: HashMap<String, ReviewStateDto>,
+    /// Gauntlet runs, oldest first
+    #[serde(default)]
+    gauntlets: Vec<GauntletResultDto>,
+    /// The scored daily challenge attempt of each day played
+    #[serde(default)]
+    daily_results: Vec<DailyResultDto>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ChallengeStatsDto {
     completed: bool,
+    /// Legacy whole-second best, read only when `best_time_ms` is absent
+    #[serde(default, skip_serializing)]
     best_time_secs: Option<u64>,
+    #[serde(default)]
+    best_time_ms: Option<u64>,
+    #[serde(default)]
+    blind_best_time_ms: Option<u64>,
+    #[serde(default)]
+    blind_best_keystrokes: Option<u32>,
     best_keystrokes: Option<u32>,
+    #[serde(default)]
+    best_score: Option<u32>,
+    /// Score of every scored attempt, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    scores: Vec<ScoreEntryDto>,
+    /// False for files written before hint usage was tracked; their
+    /// completions all count as hint-free
+    #[serde(default)]
+    hints_tracked: bool,
+    #[serde(default)]
+    unassisted_best_time_ms: Option<u64>,
+    #[serde(default)]
+    unassisted_best_keystrokes: Option<u32>,
     first_completed_at: Option<String>,
     last_attempted_at: Option<String>,
     attempt_count: u32,
+    /// Content hash of the challenge the bests were set on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_version: Option<String>,
+    /// Bests set on an earlier content version
+    #[serde(default)]
+    legacy_bests: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ScoreEntryDto {
+    attempted_at: String,
+    points: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReviewStateDto {
+    ease_factor: f64,
+    interval_days: u32,
+    repetitions: u32,
+    due: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GauntletResultDto {
+    /// Leaderboard key; runs saved before it existed use their name
+    #[serde(default)]
+    key: Option<String>,
+    name: String,
+    finished_at: String,
+    total_time_ms: u64,
+    legs: usize,
+    splits: Vec<GauntletSplitDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GauntletSplitDto {
+    challenge_id: String,
+    time_ms: u64,
+    keystrokes: Option<u32>,
+    completed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DailyResultDto {
+    date: String,
+    challenge_id: String,
+    completed: bool,
+    time_ms: u64,
+    keystrokes: Option<u32>,
+    score: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -151,21 +245,45 @@ impl ProgressDto {
             .map(|a| UnlockedAchievementDto::from_domain(a))
             .collect();
 
+        let personal_config_challenges = progress
+            .personal_config_stats()
+            .iter()
+            .map(|(id, stats)| (id.clone(), ChallengeStatsDto::from_domain(stats)))
+            .collect();
+
         Self {
             editor_preference: progress.editor_preference().map(|s| s.to_string()),
-            total_practice_time_secs: progress.total_practice_time().as_secs(),
+            total_practice_time_secs: 0,
+            total_practice_time_ms: Some(progress.total_practice_time().as_millis() as u64),
             last_practice_date: progress.last_practice_date().map(|d| d.to_string()),
             longest_streak: progress.longest_streak(),
             challenges,
             unlocked_achievements,
+            config_mode: progress.config_mode(),
+            blind_mode: progress.blind_mode(),
+            personal_config_challenges,
+            reviews: progress
+                .reviews()
+                .iter()
+                .map(|(id, state)| (id.clone(), ReviewStateDto::from_domain(state)))
+                .collect(),
+            gauntlets: progress
+                .gauntlets()
+                .iter()
+                .map(GauntletResultDto::from_domain)
+                .collect(),
+            daily_results: progress
+                .daily_results()
+                .map(DailyResultDto::from_domain)
+                .collect(),
         }
     }
 
-    fn to_domain(self) -> Progress {
+    fn into_domain(self) -> Progress {
         let challenge_stats = self
             .challenges
             .into_iter()
-            .map(|(id, dto)| (id.clone(), dto.to_domain(id)))
+            .map(|(id, dto)| (id.clone(), dto.into_domain(id)))
             .collect();
 
         let last_practice_date = self
@@ -175,18 +293,139 @@ impl ProgressDto {
         let unlocked_achievements = self
             .unlocked_achievements
             .into_iter()
-            .filter_map(|dto| dto.to_domain())
+            .filter_map(|dto| dto.into_domain())
             .map(|a| (a.id(), a))
             .collect();
 
+        let personal_config_stats = self
+            .personal_config_challenges
+            .into_iter()
+            .map(|(id, dto)| (id.clone(), dto.into_domain(id)))
+            .collect();
+
         Progress::with_values(
             challenge_stats,
-            Duration::from_secs(self.total_practice_time_secs),
+            self.total_practice_time_ms
+                .map(Duration::from_millis)
+                .unwrap_or_else(|| Duration::from_secs(self.total_practice_time_secs)),
             last_practice_date,
             self.longest_streak,
             self.editor_preference,
             unlocked_achievements,
         )
+        .with_personal_config_stats(personal_config_stats)
+        .set_config_mode(self.config_mode)
+        .set_blind_mode(self.blind_mode)
+        .with_reviews(
+            self.reviews
+                .into_iter()
+                .filter_map(|(id, dto)| dto.into_domain().map(|state| (id, state)))
+                .collect(),
+        )
+        .with_gauntlets(
+            self.gauntlets
+                .into_iter()
+                .filter_map(GauntletResultDto::into_domain)
+                .collect(),
+        )
+        .with_daily_results(
+            self.daily_results
+                .into_iter()
+                .filter_map(DailyResultDto::into_domain)
+                .collect(),
+        )
+    }
+}
+
+impl DailyResultDto {
+    fn from_domain(result: &DailyResult) -> Self {
+        Self {
+            date: result.date().to_string(),
+            challenge_id: result.challenge_id().to_string(),
+            completed: result.is_completed(),
+            time_ms: result.time().as_millis() as u64,
+            keystrokes: result.keystrokes(),
+            score: result.score().points(),
+        }
+    }
+
+    fn into_domain(self) -> Option<DailyResult> {
+        let date = NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").ok()?;
+        Some(DailyResult::new(
+            date,
+            self.challenge_id,
+            self.completed,
+            Duration::from_millis(self.time_ms),
+            self.keystrokes,
+            Score::new(self.score),
+        ))
+    }
+}
+
+impl GauntletResultDto {
+    fn from_domain(result: &GauntletResult) -> Self {
+        Self {
+            key: Some(result.key().to_string()),
+            name: result.name().to_string(),
+            finished_at: result.finished_at().to_rfc3339(),
+            total_time_ms: result.total_time().as_millis() as u64,
+            legs: result.legs(),
+            splits: result
+                .splits()
+                .iter()
+                .map(|split| GauntletSplitDto {
+                    challenge_id: split.challenge_id().to_string(),
+                    time_ms: split.time().as_millis() as u64,
+                    keystrokes: split.keystrokes(),
+                    completed: split.is_completed(),
+                })
+                .collect(),
+        }
+    }
+
+    fn into_domain(self) -> Option<GauntletResult> {
+        let finished_at = DateTime::parse_from_rfc3339(&self.finished_at).ok()?;
+        let splits = self
+            .splits
+            .into_iter()
+            .map(|split| {
+                GauntletSplit::new(
+                    split.challenge_id,
+                    Duration::from_millis(split.time_ms),
+                    split.keystrokes,
+                    split.completed,
+                )
+            })
+            .collect();
+        Some(GauntletResult::new(
+            self.key.unwrap_or_else(|| self.name.clone()),
+            self.name,
+            finished_at.with_timezone(&Utc),
+            Duration::from_millis(self.total_time_ms),
+            self.legs,
+            splits,
+        ))
+    }
+}
+
+impl ReviewStateDto {
+    fn from_domain(state: &ReviewState) -> Self {
+        Self {
+            ease_factor: state.ease_factor(),
+            interval_days: state.interval_days(),
+            repetitions: state.repetitions(),
+            due: state.due().to_string(),
+        }
+    }
+
+    fn into_domain(self) -> Option<ReviewState> {
+        let due = NaiveDate::parse_from_str(&self.due, "%Y-%m-%d").ok()?;
+        Some(ReviewState::new(
+            self.ease_factor,
+            self.interval_days,
+            self.repetitions,
+            due,
+        ))
     }
 }
 
@@ -194,8 +433,23 @@ impl ChallengeStatsDto {
     fn from_domain(stats: &ChallengeStats) -> Self {
         Self {
             completed: stats.is_completed(),
-            best_time_secs: stats.best_time().map(|d| d.as_secs()),
+            best_time_secs: None,
+            best_time_ms: stats.best_time().map(|d| d.as_millis() as u64),
+            blind_best_time_ms: stats.blind_best_time().map(|d| d.as_millis() as u64),
+            blind_best_keystrokes: stats.blind_best_keystrokes(),
             best_keystrokes: stats.best_keystrokes(),
+            best_score: stats.best_score().map(|score| score.points()),
+            scores: stats
+                .scores()
+                .iter()
+                .map(|(attempted_at, score)| ScoreEntryDto {
+                    attempted_at: attempted_at.to_rfc3339(),
+                    points: score.points(),
+                })
+                .collect(),
+            hints_tracked: true,
+            unassisted_best_time_ms: stats.unassisted_best_time().map(|d| d.as_millis() as u64),
+            unassisted_best_keystrokes: stats.unassisted_best_keystrokes(),
             first_completed_at: stats
                 .first_completed_at()
                 .map(|dt| dt.to_rfc3339()),
@@ -203,10 +457,12 @@ impl ChallengeStatsDto {
                 .last_attempted_at()
                 .map(|dt| dt.to_rfc3339()),
             attempt_count: stats.attempt_count(),
+            content_version: stats.content_version().map(str::to_string),
+            legacy_bests: stats.has_legacy_bests(),
         }
     }
 
-    fn to_domain(self, challenge_id: String) -> ChallengeStats {
+    fn into_domain(self, challenge_id: String) -> ChallengeStats {
         let first_completed_at = self
             .first_completed_at
             .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
@@ -220,8 +476,12 @@ impl ChallengeStatsDto {
         // Reconstruct ChallengeStats using public methods
         let mut stats = ChallengeStats::new(challenge_id);
 
-        if self.completed && self.best_time_secs.is_some() {
-            let time = Duration::from_secs(self.best_time_secs.unwrap());
+        let best_time = self
+            .best_time_ms
+            .map(Duration::from_millis)
+            .or(self.best_time_secs.map(Duration::from_secs));
+
+        if let (true, Some(time)) = (self.completed, best_time) {
             let completed_at = first_completed_at.unwrap_or_else(Utc::now);
 
             // Create initial completed stats
@@ -242,9 +502,52 @@ impl ChallengeStatsDto {
                     attempt_at,
                 );
             }
+        } else if let (true, Some(blind_best_time_ms)) = (self.completed, self.blind_best_time_ms) {
+            // Completed only blind: there are no regular bests to start from
+            let completed_at = first_completed_at.unwrap_or_else(Utc::now);
+            let attempt = Attempt::new(
+                true,
+                Duration::from_millis(blind_best_time_ms),
+                self.blind_best_keystrokes,
+                completed_at,
+            )
+            .with_blind(true);
+            stats = stats.record(&attempt);
+            for _ in 1..self.attempt_count {
+                let attempt_at = last_attempted_at.unwrap_or(completed_at);
+                stats = stats.record(&Attempt::new(false, attempt.time(), None, attempt_at));
+            }
+        }
+
+        if self.hints_tracked {
+            let unassisted_best = self
+                .unassisted_best_time_ms
+                .map(|ms| (Duration::from_millis(ms), self.unassisted_best_keystrokes));
+            stats = stats.with_unassisted_best(unassisted_best);
         }
 
-        stats
+        if let Some(best_score) = self.best_score {
+            stats = stats.with_best_score(Score::new(best_score));
+        }
+
+        let scores = self
+            .scores
+            .into_iter()
+            .filter_map(|entry| {
+                let attempted_at = DateTime::parse_from_rfc3339(&entry.attempted_at).ok()?;
+                Some((attempted_at.with_timezone(&Utc), Score::new(entry.points)))
+            })
+            .collect();
+        stats = stats.with_scores(scores);
+
+        if let Some(blind_best_time_ms) = self.blind_best_time_ms {
+            stats = stats.with_blind_best(
+                Duration::from_millis(blind_best_time_ms),
+                self.blind_best_keystrokes,
+            );
+        }
+
+        stats.with_version_state(self.content_version, self.legacy_bests)
     }
 }
 
@@ -256,7 +559,7 @@ impl UnlockedAchievementDto {
         }
     }
 
-    fn to_domain(self) -> Option<UnlockedAchievement> {
+    fn into_domain(self) -> Option<UnlockedAchievement> {
         DateTime::parse_from_rfc3339(&self.unlocked_at)
             .ok()
             .map(|dt| UnlockedAchievement::new(self.id, dt.with_timezone(&Utc)))
@@ -268,6 +571,12 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_profile_names_stay_in_data_dir() {
+        assert!(JsonProgressRepository::for_profile("../work").is_err());
+        assert!(JsonProgressRepository::for_profile("").is_err());
+    }
+
     #[test]
     fn test_save_and_load_progress() {
         let temp_dir = TempDir::new().unwrap();
@@ -290,6 +599,233 @@ mod tests {
         assert_eq!(loaded.total_practice_time(), Duration::from_secs(10));
     }
 
+    #[test]
+    fn test_save_and_load_personal_config_progress() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let mut progress = Progress::new().set_config_mode(ConfigMode::Personal);
+        progress.record_personal_config(
+            "test-1".to_string(),
+            Attempt::new(true, Duration::from_secs(10), Some(15), Utc::now()),
+        );
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        assert_eq!(loaded.config_mode(), ConfigMode::Personal);
+        assert_eq!(loaded.total_completed(), 0);
+        assert_eq!(loaded.personal_config_completed(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load_millisecond_times() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let mut progress = Progress::new();
+        progress.record_attempt(
+            "test-1".to_string(),
+            true,
+            Duration::from_millis(8_123),
+            Some(15),
+            Utc::now(),
+        );
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        let stats = loaded.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_time(), Some(Duration::from_millis(8_123)));
+        assert_eq!(loaded.total_practice_time(), Duration::from_millis(8_123));
+    }
+
+    #[test]
+    fn test_save_and_load_blind_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let mut progress = Progress::new();
+        progress.record(
+            "test-1".to_string(),
+            Attempt::new(true, Duration::from_secs(20), Some(30), Utc::now()).with_blind(true),
+        );
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        let stats = loaded.get_challenge_stats("test-1").unwrap();
+        assert!(stats.is_completed());
+        assert_eq!(stats.best_time(), None);
+        assert_eq!(stats.blind_best_time(), Some(Duration::from_secs(20)));
+        assert_eq!(stats.blind_best_keystrokes(), Some(30));
+    }
+
+    #[test]
+    fn test_save_and_load_best_score() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let mut progress = Progress::new();
+        progress.record(
+            "test-1".to_string(),
+            Attempt::new(true, Duration::from_secs(20), Some(30), Utc::now())
+                .with_score(Score::new(640)),
+        );
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        let stats = loaded.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_score(), Some(Score::new(640)));
+        assert_eq!(stats.scores().len(), 1);
+        assert_eq!(stats.scores()[0].1, Score::new(640));
+    }
+
+    #[test]
+    fn test_save_and_load_content_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let mut progress = Progress::new();
+        progress.record_attempt("test-1".to_string(), true, Duration::from_secs(20), Some(30), Utc::now());
+        progress.set_content_version("test-1", "aaaa");
+        progress.set_content_version("test-1", "bbbb");
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        let stats = loaded.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.content_version(), Some("bbbb"));
+        assert!(stats.has_legacy_bests());
+        assert_eq!(stats.best_time(), Some(Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn test_save_and_load_hint_usage() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let mut progress = Progress::new();
+        progress.record(
+            "hinted".to_string(),
+            Attempt::new(true, Duration::from_secs(5), Some(10), Utc::now()).with_hints_used(2),
+        );
+        progress.record(
+            "unassisted".to_string(),
+            Attempt::new(true, Duration::from_secs(20), Some(30), Utc::now()),
+        );
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        assert!(!loaded.get_challenge_stats("hinted").unwrap().is_solved_without_hints());
+        let stats = loaded.get_challenge_stats("unassisted").unwrap();
+        assert_eq!(stats.unassisted_best_time(), Some(Duration::from_secs(20)));
+        assert_eq!(stats.unassisted_best_keystrokes(), Some(30));
+    }
+
+    #[test]
+    fn test_save_and_load_reviews() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let due = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let mut progress = Progress::new();
+        progress.set_review_state("test-1".to_string(), ReviewState::new(2.36, 6, 2, due));
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        assert_eq!(loaded.review_state("test-1"), Some(&ReviewState::new(2.36, 6, 2, due)));
+    }
+
+    #[test]
+    fn test_save_and_load_gauntlets() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let finished_at = DateTime::parse_from_rfc3339("2025-03-14T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let result = GauntletResult::new(
+            "random/2",
+            "Random ×2",
+            finished_at,
+            Duration::from_millis(21_500),
+            2,
+            vec![
+                GauntletSplit::new("test-1", Duration::from_millis(9_250), Some(12), true),
+                GauntletSplit::new("test-2", Duration::from_millis(11_000), None, true),
+            ],
+        );
+        let mut progress = Progress::new();
+        progress.record_gauntlet(result.clone());
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        assert_eq!(loaded.gauntlets(), [result]);
+    }
+
+    #[test]
+    fn test_save_and_load_daily_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        let repo = JsonProgressRepository::with_path(file_path);
+
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let result = DailyResult::new(date, "test-1", true, Duration::from_millis(8_500), Some(9), Score::new(812));
+        let mut progress = Progress::new();
+        progress.record_daily(result.clone());
+
+        repo.save(&progress).unwrap();
+        let loaded = repo.load().unwrap();
+
+        assert_eq!(loaded.daily_result(date), Some(&result));
+    }
+
+    #[test]
+    fn test_load_legacy_second_times() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("progress.json");
+        fs::write(
+            &file_path,
+            r#"{
+                "editor_preference": "Helix",
+                "total_practice_time_secs": 30,
+                "last_practice_date": null,
+                "longest_streak": 1,
+                "challenges": {
+                    "test-1": {
+                        "completed": true,
+                        "best_time_secs": 12,
+                        "best_keystrokes": 20,
+                        "first_completed_at": null,
+                        "last_attempted_at": null,
+                        "attempt_count": 1
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let loaded = JsonProgressRepository::with_path(file_path).load().unwrap();
+
+        let stats = loaded.get_challenge_stats("test-1").unwrap();
+        assert_eq!(stats.best_time(), Some(Duration::from_secs(12)));
+        assert!(stats.is_solved_without_hints());
+        assert_eq!(loaded.total_practice_time(), Duration::from_secs(30));
+    }
+
     #[test]
     fn test_load_nonexistent_returns_empty() {
         let temp_dir = TempDir::new().unwrap();
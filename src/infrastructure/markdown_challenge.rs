@@ -0,0 +1,205 @@
+use std::path::Path;
+
+use serde_yaml::{Mapping, Value};
+
+use crate::infrastructure::challenge_loader::LoadFailure;
+
+/// Front matter delimiter, on a line of its own before and after the YAML
+const FRONT_MATTER_FENCE: &str = "---";
+
+/// Code block contents of one file, keyed by its path (none for a
+/// single-buffer challenge)
+struct BlockPair {
+    path: Option<String>,
+    starting: Option<String>,
+    target: Option<String>,
+}
+
+/// Turns a Markdown challenge into a document shaped like a TOML challenge
+///
+/// The YAML front matter holds the `metadata` and `hints` tables. Fenced
+/// code blocks whose info string contains `starting` or `target` supply
+/// the text, optionally followed by a file path for multi-file challenges:
+///
+/// ````markdown
+/// ```rust starting src/lib.rs
+/// fn total() {}
+/// ```
+/// ````
+///
+/// Other prose and code blocks are ignored.
+pub(crate) fn to_document(path: &Path, content: &str) -> Result<Value, LoadFailure> {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.first().map(|line| line.trim_end()) != Some(FRONT_MATTER_FENCE) {
+        return Err(LoadFailure::new(
+            path,
+            Some(1),
+            "Markdown challenges start with YAML front matter between --- lines",
+        ));
+    }
+    let end = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.trim_end() == FRONT_MATTER_FENCE)
+        .map(|index| index + 1)
+        .ok_or_else(|| LoadFailure::new(path, Some(1), "Front matter has no closing --- line"))?;
+
+    let front_matter = lines[1..end].join("\n");
+    let mut document = match serde_yaml::from_str::<Value>(&front_matter) {
+        Ok(Value::Mapping(mapping)) => mapping,
+        Ok(Value::Null) => Mapping::new(),
+        Ok(_) => return Err(LoadFailure::new(path, Some(2), "Front matter must be a YAML mapping")),
+        // The opening --- line comes before the front matter
+        Err(e) => {
+            let line = e.location().map(|location| location.line() + 1);
+            return Err(LoadFailure::new(path, line, yaml_message(&e)));
+        }
+    };
+
+    let pairs = code_blocks(path, &lines, end + 1)?;
+    match pairs.as_slice() {
+        [] => {}
+        [BlockPair { path: None, .. }] => {
+            let pair = &pairs[0];
+            document.insert("content".into(), pair_value(path, pair)?);
+        }
+        _ => {
+            let files = pairs
+                .iter()
+                .map(|pair| {
+                    if pair.path.is_none() {
+                        return Err(LoadFailure::new(
+                            path,
+                            None,
+                            "Multi-file challenges need a file path after every `starting` and `target`",
+                        ));
+                    }
+                    pair_value(path, pair)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            document.insert("files".into(), Value::Sequence(files));
+        }
+    }
+    Ok(Value::Mapping(document))
+}
+
+/// Collects the `starting` and `target` blocks after the front matter,
+/// grouped by file path in order of appearance
+fn code_blocks(path: &Path, lines: &[&str], first: usize) -> Result<Vec<BlockPair>, LoadFailure> {
+    let mut pairs: Vec<BlockPair> = Vec::new();
+    let mut index = first;
+    while index < lines.len() {
+        let Some((fence, info)) = opening_fence(lines[index]) else {
+            index += 1;
+            continue;
+        };
+        let opening = index;
+        let closing = lines[opening + 1..]
+            .iter()
+            .position(|line| is_closing_fence(line, &fence))
+            .map(|offset| opening + 1 + offset)
+            .ok_or_else(|| LoadFailure::new(path, Some(opening + 1), "Code block is never closed"))?;
+        index = closing + 1;
+
+        let words: Vec<&str> = info.split_whitespace().collect();
+        let Some(role) = words.iter().position(|word| matches!(*word, "starting" | "target")) else {
+            continue;
+        };
+        let file = words.get(role + 1).map(|file| file.to_string());
+        let text = lines[opening + 1..closing].join("\n");
+
+        let pair = match pairs.iter_mut().position(|pair| pair.path == file) {
+            Some(existing) => &mut pairs[existing],
+            None => {
+                pairs.push(BlockPair { path: file, starting: None, target: None });
+                pairs.last_mut().expect("pair was just added")
+            }
+        };
+        let slot = if words[role] == "starting" { &mut pair.starting } else { &mut pair.target };
+        if slot.is_some() {
+            return Err(LoadFailure::new(
+                path,
+                Some(opening + 1),
+                format!("Second `{}` code block for the same file", words[role]),
+            ));
+        }
+        *slot = Some(text);
+    }
+    Ok(pairs)
+}
+
+/// The backtick or tilde run opening a fenced code block, and its info string
+fn opening_fence(line: &str) -> Option<(String, &str)> {
+    let trimmed = line.trim_start();
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.chars().take_while(|c| *c == marker).count();
+    (length >= 3).then(|| (marker.to_string().repeat(length), &trimmed[length..]))
+}
+
+/// Whether a line closes the block opened by `fence`: at least as many of the
+/// same fence characters and nothing else
+fn is_closing_fence(line: &str, fence: &str) -> bool {
+    let trimmed = line.trim();
+    let marker = fence.chars().next().unwrap_or('`');
+    trimmed.len() >= fence.len() && trimmed.chars().all(|c| c == marker)
+}
+
+/// The `[content]` table or a `[[files]]` entry for a pair of blocks
+fn pair_value(path: &Path, pair: &BlockPair) -> Result<Value, LoadFailure> {
+    let missing = |role: &str| {
+        let file = pair.path.as_deref().map(|file| format!(" for {}", file)).unwrap_or_default();
+        LoadFailure::new(path, None, format!("Missing `{}` code block{}", role, file))
+    };
+    let mut table = Mapping::new();
+    if let Some(file) = &pair.path {
+        table.insert("path".into(), file.as_str().into());
+    }
+    table.insert("starting".into(), pair.starting.as_deref().ok_or_else(|| missing("starting"))?.into());
+    table.insert("target".into(), pair.target.as_deref().ok_or_else(|| missing("target"))?.into());
+    Ok(Value::Mapping(table))
+}
+
+/// YAML error message without the location, which is reported separately
+pub(crate) fn yaml_message(error: &serde_yaml::Error) -> String {
+    let message = error.to_string();
+    match message.split_once(" at line ") {
+        Some((message, _)) => message.to_string(),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(content: &str) -> Result<Value, LoadFailure> {
+        to_document(Path::new("01-rename.md"), content)
+    }
+
+    #[test]
+    fn test_multi_file_blocks() {
+        let value = document(
+            "---\nmetadata:\n  id: rename-01\n---\n\n```rust starting src/lib.rs\nfn a() {}\n```\n\n```rust starting src/main.rs\na();\n```\n\n```rust target src/lib.rs\nfn b() {}\n```\n\n```rust target src/main.rs\nb();\n```\n",
+        )
+        .unwrap();
+
+        let files = value["files"].as_sequence().unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1]["path"].as_str(), Some("src/main.rs"));
+        assert_eq!(files[1]["target"].as_str(), Some("b();"));
+    }
+
+    #[test]
+    fn test_errors_point_at_lines() {
+        assert_eq!(document("# Rename\n").unwrap_err().line(), Some(1));
+
+        let yaml_error = document("---\nmetadata:\n  id: [rename\n---\n").unwrap_err();
+        assert!(yaml_error.line().is_some_and(|line| line >= 3));
+
+        let unclosed = document("---\nmetadata: {}\n---\n\n```text starting\nabc\n").unwrap_err();
+        assert_eq!(unclosed.line(), Some(5));
+
+        let missing = document("---\nmetadata: {}\n---\n```text starting\nabc\n```\n").unwrap_err();
+        assert!(missing.message().contains("`target`"));
+    }
+}
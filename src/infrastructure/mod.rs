@@ -1,14 +1,34 @@
 pub mod challenge_loader;
+pub mod markdown_challenge;
+pub mod template_challenge_loader;
+pub mod builtin_challenge_loader;
+pub mod challenge_writer;
+pub mod challenge_linter;
+pub mod challenge_library;
+pub mod pack_store;
+pub mod pack_challenge_loader;
+pub mod data_dir;
+pub mod git_history;
 pub mod editor;
 pub mod filesystem;
 pub mod watcher;
 pub mod cast_parser;
 pub mod recorder;
 pub mod json_progress_repository;
+pub mod pty_session;
 
-pub use challenge_loader::{ChallengeLoader, TomlChallengeLoader};
-pub use editor::HelixEditor;
+pub use challenge_loader::{ChallengeLoader, FileChallengeLoader};
+pub use template_challenge_loader::TemplateChallengeLoader;
+pub use builtin_challenge_loader::BuiltinChallengeLoader;
+pub use challenge_writer::TomlChallengeWriter;
+pub use challenge_linter::ChallengeLinter;
+pub use challenge_library::ChallengeLibrary;
+pub use pack_store::PackStore;
+pub use pack_challenge_loader::PackChallengeLoader;
+pub use git_history::GitHistory;
+pub use editor::{EditorKind, TerminalEditor};
 pub use filesystem::LocalFileSystem;
 pub use watcher::FileChangeWatcher;
 pub use recorder::{Recorder, AsciinemaRecorder};
+pub use cast_parser::CastParser;
 pub use json_progress_repository::JsonProgressRepository;
@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::domain::pack_manifest::namespaced_id;
+use crate::domain::{LearningPath, PathStage};
+use crate::infrastructure::challenge_loader::{
+    ChallengeLoader, LoadFailure, LoadReport, FileChallengeLoader, PATHS_FILE,
+};
+use crate::infrastructure::pack_store::{read_manifest, PackStore};
+use crate::infrastructure::template_challenge_loader::TemplateChallengeLoader;
+
+/// Loads the challenges, templates and learning paths of every installed
+/// pack
+///
+/// Ids are namespaced by pack (`rust-refactors/rename-01`) so packs never
+/// collide with each other or the bundled challenges in progress.
+pub struct PackChallengeLoader {
+    store: PackStore,
+}
+
+impl PackChallengeLoader {
+    pub fn new(store: PackStore) -> Self {
+        Self { store }
+    }
+
+    fn load_pack(dir: &Path) -> Result<LoadReport> {
+        let manifest = read_manifest(dir)?;
+        let tool_version = env!("CARGO_PKG_VERSION");
+        if !manifest.supports_tool_version(tool_version) {
+            anyhow::bail!(
+                "Pack {} needs editor-dojo {} or newer; this is {}",
+                manifest.name(),
+                manifest.min_tool_version().unwrap_or_default(),
+                tool_version
+            );
+        }
+
+        let mut report = FileChallengeLoader::new(dir).load()?;
+        report.extend(TemplateChallengeLoader::new(dir.join("templates")).load()?);
+        Ok(report.in_pack(manifest.name()))
+    }
+
+    /// Paths of one pack, named after the pack
+    fn load_pack_paths(dir: &Path) -> Result<Vec<LearningPath>> {
+        let manifest = read_manifest(dir)?;
+        let pack = manifest.name();
+        let paths = FileChallengeLoader::new(dir)
+            .load_paths()?
+            .iter()
+            .map(|path| {
+                let stages = path
+                    .stages()
+                    .iter()
+                    .map(|stage| {
+                        let ids = stage.challenge_ids().iter().map(|id| namespaced_id(pack, id)).collect();
+                        PathStage::new(stage.name(), ids)
+                    })
+                    .collect();
+                LearningPath::new(format!("{}: {}", pack, path.name()), stages)
+            })
+            .collect();
+        Ok(paths)
+    }
+
+    /// Paths of every pack; a pack whose paths cannot be read is reported
+    /// as one failure and the other packs keep theirs
+    pub fn load_paths_report(&self) -> Result<(Vec<LearningPath>, Vec<LoadFailure>)> {
+        let mut paths = Vec::new();
+        let mut failures = Vec::new();
+        for dir in self.store.pack_dirs()? {
+            match Self::load_pack_paths(&dir) {
+                Ok(pack_paths) => paths.extend(pack_paths),
+                Err(e) => failures.push(LoadFailure::new(dir.join(PATHS_FILE), None, format!("{:#}", e))),
+            }
+        }
+        Ok((paths, failures))
+    }
+}
+
+impl ChallengeLoader for PackChallengeLoader {
+    /// Loads every installed pack; a pack that cannot be loaded at all is
+    /// reported as one failure
+    fn load(&self) -> Result<LoadReport> {
+        let mut report = LoadReport::new(Vec::new(), Vec::new());
+        for dir in self.store.pack_dirs()? {
+            match Self::load_pack(&dir) {
+                Ok(pack) => report.extend(pack),
+                Err(e) => report.extend(LoadReport::new(
+                    Vec::new(),
+                    vec![LoadFailure::new(dir, None, format!("{:#}", e))],
+                )),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Paths of every pack, named after their pack; fails on the first
+    /// pack whose paths cannot be read
+    fn load_paths(&self) -> Result<Vec<LearningPath>> {
+        let (paths, failures) = self.load_paths_report()?;
+        match failures.first() {
+            Some(failure) => anyhow::bail!("{}", failure),
+            None => Ok(paths),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_pack_ids_are_namespaced() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pack = dir.path().join("packs/rust");
+        fs::create_dir_all(&pack).unwrap();
+        fs::write(pack.join("pack.toml"), "name = \"rust\"\nversion = \"1.0\"\n").unwrap();
+        fs::write(
+            pack.join("02-rename.toml"),
+            r#"
+[metadata]
+id = "rename-02"
+title = "Rename"
+description = "Rename the function"
+requires = ["rename-01"]
+
+[hints]
+generic = "Select and change"
+
+[content]
+starting = "a"
+target = "b"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            pack.join("paths.toml"),
+            "[[paths]]\nname = \"Basics\"\n\n[[paths.stages]]\nname = \"One\"\nchallenges = [\"rename-02\"]\n",
+        )
+        .unwrap();
+
+        let loader = PackChallengeLoader::new(PackStore::new(dir.path().join("packs")));
+        let challenges = loader.load_all().unwrap();
+        assert_eq!(challenges.len(), 1);
+        assert_eq!(challenges[0].id(), "rust/rename-02");
+        assert_eq!(challenges[0].requires(), ["rust/rename-01"]);
+
+        let paths = loader.load_paths().unwrap();
+        assert_eq!(paths[0].name(), "rust: Basics");
+        assert_eq!(paths[0].stages()[0].challenge_ids(), ["rust/rename-02"]);
+    }
+}
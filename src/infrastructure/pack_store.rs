@@ -0,0 +1,333 @@
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+
+use crate::domain::PackManifest;
+use crate::infrastructure::challenge_loader::MANIFEST_FILE;
+use crate::infrastructure::data_dir::data_dir;
+
+/// `pack.toml`: what a pack is and what it needs
+#[derive(Debug, Deserialize)]
+struct TomlManifest {
+    name: String,
+    version: String,
+    #[serde(default)]
+    author: Option<String>,
+    /// Editors the challenges target, e.g. `["helix"]`
+    #[serde(default)]
+    editors: Vec<String>,
+    #[serde(default)]
+    license: Option<String>,
+    /// Oldest editor-dojo version able to load the pack
+    #[serde(default)]
+    min_tool_version: Option<String>,
+}
+
+impl TomlManifest {
+    fn into_domain(self) -> Result<PackManifest> {
+        if !PackManifest::is_valid_name(&self.name) {
+            anyhow::bail!(
+                "Invalid pack name '{}'; use lowercase letters, digits, '-' and '_'",
+                self.name
+            );
+        }
+
+        let mut manifest = PackManifest::new(self.name, self.version).with_editors(self.editors);
+        if let Some(author) = self.author {
+            manifest = manifest.with_author(author);
+        }
+        if let Some(license) = self.license {
+            manifest = manifest.with_license(license);
+        }
+        if let Some(version) = self.min_tool_version {
+            manifest = manifest.with_min_tool_version(version);
+        }
+        Ok(manifest)
+    }
+}
+
+/// Reads the `pack.toml` of a pack directory
+pub fn read_manifest(pack_dir: &Path) -> Result<PackManifest> {
+    let path = pack_dir.join(MANIFEST_FILE);
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read pack manifest: {}", path.display()))?;
+    let manifest: TomlManifest = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse pack manifest: {}", path.display()))?;
+    manifest
+        .into_domain()
+        .with_context(|| format!("Invalid pack manifest: {}", path.display()))
+}
+
+/// Installed challenge packs, one directory per pack under the user data dir
+pub struct PackStore {
+    packs_dir: PathBuf,
+}
+
+impl PackStore {
+    pub fn new(packs_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            packs_dir: packs_dir.into(),
+        }
+    }
+
+    /// Store in `packs/` under the user data dir
+    pub fn open_default() -> Result<Self> {
+        Ok(Self::new(data_dir()?.join("packs")))
+    }
+
+    pub fn packs_dir(&self) -> &Path {
+        &self.packs_dir
+    }
+
+    /// Directory an installed pack lives in
+    pub fn pack_dir(&self, name: &str) -> PathBuf {
+        self.packs_dir.join(name)
+    }
+
+    /// Installs a pack from a directory or a `.tar.gz`/`.tgz` archive with a
+    /// `pack.toml`, replacing any installed version of it
+    pub fn install(&self, source: &Path) -> Result<PackManifest> {
+        if source.is_dir() {
+            return self.install_dir(source);
+        }
+
+        let file = File::open(source)
+            .with_context(|| format!("Failed to open pack: {}", source.display()))?;
+        let unpacked = tempfile::TempDir::new().context("Failed to create temporary directory")?;
+        tar::Archive::new(GzDecoder::new(file))
+            .unpack(unpacked.path())
+            .with_context(|| format!("Failed to unpack {}; is it a .tar.gz archive?", source.display()))?;
+
+        // Archives usually wrap the pack in one top-level directory
+        let root = if unpacked.path().join(MANIFEST_FILE).exists() {
+            unpacked.path().to_path_buf()
+        } else {
+            let entries: Vec<PathBuf> = fs::read_dir(unpacked.path())?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .collect();
+            match entries.as_slice() {
+                [dir] if dir.join(MANIFEST_FILE).exists() => dir.clone(),
+                _ => anyhow::bail!("{} has no {}", source.display(), MANIFEST_FILE),
+            }
+        };
+        self.install_dir(&root)
+    }
+
+    fn install_dir(&self, source: &Path) -> Result<PackManifest> {
+        let manifest = read_manifest(source)?;
+        let tool_version = env!("CARGO_PKG_VERSION");
+        if !manifest.supports_tool_version(tool_version) {
+            anyhow::bail!(
+                "Pack {} needs editor-dojo {} or newer; this is {}",
+                manifest.name(),
+                manifest.min_tool_version().unwrap_or_default(),
+                tool_version
+            );
+        }
+
+        // Copy next to the installed packs first, so a failed copy leaves any
+        // installed version untouched and the swap is a rename
+        fs::create_dir_all(&self.packs_dir)
+            .with_context(|| format!("Failed to create packs directory: {}", self.packs_dir.display()))?;
+        let staging = tempfile::Builder::new()
+            .prefix(".installing-")
+            .tempdir_in(&self.packs_dir)
+            .context("Failed to create temporary directory")?;
+        let staged = staging.path().join(manifest.name());
+        copy_dir(source, &staged)?;
+
+        let target = self.pack_dir(manifest.name());
+        let old = staging.path().join(".old");
+        if target.exists() {
+            fs::rename(&target, &old)
+                .with_context(|| format!("Failed to move old version of pack: {}", target.display()))?;
+        }
+        if let Err(e) = fs::rename(&staged, &target) {
+            if old.exists() {
+                let _ = fs::rename(&old, &target);
+            }
+            return Err(e).with_context(|| format!("Failed to install pack: {}", target.display()));
+        }
+        // Dropping the staging directory removes the old version
+        Ok(manifest)
+    }
+
+    /// Manifests of the installed packs, by name
+    pub fn list(&self) -> Result<Vec<PackManifest>> {
+        Ok(self.pack_dirs()?.iter().filter_map(|dir| read_manifest(dir).ok()).collect())
+    }
+
+    /// Directories of the installed packs, by name
+    pub fn pack_dirs(&self) -> Result<Vec<PathBuf>> {
+        if !self.packs_dir.exists() {
+            return Ok(Vec::new());
+        }
+        let entries = fs::read_dir(&self.packs_dir)
+            .with_context(|| format!("Failed to read packs directory: {}", self.packs_dir.display()))?;
+        let mut dirs: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            // Hidden directories are installs in progress
+            .filter(|path| !path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.')))
+            .filter(|path| path.join(MANIFEST_FILE).is_file())
+            .collect();
+        dirs.sort();
+        Ok(dirs)
+    }
+
+    /// Uninstalls a pack, returning its manifest
+    pub fn remove(&self, name: &str) -> Result<PackManifest> {
+        let dir = self.pack_dir(name);
+        if !PackManifest::is_valid_name(name) || !dir.join(MANIFEST_FILE).exists() {
+            anyhow::bail!("No installed pack named '{}'", name);
+        }
+        let manifest = read_manifest(&dir)?;
+        fs::remove_dir_all(&dir)
+            .with_context(|| format!("Failed to remove pack: {}", dir.display()))?;
+        Ok(manifest)
+    }
+}
+
+/// Copies a directory tree, skipping anything that is not a file or directory
+///
+/// Symlinks are skipped rather than followed, so a pack can't pull in files
+/// from outside its own directory.
+fn copy_dir(source: &Path, target: &Path) -> Result<()> {
+    fs::create_dir_all(target)
+        .with_context(|| format!("Failed to create directory: {}", target.display()))?;
+    for entry in fs::read_dir(source)
+        .with_context(|| format!("Failed to read directory: {}", source.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        let destination = target.join(entry.file_name());
+        let file_type = entry
+            .file_type()
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        if file_type.is_dir() {
+            copy_dir(&path, &destination)?;
+        } else if file_type.is_file() {
+            fs::copy(&path, &destination)
+                .with_context(|| format!("Failed to copy {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    const MANIFEST: &str = r#"
+name = "rust-refactors"
+version = "1.2.0"
+author = "Ada"
+editors = ["helix"]
+license = "MIT"
+"#;
+
+    fn write_pack(dir: &Path, manifest: &str) {
+        fs::create_dir_all(dir.join("templates")).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+        fs::write(dir.join("01-rename.toml"), "# challenge").unwrap();
+        fs::write(dir.join("templates/rename.toml"), "# template").unwrap();
+    }
+
+    #[test]
+    fn test_install_list_and_remove_directory_pack() {
+        let dir = tempfile::TempDir::new().unwrap();
+        write_pack(&dir.path().join("source"), MANIFEST);
+        let store = PackStore::new(dir.path().join("packs"));
+
+        let manifest = store.install(&dir.path().join("source")).unwrap();
+        assert_eq!(manifest.name(), "rust-refactors");
+        assert!(store.pack_dir("rust-refactors").join("templates/rename.toml").exists());
+
+        let installed = store.list().unwrap();
+        assert_eq!(installed.len(), 1);
+        assert_eq!(installed[0].author(), Some("Ada"));
+        assert_eq!(installed[0].editors(), ["helix"]);
+
+        assert_eq!(store.remove("rust-refactors").unwrap().version(), "1.2.0");
+        assert!(store.list().unwrap().is_empty());
+        assert!(store.remove("rust-refactors").is_err());
+    }
+
+    #[test]
+    fn test_reinstall_replaces_the_old_version() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source = dir.path().join("source");
+        write_pack(&source, MANIFEST);
+        let store = PackStore::new(dir.path().join("packs"));
+        store.install(&source).unwrap();
+
+        fs::remove_file(source.join("01-rename.toml")).unwrap();
+        fs::write(source.join(MANIFEST_FILE), MANIFEST.replace("1.2.0", "1.3.0")).unwrap();
+        store.install(&source).unwrap();
+
+        let installed = store.list().unwrap();
+        assert_eq!(installed.len(), 1);
+        assert_eq!(installed[0].version(), "1.3.0");
+        assert!(!store.pack_dir("rust-refactors").join("01-rename.toml").exists());
+        // Nothing is left over from staging
+        assert_eq!(fs::read_dir(store.packs_dir()).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_install_skips_symlinks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source = dir.path().join("source");
+        write_pack(&source, MANIFEST);
+        fs::write(dir.path().join("secret"), "outside").unwrap();
+        std::os::unix::fs::symlink(dir.path().join("secret"), source.join("secret.toml")).unwrap();
+        std::os::unix::fs::symlink(dir.path(), source.join("parent")).unwrap();
+
+        let store = PackStore::new(dir.path().join("packs"));
+        store.install(&source).unwrap();
+        let pack = store.pack_dir("rust-refactors");
+        assert!(pack.join("01-rename.toml").exists());
+        assert!(!pack.join("secret.toml").exists());
+        assert!(!pack.join("parent").exists());
+    }
+
+    #[test]
+    fn test_install_archive_with_top_level_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        write_pack(&dir.path().join("rust-refactors-1.2.0"), MANIFEST);
+
+        let archive_path = dir.path().join("pack.tar.gz");
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            File::create(&archive_path).unwrap(),
+            Compression::default(),
+        ));
+        builder
+            .append_dir_all("rust-refactors-1.2.0", dir.path().join("rust-refactors-1.2.0"))
+            .unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let store = PackStore::new(dir.path().join("packs"));
+        store.install(&archive_path).unwrap();
+        assert!(store.pack_dir("rust-refactors").join("01-rename.toml").exists());
+    }
+
+    #[test]
+    fn test_install_rejects_bad_manifests() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store = PackStore::new(dir.path().join("packs"));
+
+        write_pack(&dir.path().join("escape"), &MANIFEST.replace("rust-refactors", "../escape"));
+        assert!(store.install(&dir.path().join("escape")).is_err());
+
+        write_pack(
+            &dir.path().join("future"),
+            &format!("{}min_tool_version = \"999.0\"\n", MANIFEST),
+        );
+        assert!(store.install(&dir.path().join("future")).is_err());
+        assert!(store.list().unwrap().is_empty());
+    }
+}
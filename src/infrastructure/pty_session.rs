@@ -0,0 +1,462 @@
+use std::io::{self, Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use crossterm::{cursor, event, execute, style, terminal};
+#[cfg(not(unix))]
+use portable_pty::ChildKiller;
+use portable_pty::{native_pty_system, CommandBuilder, MasterPty, PtySize};
+
+use crate::application::{EditorSession, LaunchCommand, RunnerEvent};
+
+/// How long to wait after the quit command before sending SIGTERM
+const QUIT_GRACE: Duration = Duration::from_millis(1500);
+
+/// How long to wait after SIGTERM before sending SIGKILL
+const TERM_GRACE: Duration = Duration::from_millis(1000);
+
+/// How often the stdin forwarder checks whether the session has ended
+#[cfg(unix)]
+const STDIN_POLL_MS: i32 = 50;
+
+/// How often the real terminal's size is compared with the PTY's
+const RESIZE_POLL: Duration = Duration::from_millis(100);
+
+/// How long to wait for the editor's last output before restoring the terminal
+const OUTPUT_DRAIN: Duration = Duration::from_millis(500);
+
+/// An editor running inside a pseudo-terminal owned by the dojo
+///
+/// Keyboard input is forwarded from the real terminal to the PTY and editor
+/// output is copied back, so the user sees no difference. Owning the PTY
+/// lets the dojo type the editor's quit command itself when a challenge is
+/// solved instead of killing it mid-frame. Resizes of the real terminal are
+/// passed on to the PTY.
+///
+/// Signals need unix; elsewhere the last resort after the quit command is
+/// terminating the process.
+pub struct PtySession {
+    #[cfg(unix)]
+    pid: Option<u32>,
+    #[cfg(not(unix))]
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    quit_sequence: &'static [u8],
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    // Kept alive so the PTY stays open while the editor runs
+    _master: Arc<Mutex<Box<dyn MasterPty + Send>>>,
+    exit_rx: mpsc::Receiver<()>,
+    exited: Arc<AtomicBool>,
+    // Tells the input and resize threads to finish
+    stop: Arc<AtomicBool>,
+    input_thread: Option<JoinHandle<()>>,
+    resize_thread: Option<JoinHandle<()>>,
+    output_done: mpsc::Receiver<()>,
+    raw_mode: bool,
+    finished: bool,
+}
+
+impl PtySession {
+    /// Spawns `command` in a new PTY sized like the current terminal
+    ///
+    /// `quit_sequence` is typed into the editor by `close`; the session
+    /// sends `RunnerEvent::EditorExited` on `events` when the process exits.
+    pub fn spawn(
+        command: &LaunchCommand,
+        quit_sequence: &'static [u8],
+        events: mpsc::Sender<RunnerEvent>,
+    ) -> Result<Self> {
+        let (cols, rows) = terminal::size().unwrap_or((80, 24));
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(|e| anyhow!("Failed to open pseudo-terminal: {}", e))?;
+
+        let mut builder = CommandBuilder::new(command.program());
+        builder.args(command.args());
+        for (key, value) in command.envs() {
+            builder.env(key, value);
+        }
+        builder.cwd(command.current_dir());
+
+        let mut child = pair
+            .slave
+            .spawn_command(builder)
+            .map_err(|e| anyhow!("Failed to spawn '{}': {}", command.program(), e))?;
+        drop(pair.slave);
+
+        let reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|e| anyhow!("Failed to read from pseudo-terminal: {}", e))?;
+        let writer = pair
+            .master
+            .take_writer()
+            .map_err(|e| anyhow!("Failed to write to pseudo-terminal: {}", e))?;
+        let writer = Arc::new(Mutex::new(writer));
+
+        // The editor expects raw keys; the real terminal just passes them on
+        let raw_mode = terminal::enable_raw_mode().is_ok();
+
+        // Editor output -> real terminal
+        let (output_tx, output_done) = mpsc::channel();
+        thread::Builder::new()
+            .name("pty-output".to_string())
+            .spawn(move || {
+                copy_output(reader);
+                let _ = output_tx.send(());
+            })
+            .context("Failed to start PTY output thread")?;
+
+        // Real terminal -> editor
+        let stop = Arc::new(AtomicBool::new(false));
+        let input_thread = {
+            let writer = Arc::clone(&writer);
+            let stop = Arc::clone(&stop);
+            thread::Builder::new()
+                .name("pty-input".to_string())
+                .spawn(move || forward_input(&writer, &stop))
+                .context("Failed to start PTY input thread")?
+        };
+
+        // Real terminal size -> PTY
+        let master = Arc::new(Mutex::new(pair.master));
+        let resize_thread = {
+            let master = Arc::clone(&master);
+            let stop = Arc::clone(&stop);
+            thread::Builder::new()
+                .name("pty-resize".to_string())
+                .spawn(move || forward_resizes(&master, (cols, rows), &stop))
+                .context("Failed to start PTY resize thread")?
+        };
+
+        // Exit monitor
+        #[cfg(unix)]
+        let pid = child.process_id();
+        #[cfg(not(unix))]
+        let killer = child.clone_killer();
+        let exited = Arc::new(AtomicBool::new(false));
+        let (exit_tx, exit_rx) = mpsc::channel();
+        {
+            let exited = Arc::clone(&exited);
+            thread::Builder::new()
+                .name("pty-waiter".to_string())
+                .spawn(move || {
+                    let _ = child.wait();
+                    exited.store(true, Ordering::SeqCst);
+                    let _ = exit_tx.send(());
+                    let _ = events.send(RunnerEvent::EditorExited);
+                })
+                .context("Failed to start editor exit monitor")?;
+        }
+
+        Ok(Self {
+            #[cfg(unix)]
+            pid,
+            #[cfg(not(unix))]
+            killer,
+            quit_sequence,
+            writer,
+            _master: master,
+            exit_rx,
+            exited,
+            stop,
+            input_thread: Some(input_thread),
+            resize_thread: Some(resize_thread),
+            output_done,
+            raw_mode,
+            finished: false,
+        })
+    }
+
+    fn has_exited(&self) -> bool {
+        self.exited.load(Ordering::SeqCst)
+    }
+
+    /// Waits up to `timeout` for the editor to exit
+    fn wait_for_exit(&self, timeout: Duration) -> bool {
+        self.has_exited() || self.exit_rx.recv_timeout(timeout).is_ok()
+    }
+
+    fn send_quit_command(&self) -> Result<()> {
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| anyhow!("PTY writer lock poisoned"))?;
+        writer.write_all(self.quit_sequence)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Stops an editor that ignored its quit command: SIGTERM, then SIGKILL
+    #[cfg(unix)]
+    fn terminate(&mut self) {
+        self.signal(libc::SIGTERM);
+        if !self.wait_for_exit(TERM_GRACE) {
+            self.signal(libc::SIGKILL);
+            self.wait_for_exit(TERM_GRACE);
+        }
+    }
+
+    /// Stops an editor that ignored its quit command
+    #[cfg(not(unix))]
+    fn terminate(&mut self) {
+        if !self.has_exited() {
+            let _ = self.killer.kill();
+        }
+        self.wait_for_exit(TERM_GRACE);
+    }
+
+    #[cfg(unix)]
+    fn signal(&self, signal: libc::c_int) {
+        if let Some(pid) = self.pid {
+            if !self.has_exited() {
+                // SAFETY: kill(2) has no memory-safety preconditions
+                unsafe {
+                    libc::kill(pid as libc::pid_t, signal);
+                }
+            }
+        }
+    }
+
+    /// Stops forwarding input and puts the real terminal back in order
+    fn finish(&mut self) -> Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(input_thread) = self.input_thread.take() {
+            // Without poll(2) the thread sits in a blocking read until the
+            // next key, so it is left to finish on its own
+            if cfg!(unix) {
+                let _ = input_thread.join();
+            }
+        }
+        if let Some(resize_thread) = self.resize_thread.take() {
+            let _ = resize_thread.join();
+        }
+
+        // Let the editor's final output (e.g. leaving its screen) through first
+        let _ = self.output_done.recv_timeout(OUTPUT_DRAIN);
+
+        if self.raw_mode {
+            restore_terminal()?;
+        }
+        Ok(())
+    }
+}
+
+impl EditorSession for PtySession {
+    fn close(&mut self) -> Result<()> {
+        // 1. Ask the editor to quit the way a user would
+        if !self.has_exited() {
+            let _ = self.send_quit_command();
+        }
+        // 2. SIGTERM, 3. SIGKILL
+        if !self.wait_for_exit(QUIT_GRACE) {
+            self.terminate();
+        }
+        self.finish()
+    }
+
+    fn wait(&mut self) -> Result<()> {
+        if !self.has_exited() {
+            let _ = self.exit_rx.recv();
+        }
+        self.finish()
+    }
+}
+
+impl Drop for PtySession {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.close();
+        }
+    }
+}
+
+/// Copies PTY output to stdout until the PTY closes
+fn copy_output(mut reader: Box<dyn Read + Send>) {
+    let mut stdout = io::stdout();
+    let mut buf = [0u8; 4096];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                if stdout.write_all(&buf[..n]).is_err() {
+                    break;
+                }
+                let _ = stdout.flush();
+            }
+        }
+    }
+}
+
+/// Forwards stdin to the PTY until `stop` is set
+///
+/// Reads the file descriptor directly (no std buffering) and only after
+/// `poll` reports input, so nothing typed after the session ends is consumed
+/// here instead of by the next screen.
+#[cfg(unix)]
+fn forward_input(writer: &Mutex<Box<dyn Write + Send>>, stop: &AtomicBool) {
+    let mut buf = [0u8; 1024];
+    while !stop.load(Ordering::SeqCst) {
+        let mut fds = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `fds` is a valid pollfd for the duration of the call
+        let ready = unsafe { libc::poll(&mut fds, 1, STDIN_POLL_MS) };
+        if ready < 0 {
+            if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            break;
+        }
+        if ready == 0 || stop.load(Ordering::SeqCst) {
+            continue;
+        }
+
+        // SAFETY: `buf` is valid for writes of `buf.len()` bytes
+        let n = unsafe { libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len()) };
+        if n <= 0 {
+            break;
+        }
+
+        let Ok(mut writer) = writer.lock() else {
+            break;
+        };
+        if writer.write_all(&buf[..n as usize]).is_err() {
+            break;
+        }
+        let _ = writer.flush();
+    }
+}
+
+/// Forwards stdin to the PTY until `stop` is set
+///
+/// Without `poll` the read blocks, so the first key typed after the session
+/// ends is still consumed here.
+#[cfg(not(unix))]
+fn forward_input(writer: &Mutex<Box<dyn Write + Send>>, stop: &AtomicBool) {
+    let mut stdin = io::stdin();
+    let mut buf = [0u8; 1024];
+    while !stop.load(Ordering::SeqCst) {
+        let n = match stdin.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let Ok(mut writer) = writer.lock() else {
+            break;
+        };
+        if writer.write_all(&buf[..n]).is_err() {
+            break;
+        }
+        let _ = writer.flush();
+    }
+}
+
+/// Resizes the PTY whenever the real terminal changes size, until `stop` is set
+///
+/// Polling the size works the same on every platform, unlike SIGWINCH.
+fn forward_resizes(master: &Mutex<Box<dyn MasterPty + Send>>, initial: (u16, u16), stop: &AtomicBool) {
+    let mut current = initial;
+    while !stop.load(Ordering::SeqCst) {
+        thread::sleep(RESIZE_POLL);
+        let Ok(size) = terminal::size() else {
+            continue;
+        };
+        if size == current {
+            continue;
+        }
+
+        let Ok(master) = master.lock() else {
+            break;
+        };
+        let (cols, rows) = size;
+        if master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .is_ok()
+        {
+            current = size;
+        }
+    }
+}
+
+/// Undoes anything a killed editor may have left behind
+fn restore_terminal() -> Result<()> {
+    let _ = terminal::disable_raw_mode();
+    execute!(
+        io::stdout(),
+        terminal::LeaveAlternateScreen,
+        event::DisableMouseCapture,
+        event::DisableBracketedPaste,
+        style::ResetColor,
+        cursor::Show
+    )
+    .context("Failed to restore terminal")
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use std::time::Instant;
+
+    fn command(program: &str, args: &[&str]) -> LaunchCommand {
+        args.iter()
+            .fold(LaunchCommand::new(program, Path::new("/")), |cmd, arg| {
+                cmd.arg(*arg)
+            })
+    }
+
+    #[test]
+    fn test_close_with_quit_command() {
+        // `cat` in a PTY exits on Ctrl-D at the start of a line
+        let (tx, rx) = mpsc::channel();
+        let mut session = PtySession::spawn(&command("cat", &[]), b"\x04", tx).unwrap();
+
+        let start = Instant::now();
+        session.close().unwrap();
+
+        assert!(start.elapsed() < QUIT_GRACE);
+        assert_eq!(rx.recv().unwrap(), RunnerEvent::EditorExited);
+    }
+
+    #[test]
+    fn test_close_escalates_to_signals() {
+        let (tx, rx) = mpsc::channel();
+        let mut session = PtySession::spawn(&command("sleep", &["30"]), b"q", tx).unwrap();
+
+        session.close().unwrap();
+
+        assert_eq!(rx.recv().unwrap(), RunnerEvent::EditorExited);
+    }
+
+    #[test]
+    fn test_wait_for_editor_exit() {
+        let (tx, rx) = mpsc::channel();
+        let mut session = PtySession::spawn(&command("true", &[]), b"", tx).unwrap();
+
+        assert_eq!(rx.recv().unwrap(), RunnerEvent::EditorExited);
+        session.wait().unwrap();
+    }
+}
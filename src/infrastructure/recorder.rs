@@ -1,21 +1,29 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
-use std::process::{Child, Command};
+use std::process::Command;
+use std::time::Duration;
 
+use crate::application::LaunchCommand;
+use crate::domain::pack_manifest::PACK_SEPARATOR;
 use crate::domain::{KeySequence, Recording};
 use super::cast_parser::CastParser;
 
 /// Trait for recording challenge attempts.
 pub trait Recorder {
-    /// Starts recording and spawns the editor with the given file.
+    /// Picks a fresh path for recording an attempt at a challenge.
+    fn recording_path(&self, challenge_id: &str) -> Result<PathBuf>;
+
+    /// Wraps the editor command so that running it records the session.
     ///
-    /// Returns a handle to the spawned process.
-    fn start_recording(&mut self, file_path: &Path, output_path: &Path) -> Result<Child>;
+    /// The returned command is spawned by the `EditorSpawner` in place of
+    /// the editor; its working directory is unchanged.
+    fn wrap_command(&self, command: &LaunchCommand, output_path: &Path) -> LaunchCommand;
 
-    /// Stops the recording and parses the output to create a Recording.
+    /// Parses the recording output to create a Recording.
     ///
-    /// This should be called after the editor process has exited.
-    fn finalize_recording(&self, output_path: &Path) -> Result<Recording>;
+    /// This should be called after the editor process has exited. Input
+    /// recorded after `cutoff` (e.g. keys sent to close the editor) is ignored.
+    fn finalize_recording(&self, output_path: &Path, cutoff: Option<Duration>) -> Result<Recording>;
 
     /// Checks if asciinema is installed and available.
     ///
@@ -24,18 +32,30 @@ pub trait Recorder {
 }
 
 /// Implementation of Recorder using asciinema.
-pub struct AsciinemaRecorder {
-    editor_command: String,
-}
+#[derive(Default)]
+pub struct AsciinemaRecorder;
 
 impl AsciinemaRecorder {
-    /// Creates a new AsciinemaRecorder with the specified editor command.
+    /// Creates a new AsciinemaRecorder.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Builds the shell command line passed to `asciinema rec -c`.
     ///
-    /// The editor_command should be the base command (e.g., "hx" for Helix).
-    pub fn new(editor_command: impl Into<String>) -> Self {
-        Self {
-            editor_command: editor_command.into(),
+    /// Environment overrides are applied through `env` so that only the
+    /// editor sees them, not asciinema itself.
+    fn shell_command(command: &LaunchCommand) -> String {
+        let mut parts = Vec::new();
+        if !command.envs().is_empty() {
+            parts.push("env".to_string());
+            for (key, value) in command.envs() {
+                parts.push(shell_quote(&format!("{}={}", key, value)));
+            }
         }
+        parts.push(shell_quote(command.program()));
+        parts.extend(command.args().iter().map(|arg| shell_quote(arg)));
+        parts.join(" ")
     }
 
     /// Ensures the recordings directory exists.
@@ -65,31 +85,36 @@ impl AsciinemaRecorder {
             .context("Failed to get system time")?
             .as_secs();
 
-        let filename = format!("challenge-{}-{}.cast", challenge_id, timestamp);
+        // Pack challenge ids contain the pack separator
+        let filename = format!(
+            "challenge-{}-{}.cast",
+            challenge_id.replace(PACK_SEPARATOR, "_"),
+            timestamp
+        );
         Ok(recordings_dir.join(filename))
     }
 }
 
 impl Recorder for AsciinemaRecorder {
-    fn start_recording(&mut self, file_path: &Path, output_path: &Path) -> Result<Child> {
-        // Build the command to record: asciinema rec --overwrite <output> -c "hx <file>"
-        let editor_command = format!("{} {}", self.editor_command, file_path.display());
+    fn recording_path(&self, challenge_id: &str) -> Result<PathBuf> {
+        Self::generate_recording_path(challenge_id)
+    }
 
-        let child = Command::new("asciinema")
+    fn wrap_command(&self, command: &LaunchCommand, output_path: &Path) -> LaunchCommand {
+        // asciinema rec --stdin --overwrite <output> -c "<editor> <files>"
+        // --stdin captures input events, which is where keystrokes come from
+        LaunchCommand::new("asciinema", command.current_dir())
             .arg("rec")
+            .arg("--stdin")
             .arg("--overwrite")
-            .arg(output_path)
+            .arg(output_path.display().to_string())
             .arg("-c")
-            .arg(editor_command)
-            .spawn()
-            .context("Failed to start asciinema recording")?;
-
-        Ok(child)
+            .arg(Self::shell_command(command))
     }
 
-    fn finalize_recording(&self, output_path: &Path) -> Result<Recording> {
+    fn finalize_recording(&self, output_path: &Path, cutoff: Option<Duration>) -> Result<Recording> {
         // Parse the .cast file to extract keystrokes
-        let key_sequence = CastParser::parse(output_path)
+        let key_sequence = CastParser::parse_until(output_path, cutoff)
             .unwrap_or_else(|e| {
                 eprintln!("Warning: Failed to parse recording: {}", e);
                 KeySequence::empty()
@@ -106,17 +131,27 @@ impl Recorder for AsciinemaRecorder {
     }
 }
 
+/// Quotes an argument for the shell command line passed to `asciinema -c`.
+fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
 /// A no-op recorder that doesn't actually record anything.
 ///
 /// Used when asciinema is not available or recording is disabled.
 pub struct NoOpRecorder;
 
 impl Recorder for NoOpRecorder {
-    fn start_recording(&mut self, _file_path: &Path, _output_path: &Path) -> Result<Child> {
-        anyhow::bail!("NoOpRecorder cannot start recording")
+    fn recording_path(&self, _challenge_id: &str) -> Result<PathBuf> {
+        // Nothing is ever written there
+        Ok(PathBuf::new())
     }
 
-    fn finalize_recording(&self, _output_path: &Path) -> Result<Recording> {
+    fn wrap_command(&self, command: &LaunchCommand, _output_path: &Path) -> LaunchCommand {
+        command.clone()
+    }
+
+    fn finalize_recording(&self, _output_path: &Path, _cutoff: Option<Duration>) -> Result<Recording> {
         anyhow::bail!("NoOpRecorder cannot finalize recording")
     }
 
@@ -130,9 +165,31 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_asciinema_recorder_creation() {
-        let recorder = AsciinemaRecorder::new("hx");
-        assert_eq!(recorder.editor_command, "hx");
+    fn test_shell_command() {
+        let command = LaunchCommand::new("vim", Path::new("/tmp/dojo"))
+            .arg("--clean")
+            .arg("a b.txt")
+            .env("HOME", "/tmp/home");
+        assert_eq!(
+            AsciinemaRecorder::shell_command(&command),
+            "env 'HOME=/tmp/home' 'vim' '--clean' 'a b.txt'"
+        );
+
+        let bare = LaunchCommand::new("hx", Path::new("/tmp/dojo")).arg("a.txt");
+        assert_eq!(AsciinemaRecorder::shell_command(&bare), "'hx' 'a.txt'");
+    }
+
+    #[test]
+    fn test_wrap_command() {
+        let command = LaunchCommand::new("hx", Path::new("/tmp/dojo")).arg("a.txt");
+        let wrapped = AsciinemaRecorder::new().wrap_command(&command, Path::new("/tmp/out.cast"));
+
+        assert_eq!(wrapped.program(), "asciinema");
+        assert_eq!(
+            wrapped.args(),
+            ["rec", "--stdin", "--overwrite", "/tmp/out.cast", "-c", "'hx' 'a.txt'"]
+        );
+        assert_eq!(wrapped.current_dir(), Path::new("/tmp/dojo"));
     }
 
     #[test]
@@ -147,6 +204,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("/tmp/dojo/src/lib.rs"), "'/tmp/dojo/src/lib.rs'");
+        assert_eq!(shell_quote("it's.txt"), "'it'\\''s.txt'");
+    }
+
     #[test]
     fn test_is_available() {
         // This will depend on whether asciinema is actually installed
@@ -0,0 +1,256 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::application::ChallengeGenerator;
+use crate::domain::{Challenge, ChallengeTemplate, IdentifierCase, ParamKind, TemplateParam};
+use crate::infrastructure::challenge_loader::{
+    load_file, parse_file, ChallengeLoader, LoadFailure, LoadReport, TomlChallenge,
+};
+
+/// Template file structure: a regular challenge whose text holds
+/// `{{name}}` placeholders, plus one `[[params]]` entry per placeholder
+#[derive(Debug, Deserialize)]
+struct TomlTemplate {
+    #[serde(flatten)]
+    challenge: TomlChallenge,
+    #[serde(default)]
+    params: Vec<TomlParam>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlParam {
+    name: String,
+    /// identifier, number, list or lorem
+    kind: String,
+    /// Identifier case: snake (default), camel or pascal
+    #[serde(default)]
+    case: Option<String>,
+    #[serde(default)]
+    min: Option<i64>,
+    #[serde(default)]
+    max: Option<i64>,
+    /// List item separator (default: ", ")
+    #[serde(default)]
+    separator: Option<String>,
+}
+
+impl TomlParam {
+    fn into_domain(self) -> Result<TemplateParam> {
+        let range = |default_min: i64, default_max: i64| -> Result<(i64, i64)> {
+            let min = self.min.unwrap_or(default_min);
+            let max = self.max.unwrap_or(default_max.max(min));
+            if min > max {
+                anyhow::bail!("Parameter '{}' has min {} above max {}", self.name, min, max);
+            }
+            Ok((min, max))
+        };
+        let count = |default_min: i64, default_max: i64| -> Result<(usize, usize)> {
+            let (min, max) = range(default_min, default_max)?;
+            if min < 0 {
+                anyhow::bail!("Parameter '{}' needs a count of at least 0, got {}", self.name, min);
+            }
+            Ok((min as usize, max as usize))
+        };
+
+        let kind = match self.kind.to_lowercase().as_str() {
+            "identifier" => {
+                let case = match &self.case {
+                    Some(name) => IdentifierCase::from_name(name).with_context(|| {
+                        format!("Unknown case '{}'; use snake, camel or pascal", name)
+                    })?,
+                    None => IdentifierCase::Snake,
+                };
+                ParamKind::Identifier { case }
+            }
+            "number" => {
+                let (min, max) = range(1, 99)?;
+                ParamKind::Number { min, max }
+            }
+            "list" => {
+                let (min, max) = count(3, 6)?;
+                ParamKind::List {
+                    min,
+                    max,
+                    separator: self.separator.clone().unwrap_or_else(|| ", ".to_string()),
+                }
+            }
+            "lorem" => {
+                let (min, max) = count(2, 5)?;
+                ParamKind::Lorem { min, max }
+            }
+            other => anyhow::bail!(
+                "Unknown kind '{}' for parameter '{}'; use identifier, number, list or lorem",
+                other,
+                self.name
+            ),
+        };
+        Ok(TemplateParam::new(self.name, kind))
+    }
+}
+
+impl TomlTemplate {
+    fn into_domain(self) -> Result<Challenge> {
+        let challenge = self.challenge.into_domain()?;
+        let params = self
+            .params
+            .into_iter()
+            .map(TomlParam::into_domain)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut template = ChallengeTemplate::new(params, challenge.files().to_vec());
+        if let (Some(solution), Some(keystrokes)) =
+            (challenge.optimal_solution(), challenge.optimal_keystrokes())
+        {
+            template = template.with_optimal_solution(solution, keystrokes);
+        }
+
+        let unknown = template.unknown_placeholders();
+        if !unknown.is_empty() {
+            anyhow::bail!("No [[params]] entry for placeholder(s): {}", unknown.join(", "));
+        }
+
+        // Start from a fixed rendering so list previews are stable
+        Ok(ChallengeGenerator::generate(&challenge.with_template(template), 0))
+    }
+}
+
+/// Parses the content of a template file; see `parse_file`
+pub(crate) fn parse_template(
+    path: &Path,
+    content: &str,
+) -> std::result::Result<Challenge, LoadFailure> {
+    parse_file(path, content, TomlTemplate::into_domain)
+}
+
+/// Loads challenge templates from TOML files in a directory
+///
+/// Each template becomes one challenge whose text is generated afresh for
+/// every attempt (see `ChallengeGenerator`).
+pub struct TemplateChallengeLoader {
+    templates_dir: PathBuf,
+}
+
+impl TemplateChallengeLoader {
+    pub fn new(templates_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            templates_dir: templates_dir.into(),
+        }
+    }
+}
+
+impl ChallengeLoader for TemplateChallengeLoader {
+    /// Loads every template; a missing directory just means no templates
+    fn load(&self) -> Result<LoadReport> {
+        if !self.templates_dir.exists() {
+            return Ok(LoadReport::new(Vec::new(), Vec::new()));
+        }
+
+        let entries = fs::read_dir(&self.templates_dir).with_context(|| {
+            format!(
+                "Failed to read templates directory: {}",
+                self.templates_dir.display()
+            )
+        })?;
+
+        let mut template_files = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("toml") {
+                template_files.push(path);
+            }
+        }
+        template_files.sort();
+
+        let (loaded, failed): (Vec<_>, Vec<_>) = template_files
+            .iter()
+            .map(|path| load_file(path, TomlTemplate::into_domain))
+            .partition(|result| result.is_ok());
+        Ok(LoadReport::new(
+            loaded.into_iter().filter_map(|result| result.ok()).collect(),
+            failed.into_iter().filter_map(|result| result.err()).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = r#"
+[metadata]
+id = "template-rename"
+title = "Rename a variable"
+description = "Rename the variable"
+
+[hints]
+generic = "Select the word and change it"
+optimal_solution = "wmiwc{{new}}<esc>"
+optimal_keystrokes = 6
+
+[content]
+starting = "let {{old}} = {{n}};"
+target = "let {{new}} = {{n}};"
+
+[[params]]
+name = "old"
+kind = "identifier"
+
+[[params]]
+name = "new"
+kind = "identifier"
+case = "camel"
+
+[[params]]
+name = "n"
+kind = "number"
+max = 9
+"#;
+
+    fn parse(toml: &str) -> Result<Challenge> {
+        toml::from_str::<TomlTemplate>(toml)?.into_domain()
+    }
+
+    #[test]
+    fn test_parse_template() {
+        let challenge = parse(TEMPLATE).unwrap();
+
+        assert!(challenge.is_generated());
+        assert_eq!(challenge.id(), "template-rename");
+        assert!(!challenge.starting_content().contains("{{"));
+        assert_eq!(challenge.template().unwrap().params().len(), 3);
+        assert_eq!(
+            challenge.template().unwrap().params()[2].kind(),
+            &ParamKind::Number { min: 1, max: 9 }
+        );
+    }
+
+    #[test]
+    fn test_undeclared_placeholder_is_rejected() {
+        assert!(parse(&TEMPLATE.replace("{{n}};\"\ntarget", "{{count}};\"\ntarget")).is_err());
+        assert!(parse(&TEMPLATE.replace("kind = \"number\"", "kind = \"colour\"")).is_err());
+        assert!(parse(&TEMPLATE.replace("max = 9", "min = 5\nmax = 2")).is_err());
+    }
+
+    #[test]
+    fn test_missing_directory_has_no_templates() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let loader = TemplateChallengeLoader::new(dir.path().join("templates"));
+        assert!(loader.load_all().unwrap().is_empty());
+
+        fs::create_dir(dir.path().join("templates")).unwrap();
+        fs::write(dir.path().join("templates/rename.toml"), TEMPLATE).unwrap();
+        assert_eq!(loader.load_all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_bundled_templates_load() {
+        let loader = TemplateChallengeLoader::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/challenges/helix/templates"
+        ));
+        assert!(!loader.load_all().unwrap().is_empty());
+    }
+}
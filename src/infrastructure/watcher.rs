@@ -3,9 +3,10 @@ use std::sync::mpsc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
-use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher as NotifyWatcher};
+use notify::event::{EventKind, ModifyKind};
+use notify::{Config, Event, RecommendedWatcher, RecursiveMode, Watcher as NotifyWatcher};
 
-use crate::application::FileWatcher;
+use crate::application::{FileWatcher, RunnerEvent};
 
 /// File watcher implementation using the notify crate
 pub struct FileChangeWatcher {
@@ -18,6 +19,17 @@ impl FileChangeWatcher {
     }
 }
 
+/// Whether an event can change file content
+///
+/// Access and metadata-only events (atime, permissions) are ignored; creates
+/// and renames are kept because editors save by renaming a temp file.
+fn is_content_change(event: &Event) -> bool {
+    !matches!(
+        event.kind,
+        EventKind::Access(_) | EventKind::Modify(ModifyKind::Metadata(_))
+    )
+}
+
 impl Default for FileChangeWatcher {
     fn default() -> Self {
         Self::new()
@@ -25,22 +37,26 @@ impl Default for FileChangeWatcher {
 }
 
 impl FileWatcher for FileChangeWatcher {
-    fn watch(&mut self, file_path: &Path, tx: mpsc::Sender<()>) -> Result<()> {
-        // Create a debounced watcher to avoid excessive notifications
+    fn watch(&mut self, path: &Path, tx: mpsc::Sender<RunnerEvent>) -> Result<()> {
+        // The poll interval only applies to the polling fallback backend
         let config = Config::default().with_poll_interval(Duration::from_millis(100));
 
         let mut watcher = RecommendedWatcher::new(
-            move |_res| {
-                // Send notification on any file event
-                let _ = tx.send(());
+            move |res: notify::Result<Event>| {
+                // Errors may mean missed events, so treat them as a change too
+                if res.as_ref().map_or(true, is_content_change) {
+                    let _ = tx.send(RunnerEvent::FileChanged);
+                }
             },
             config,
         )
         .context("Failed to create file watcher")?;
 
+        // Watch the directory recursively so nested workspace files are covered
+        // and replaced inodes (rename-on-save) keep being observed
         watcher
-            .watch(file_path, RecursiveMode::NonRecursive)
-            .with_context(|| format!("Failed to watch file: {}", file_path.display()))?;
+            .watch(path, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch path: {}", path.display()))?;
 
         self.watcher = Some(watcher);
         Ok(())
@@ -52,3 +68,49 @@ impl FileWatcher for FileChangeWatcher {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, DataChange, MetadataKind, ModifyKind, RenameMode};
+
+    #[test]
+    fn test_is_content_change() {
+        assert!(is_content_change(&Event::new(EventKind::Create(CreateKind::File))));
+        assert!(is_content_change(&Event::new(EventKind::Modify(ModifyKind::Data(
+            DataChange::Content
+        )))));
+        assert!(is_content_change(&Event::new(EventKind::Modify(ModifyKind::Name(
+            RenameMode::To
+        )))));
+        assert!(!is_content_change(&Event::new(EventKind::Access(AccessKind::Any))));
+        assert!(!is_content_change(&Event::new(EventKind::Modify(ModifyKind::Metadata(
+            MetadataKind::Permissions
+        )))));
+    }
+
+    #[test]
+    fn test_survives_rename_on_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("challenge.txt");
+        std::fs::write(&file, "start").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = FileChangeWatcher::new();
+        watcher.watch(dir.path(), tx).unwrap();
+
+        // Save by writing a temp file and renaming it over the original, twice
+        for content in ["first", "second"] {
+            let tmp = dir.path().join(".challenge.txt.tmp");
+            std::fs::write(&tmp, content).unwrap();
+            std::fs::rename(&tmp, &file).unwrap();
+            assert_eq!(
+                rx.recv_timeout(Duration::from_secs(5)).unwrap(),
+                RunnerEvent::FileChanged
+            );
+            while rx.recv_timeout(Duration::from_millis(100)).is_ok() {}
+        }
+
+        watcher.stop().unwrap();
+    }
+}
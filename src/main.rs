@@ -1,51 +1,201 @@
+// The domain and application layers expose a fuller API than the TUI uses yet.
+#![allow(dead_code)]
+
 mod application;
+mod cli;
 mod domain;
 mod infrastructure;
 mod ui;
 
 use anyhow::{Context, Result};
+use chrono::Utc;
+use clap::Parser;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-use application::{AchievementChecker, ChallengeRunner, ProgressTracker};
-use domain::Challenge;
+use application::{
+    ChallengeGenerator, ChallengeRunner, DailyPicker, GauntletPlanner, HunkSelector,
+    ProgressTracker, SurfaceRandomizer,
+};
+use application::validator::SolutionValidator;
+use cli::{Cli, Command, PackCommand, CHALLENGES_DIR, GIT_CHALLENGES_DIR};
+use domain::challenge::DEFAULT_FILE_PATH;
+use domain::{
+    Challenge, ChallengeFile, ConfigMode, Drill, GauntletResult, GauntletSplit, LearningPath,
+    Solution,
+};
+use infrastructure::data_dir::data_dir;
 use infrastructure::{
-    AsciinemaRecorder, ChallengeLoader, FileChangeWatcher, HelixEditor, JsonProgressRepository,
-    LocalFileSystem, Recorder, TomlChallengeLoader,
+    AsciinemaRecorder, BuiltinChallengeLoader, CastParser, ChallengeLibrary, ChallengeLinter, ChallengeLoader, EditorKind, FileChangeWatcher, GitHistory,
+    JsonProgressRepository, LocalFileSystem, PackStore, Recorder, TerminalEditor,
+    FileChallengeLoader, TomlChallengeWriter,
+};
+use ui::{
+    ChallengeListScreen, ChallengeMode, ChallengeScreen, DrillScreen, GauntletResultsScreen,
+    GauntletScreen, ListSelection, MainMenuScreen, MenuAction, ProgressScreen, ResultsScreen,
+    Settings, SettingsScreen,
 };
-use ui::{ChallengeListScreen, ChallengeScreen, MainMenuScreen, MenuAction, ProgressScreen, ResultsScreen};
+use ui::gauntlet_screen::format_time;
+
+/// Challenges per "Train Weakest Skill" session
+const WEAKEST_SKILL_SESSION: usize = 5;
 
 fn main() -> Result<()> {
-    // Check if Helix is installed
-    if !HelixEditor::is_installed() {
-        eprintln!("Error: Helix editor (hx) is not installed or not in PATH.");
-        eprintln!("Please install Helix from https://helix-editor.com/");
-        std::process::exit(1);
+    let cli = Cli::parse();
+    let options = Options::from_cli(&cli)?;
+    match cli.command {
+        Some(command) => run_command(command, &options),
+        None => run_menu(&options),
     }
+}
 
-    // Initialize progress tracking
-    let progress_repo = JsonProgressRepository::new()
-        .context("Failed to initialize progress repository")?;
-    let progress_tracker = ProgressTracker::new(progress_repo)
-        .context("Failed to load progress")?;
+/// Global command-line flags, checked
+struct Options {
+    /// Editor for this run instead of the saved preference
+    editor: Option<EditorKind>,
+    challenges_dir: PathBuf,
+    /// Where `from-git` writes challenges, loaded with the rest
+    git_dir: PathBuf,
+    record: bool,
+    profile: Option<String>,
+}
 
-    // Set default editor if not set
-    let progress = progress_tracker.get_progress();
-    if progress.editor_preference().is_none() {
-        progress_tracker.set_editor_preference("Helix".to_string())?;
+impl Options {
+    fn from_cli(cli: &Cli) -> Result<Self> {
+        let editor = match &cli.editor {
+            Some(name) => Some(EditorKind::from_name(name).with_context(|| {
+                let names: Vec<&str> = EditorKind::all().iter().map(EditorKind::name).collect();
+                format!("Unknown editor '{}'; use {}", name, names.join(", "))
+            })?),
+            None => None,
+        };
+        // Defaults live in the data directory, like packs and progress, so
+        // they don't depend on where the command is run from
+        let challenges_dir = match &cli.challenges_dir {
+            Some(dir) => dir.clone(),
+            None => data_dir()?.join(CHALLENGES_DIR),
+        };
+        Ok(Self {
+            editor,
+            challenges_dir,
+            git_dir: data_dir()?.join(GIT_CHALLENGES_DIR),
+            record: !cli.no_record,
+            profile: cli.profile.clone(),
+        })
+    }
+
+    /// Loads the progress of the selected profile
+    fn open_progress(&self) -> Result<ProgressTracker<JsonProgressRepository>> {
+        let progress_repo = match &self.profile {
+            Some(profile) => JsonProgressRepository::for_profile(profile)?,
+            None => JsonProgressRepository::new()
+                .context("Failed to initialize progress repository")?,
+        };
+        let progress_tracker = ProgressTracker::new(progress_repo)
+            .context("Failed to load progress")?;
+
+        // Set default editor if not set
+        let progress = progress_tracker.get_progress();
+        if progress.editor_preference().is_none() {
+            progress_tracker.set_editor_preference(EditorKind::Helix.name().to_string())?;
+        }
+        Ok(progress_tracker)
+    }
+
+    /// Loads the built-in challenges, the challenges directory, git history
+    /// and packs, and brings progress up to date with them
+    ///
+    /// Files that fail to load are skipped and reported by the library;
+    /// edits to the files are reloaded while the app runs.
+    fn open_library<R: application::ProgressRepository>(
+        &self,
+        progress_tracker: &ProgressTracker<R>,
+    ) -> Result<ChallengeLibrary> {
+        let library = ChallengeLibrary::open(
+            &self.challenges_dir,
+            &self.git_dir,
+            PackStore::open_default()?,
+        )
+        .context("Failed to load challenges")?;
+        progress_tracker.refresh_tier_thresholds(library.challenges());
+        progress_tracker.refresh_content_versions(library.challenges());
+        Ok(library)
     }
 
-    // Check if asciinema is installed (optional but recommended)
-    let use_recording = check_asciinema()?;
+    /// Checks the editor and asciinema before any challenge is played
+    fn start_session<R: application::ProgressRepository>(
+        &self,
+        progress_tracker: &ProgressTracker<R>,
+    ) -> Result<Session> {
+        let session = Session {
+            use_recording: false,
+            editor: self.editor,
+        };
 
-    // Load challenges from TOML files
-    let loader = TomlChallengeLoader::new("challenges/helix");
-    let challenges = loader.load_all().context("Failed to load challenges")?;
-    let total_challenges = challenges.len();
+        // Check if the editor is installed
+        let editor_kind = session.editor(progress_tracker);
+        if !TerminalEditor::is_installed(editor_kind) {
+            eprintln!(
+                "Error: {} ({}) is not installed or not in PATH.",
+                editor_kind.name(),
+                editor_kind.command()
+            );
+            eprintln!("Install it, or pick another editor in Settings or with --editor.");
+            if !EditorKind::all().into_iter().any(TerminalEditor::is_installed) {
+                std::process::exit(1);
+            }
+        }
+
+        // Check if asciinema is installed (optional but recommended)
+        let use_recording = self.record && check_asciinema()?;
+        Ok(Session { use_recording, ..session })
+    }
+}
+
+/// Choices for this run of the app that are not saved in progress
+#[derive(Debug, Clone, Copy)]
+struct Session {
+    /// Record keystrokes with asciinema
+    use_recording: bool,
+    /// Editor from `--editor`, overriding the saved preference
+    editor: Option<EditorKind>,
+}
+
+impl Session {
+    fn editor<R: application::ProgressRepository>(&self, progress_tracker: &ProgressTracker<R>) -> EditorKind {
+        self.editor.unwrap_or_else(|| preferred_editor(progress_tracker))
+    }
+}
+
+/// Runs the interactive menu until the user quits
+fn run_menu(options: &Options) -> Result<()> {
+    let progress_tracker = options.open_progress()?;
+    let session = options.start_session(&progress_tracker)?;
+    let mut library = options.open_library(&progress_tracker)?;
+    let mut dismissed_failures = None;
 
     // Main application loop
     loop {
+        reload_challenges(&mut library, &progress_tracker);
+        let challenges = library.challenges().to_vec();
+        let paths = library.paths().to_vec();
+        let total_challenges = challenges.len();
+        // Dismissed warnings come back when a reload changes the failures
+        let mut load_failures = load_errors(&library);
+        if dismissed_failures.as_ref() == Some(&load_failures) {
+            load_failures.clear();
+        }
+
         let progress = progress_tracker.get_progress();
-        let mut main_menu = MainMenuScreen::new();
+        let reviews_due = progress_tracker.reviews_due(&challenges);
+        let weakest_skill = progress_tracker.weakest_skill(&challenges, WEAKEST_SKILL_SESSION);
+        let today = Utc::now().date_naive();
+        let mut main_menu = MainMenuScreen::new()
+            .with_daily(progress.daily_result(today).is_some(), progress.daily_streak(today))
+            .with_reviews_due(reviews_due.len())
+            .with_weakest_skill(weakest_skill.as_ref().map(|(skill, _)| skill.tag()))
+            .with_load_failures(load_failures);
 
         let action = main_menu
             .show(&progress, total_challenges)
@@ -53,13 +203,31 @@ fn main() -> Result<()> {
 
         match action {
             MenuAction::StartTraining => {
-                if let Err(e) = run_training(&challenges, &progress_tracker, use_recording) {
+                if let Err(e) = run_training(&mut library, &progress_tracker, session) {
+                    eprintln!("Error during training: {}", e);
+                }
+            }
+            MenuAction::Daily => {
+                if let Err(e) = run_daily(&progress_tracker, session) {
+                    eprintln!("Error during daily challenge: {}", e);
+                }
+            }
+            MenuAction::Review => {
+                if let Err(e) = run_queue(&reviews_due, total_challenges, &progress_tracker, session) {
+                    eprintln!("Error during review: {}", e);
+                }
+            }
+            MenuAction::TrainWeakestSkill => {
+                let queue = weakest_skill.map(|(_, queue)| queue).unwrap_or_default();
+                if let Err(e) = run_queue(&queue, total_challenges, &progress_tracker, session) {
                     eprintln!("Error during training: {}", e);
                 }
             }
             MenuAction::ViewProgress => {
                 let progress = progress_tracker.get_progress();
-                let progress_screen = ProgressScreen::new();
+                let progress_screen = ProgressScreen::new()
+                    .with_challenges(&challenges)
+                    .with_skills(progress_tracker.skill_profile(&challenges));
                 progress_screen.show(&progress, total_challenges)
                     .context("Failed to display progress screen")?;
             }
@@ -67,13 +235,25 @@ fn main() -> Result<()> {
                 // Show challenge list without starting one
                 let progress = progress_tracker.get_progress();
                 let list_screen = ChallengeListScreen::new(challenges.clone())
-                    .with_progress(progress);
-                let _ = list_screen.show();
+                    .with_progress(progress)
+                    .with_paths(paths.clone())
+                    .with_load_errors(load_errors(&library));
+                let _ = list_screen.show_with_reload(&mut || {
+                    reload_challenges(&mut library, &progress_tracker)
+                        .then(|| (library.challenges().to_vec(), library.paths().to_vec(), load_errors(&library)))
+                });
+            }
+            MenuAction::Gauntlet => {
+                if let Err(e) = run_gauntlet(&challenges, &paths, &progress_tracker, session) {
+                    eprintln!("Error during gauntlet: {}", e);
+                }
             }
             MenuAction::Settings => {
-                println!("Settings not yet implemented.");
-                std::thread::sleep(std::time::Duration::from_secs(1));
+                if let Err(e) = run_settings(&progress_tracker) {
+                    eprintln!("Error updating settings: {}", e);
+                }
             }
+            MenuAction::DismissWarnings => dismissed_failures = Some(load_errors(&library)),
             MenuAction::Quit => {
                 println!("Goodbye!");
                 break;
@@ -84,6 +264,488 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Reloads edited challenge files and brings progress up to date with them
+///
+/// Returns whether anything was reloaded.
+fn reload_challenges<R: application::ProgressRepository>(
+    library: &mut ChallengeLibrary,
+    progress_tracker: &ProgressTracker<R>,
+) -> bool {
+    if !library.reload_if_changed() {
+        return false;
+    }
+    progress_tracker.refresh_tier_thresholds(library.challenges());
+    progress_tracker.refresh_content_versions(library.challenges());
+    true
+}
+
+/// Challenge files that failed to load, one line each
+fn load_errors(library: &ChallengeLibrary) -> Vec<String> {
+    library.failures().iter().map(ToString::to_string).collect()
+}
+
+/// Runs a command-line subcommand instead of the menu
+fn run_command(command: Command, options: &Options) -> Result<()> {
+    match command {
+        Command::Run { id } => {
+            let progress_tracker = options.open_progress()?;
+            let library = options.open_library(&progress_tracker)?;
+            let challenges = library.challenges();
+            let challenge = find_challenge(challenges, &id)?;
+            let progress = progress_tracker.get_progress();
+            let unmet = progress.unmet_requirements(challenge);
+            if progress.is_locked(challenge, challenges) {
+                anyhow::bail!(
+                    "{} is locked: reach {} on {} first",
+                    challenge.id(),
+                    challenge.required_tier().name(),
+                    unmet.join(", ")
+                );
+            }
+            let session = options.start_session(&progress_tracker)?;
+            run_challenge(challenge, challenges.len(), &progress_tracker, session)?;
+            Ok(())
+        }
+        Command::List { tag, difficulty, incomplete } => {
+            let progress_tracker = options.open_progress()?;
+            let library = options.open_library(&progress_tracker)?;
+            print_challenge_list(
+                library.challenges(),
+                &progress_tracker.get_progress(),
+                &tag,
+                difficulty.as_deref(),
+                incomplete,
+            );
+            Ok(())
+        }
+        Command::Stats { json } => {
+            let progress_tracker = options.open_progress()?;
+            let library = options.open_library(&progress_tracker)?;
+            print_stats(library.challenges(), &progress_tracker, options.profile.as_deref(), json)
+        }
+        Command::Random => {
+            let progress_tracker = options.open_progress()?;
+            let library = options.open_library(&progress_tracker)?;
+            let challenges = library.challenges();
+            let progress = progress_tracker.get_progress();
+            let unlocked: Vec<&Challenge> = challenges
+                .iter()
+                .filter(|challenge| !progress.is_locked(challenge, challenges))
+                .collect();
+            if unlocked.is_empty() {
+                anyhow::bail!("No unlocked challenges");
+            }
+            let challenge = unlocked[(fresh_seed() % unlocked.len() as u64) as usize];
+            let session = options.start_session(&progress_tracker)?;
+            run_challenge(challenge, challenges.len(), &progress_tracker, session)?;
+            Ok(())
+        }
+        Command::Review => {
+            let progress_tracker = options.open_progress()?;
+            let library = options.open_library(&progress_tracker)?;
+            let reviews_due = progress_tracker.reviews_due(library.challenges());
+            if reviews_due.is_empty() {
+                println!("No reviews due");
+                return Ok(());
+            }
+            let session = options.start_session(&progress_tracker)?;
+            run_queue(&reviews_due, library.challenges().len(), &progress_tracker, session)
+        }
+        Command::FromGit {
+            repo,
+            out,
+            commits,
+            max,
+        } => {
+            let out = out.unwrap_or_else(|| options.git_dir.clone());
+            let hunks = GitHistory::new(&repo).hunks(commits)?;
+            let challenges = HunkSelector::select(&hunks, max);
+            let writer = TomlChallengeWriter::new(&out);
+            for challenge in &challenges {
+                let path = writer.write(challenge)?;
+                println!("  {}  {}", path.display(), challenge.title());
+            }
+            println!(
+                "Wrote {} challenge(s) from {} hunk(s) in the last {} commit(s) of {}",
+                challenges.len(),
+                hunks.len(),
+                commits,
+                repo.display()
+            );
+            Ok(())
+        }
+        Command::Author { starting, out } => {
+            let out = out.unwrap_or_else(|| options.challenges_dir.clone());
+            run_author(&starting, &out, options)
+        }
+        Command::Lint { dir } => {
+            let dir = dir.unwrap_or_else(|| options.challenges_dir.clone());
+            let issues = ChallengeLinter::new(&dir).lint()?;
+            for issue in &issues {
+                println!("{}", issue);
+            }
+            let errors = issues.iter().filter(|issue| issue.is_error()).count();
+            println!(
+                "{}: {} error(s), {} warning(s)",
+                dir.display(),
+                errors,
+                issues.len() - errors
+            );
+            if errors > 0 {
+                anyhow::bail!("{} has {} error(s)", dir.display(), errors);
+            }
+            Ok(())
+        }
+        Command::ExportBuiltin { out, force } => {
+            let out = out.unwrap_or_else(|| options.challenges_dir.clone());
+            let written = BuiltinChallengeLoader::new().export(&out, force)?;
+            // Only the --challenges-dir directory is layered over the built-in set
+            let layered = matches!(
+                (std::fs::canonicalize(&out), std::fs::canonicalize(&options.challenges_dir)),
+                (Ok(out), Ok(layered)) if out == layered
+            );
+            if layered {
+                println!(
+                    "Wrote {} built-in file(s) to {}; they now replace the built-in challenges",
+                    written.len(),
+                    out.display()
+                );
+            } else {
+                println!(
+                    "Wrote {} built-in file(s) to {}; pass --challenges-dir {} to use them",
+                    written.len(),
+                    out.display(),
+                    out.display()
+                );
+            }
+            Ok(())
+        }
+        Command::Pack { command } => run_pack(command),
+    }
+}
+
+/// Looks up a challenge by id, suggesting ids that contain it
+fn find_challenge<'a>(challenges: &'a [Challenge], id: &str) -> Result<&'a Challenge> {
+    if let Some(challenge) = challenges.iter().find(|challenge| challenge.id() == id) {
+        return Ok(challenge);
+    }
+    let similar: Vec<&str> = challenges
+        .iter()
+        .map(|challenge| challenge.id())
+        .filter(|candidate| candidate.contains(id))
+        .take(5)
+        .collect();
+    if similar.is_empty() {
+        anyhow::bail!("No challenge with id '{}'; see `editor-dojo list`", id);
+    }
+    anyhow::bail!("No challenge with id '{}'; did you mean {}?", id, similar.join(", "))
+}
+
+/// Prints one line per challenge: id, difficulty, status and title
+///
+/// With tags, a challenge must have at least one of them, as in the
+/// challenge list's tag filter.
+fn print_challenge_list(
+    challenges: &[Challenge],
+    progress: &domain::Progress,
+    tags: &[String],
+    difficulty: Option<&str>,
+    incomplete: bool,
+) {
+    for challenge in challenges {
+        let stats = progress.get_challenge_stats(challenge.id());
+        let completed = stats.is_some_and(|stats| stats.is_completed());
+        let matches_tags = tags.is_empty() || challenge.tags().iter().any(|tag| tags.contains(tag));
+        let matches_difficulty = difficulty.is_none_or(|wanted| {
+            challenge.difficulty().is_some_and(|d| d.eq_ignore_ascii_case(wanted))
+        });
+        if !matches_tags || !matches_difficulty || (incomplete && completed) {
+            continue;
+        }
+
+        let status = if progress.is_locked(challenge, challenges) {
+            "locked".to_string()
+        } else {
+            match stats.filter(|stats| stats.is_completed()) {
+                Some(stats) => stats
+                    .mastery_tier()
+                    .map_or("done".to_string(), |tier| tier.name().to_lowercase()),
+                None => "-".to_string(),
+            }
+        };
+        println!(
+            "{:<32} {:<12} {:<8} {}",
+            challenge.id(),
+            challenge.difficulty().unwrap_or("-"),
+            status,
+            challenge.title()
+        );
+    }
+}
+
+/// Prints a progress summary, or JSON with per-challenge bests
+fn print_stats<R: application::ProgressRepository>(
+    challenges: &[Challenge],
+    progress_tracker: &ProgressTracker<R>,
+    profile: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let progress = progress_tracker.get_progress();
+    let today = Utc::now().date_naive();
+    let completed = challenges
+        .iter()
+        .filter(|challenge| {
+            progress
+                .get_challenge_stats(challenge.id())
+                .is_some_and(|stats| stats.is_completed())
+        })
+        .count();
+    let reviews_due = progress_tracker.reviews_due(challenges).len();
+
+    if json {
+        let per_challenge: Vec<serde_json::Value> = challenges
+            .iter()
+            .filter_map(|challenge| progress.get_challenge_stats(challenge.id()))
+            .map(|stats| {
+                serde_json::json!({
+                    "id": stats.challenge_id(),
+                    "completed": stats.is_completed(),
+                    "attempts": stats.attempt_count(),
+                    "best_time_secs": stats.best_time().map(|time| time.as_secs_f64()),
+                    "best_keystrokes": stats.best_keystrokes(),
+                    "best_score": stats.best_score().map(|score| score.points()),
+                    "tier": stats.mastery_tier().map(|tier| tier.name().to_lowercase()),
+                })
+            })
+            .collect();
+        let summary = serde_json::json!({
+            "profile": profile,
+            "challenges": challenges.len(),
+            "completed": completed,
+            "attempts": progress.total_attempts(),
+            "practice_time_secs": progress.total_practice_time().as_secs_f64(),
+            "current_streak": progress.calculate_current_streak(today),
+            "longest_streak": progress.longest_streak(),
+            "daily_streak": progress.daily_streak(today),
+            "average_solve_time_secs": progress.average_solve_time().map(|time| time.as_secs_f64()),
+            "average_keystrokes": progress.average_keystrokes(),
+            "reviews_due": reviews_due,
+            "per_challenge": per_challenge,
+        });
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+        return Ok(());
+    }
+
+    println!("Profile:            {}", profile.unwrap_or("default"));
+    println!("Completed:          {}/{}", completed, challenges.len());
+    println!("Attempts:           {}", progress.total_attempts());
+    println!("Practice time:      {}", format_time(progress.total_practice_time()));
+    println!(
+        "Streak:             {} day(s), longest {}",
+        progress.calculate_current_streak(today),
+        progress.longest_streak()
+    );
+    println!("Daily streak:       {}", progress.daily_streak(today));
+    if let Some(average) = progress.average_solve_time() {
+        println!("Average solve time: {}", format_time(average));
+    }
+    if let Some(average) = progress.average_keystrokes() {
+        println!("Average keystrokes: {}", average);
+    }
+    println!("Reviews due:        {}", reviews_due);
+    Ok(())
+}
+
+/// Installs, lists or removes packs in the user data dir
+fn run_pack(command: PackCommand) -> Result<()> {
+    let store = PackStore::open_default()?;
+    match command {
+        PackCommand::Install { source } => {
+            let manifest = store.install(&source)?;
+            let report = FileChallengeLoader::new(store.pack_dir(manifest.name())).load()?;
+            for failure in report.failures() {
+                println!("  warning: {}", failure);
+            }
+            println!(
+                "Installed {} {} with {} challenge(s); their ids start with {}/",
+                manifest.name(),
+                manifest.version(),
+                report.challenges().len(),
+                manifest.name()
+            );
+        }
+        PackCommand::List => {
+            let packs = store.list()?;
+            if packs.is_empty() {
+                println!("No packs installed in {}", store.packs_dir().display());
+            }
+            for pack in packs {
+                let mut details = Vec::new();
+                if let Some(author) = pack.author() {
+                    details.push(format!("by {}", author));
+                }
+                if !pack.editors().is_empty() {
+                    details.push(format!("for {}", pack.editors().join(", ")));
+                }
+                if let Some(license) = pack.license() {
+                    details.push(license.to_string());
+                }
+                println!("{} {}  {}", pack.name(), pack.version(), details.join(", "));
+            }
+        }
+        PackCommand::Remove { name } => {
+            let manifest = store.remove(&name)?;
+            println!(
+                "Removed {} {}; its progress stays recorded",
+                manifest.name(),
+                manifest.version()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Records the user editing `starting` into a new challenge and writes it
+/// as a challenge file in `out`
+fn run_author(starting: &Path, out: &Path, options: &Options) -> Result<()> {
+    if !AsciinemaRecorder::is_available() {
+        anyhow::bail!("Authoring records your keystrokes and needs asciinema; see https://asciinema.org/docs/");
+    }
+    let starting_content = std::fs::read_to_string(starting)
+        .with_context(|| format!("Failed to read starting file: {}", starting.display()))?;
+    let file_name = starting
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(DEFAULT_FILE_PATH);
+    let stem = starting
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("challenge");
+
+    // Optimal solutions are Helix keys, measured without custom keymaps
+    if let Some(editor) = options.editor.filter(|editor| *editor != EditorKind::Helix) {
+        anyhow::bail!(
+            "Challenges are authored in Helix, whose keys the optimal solution counts; {} is not supported",
+            editor.name()
+        );
+    }
+    let editor_kind = EditorKind::Helix;
+    println!("Edit the file into its target in {}, save, then quit.", editor_kind.name());
+    println!("Every key up to your last save becomes the optimal solution.");
+    prompt("Press Enter to open the editor", "")?;
+
+    let editor = TerminalEditor::new(editor_kind).with_config_mode(ConfigMode::Vanilla);
+    let mut runner = ChallengeRunner::new(editor, FileChangeWatcher::new(), LocalFileSystem::new())
+        .with_recorder(Box::new(AsciinemaRecorder::new()));
+    let files = [ChallengeFile::new(file_name, starting_content.as_str(), "")];
+    let captured = runner.capture(&files, "author").context("Failed to record the edit")?;
+
+    let target = captured.contents.into_iter().next().unwrap_or_default();
+    if SolutionValidator::new().is_valid(&target, &starting_content) {
+        anyhow::bail!("The file was not changed; nothing to author");
+    }
+    let recording = captured
+        .recording
+        .context("No keystrokes were recorded")?;
+    let solution = CastParser::raw_input_until(recording.file_path(), captured.last_change)
+        .context("Failed to read the recorded keys")?;
+    let keystrokes = recording.keystroke_count() as u32;
+    println!(
+        "\nRecorded {} keystrokes: {}",
+        keystrokes,
+        recording.key_sequence().format_for_display(80)
+    );
+
+    let default_id: String = stem
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let id = loop {
+        let id = prompt("Id", &default_id)?;
+        if TomlChallengeWriter::is_valid_id(&id) {
+            break id;
+        }
+        println!("Use lowercase letters, digits and '-' only.");
+    };
+    let title = prompt("Title", stem)?;
+    let description = prompt("Description", &title)?;
+    let difficulty = prompt("Difficulty (beginner/intermediate/advanced)", "beginner")?;
+    let tags = prompt("Tags (comma separated)", "")?;
+    let hint = prompt("Hint", "Repeat the change shown in the target")?;
+
+    let challenge = Challenge::new(id, title, description, "", "", hint)
+        .with_files(vec![ChallengeFile::new(file_name, starting_content, target)])
+        .with_difficulty(difficulty)
+        .with_tags(
+            tags.split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+        .with_optimal_solution(solution, keystrokes);
+    let path = TomlChallengeWriter::new(out).write(&challenge)?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
+
+/// Asks a question on the terminal; an empty answer takes the default
+fn prompt(question: &str, default: &str) -> Result<String> {
+    if default.is_empty() {
+        print!("{}: ", question);
+    } else {
+        print!("{} [{}]: ", question, default);
+    }
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+    Ok(if input.is_empty() { default } else { input }.to_string())
+}
+
+/// Returns the editor saved in progress, falling back to Helix
+fn preferred_editor<R: application::ProgressRepository>(
+    progress_tracker: &ProgressTracker<R>,
+) -> EditorKind {
+    progress_tracker
+        .get_progress()
+        .editor_preference()
+        .and_then(EditorKind::from_name)
+        .unwrap_or(EditorKind::Helix)
+}
+
+fn run_settings<R: application::ProgressRepository>(
+    progress_tracker: &ProgressTracker<R>,
+) -> Result<()> {
+    // Only offer editors that can actually be launched
+    let editors: Vec<String> = EditorKind::all()
+        .into_iter()
+        .filter(|kind| TerminalEditor::is_installed(*kind))
+        .map(|kind| kind.name().to_string())
+        .collect();
+
+    let current = Settings {
+        editor: preferred_editor(progress_tracker).name().to_string(),
+        config_mode: progress_tracker.get_progress().config_mode(),
+        blind_mode: progress_tracker.get_progress().blind_mode(),
+    };
+
+    let mut settings_screen = SettingsScreen::new(editors, current);
+    let settings = match settings_screen.show().context("Failed to display settings screen")? {
+        Some(settings) => settings,
+        None => return Ok(()),
+    };
+
+    if EditorKind::from_name(&settings.editor).is_some() {
+        progress_tracker.set_editor_preference(settings.editor)?;
+    }
+    progress_tracker.set_config_mode(settings.config_mode)?;
+    progress_tracker.set_blind_mode(settings.blind_mode)?;
+    Ok(())
+}
+
 fn check_asciinema() -> Result<bool> {
     if !AsciinemaRecorder::is_available() {
         eprintln!("\n┌─────────────────────────────────────────────┐");
@@ -118,60 +780,226 @@ fn check_asciinema() -> Result<bool> {
 }
 
 fn run_training<R: application::ProgressRepository>(
-    challenges: &[Challenge],
+    library: &mut ChallengeLibrary,
     progress_tracker: &ProgressTracker<R>,
-    use_recording: bool,
+    session: Session,
 ) -> Result<()> {
-    let total_challenges = challenges.len();
-
-    // Show challenge list screen with progress
+    // Show challenge list screen with progress, reloading edited challenges
     let progress = progress_tracker.get_progress();
-    let list_screen = ChallengeListScreen::new(challenges.to_vec())
-        .with_progress(progress);
-    let selected_challenge = list_screen
-        .show()
+    let list_screen = ChallengeListScreen::new(library.challenges().to_vec())
+        .with_progress(progress)
+        .with_paths(library.paths().to_vec())
+        .with_load_errors(load_errors(library));
+    let selection = list_screen
+        .show_with_reload(&mut || {
+            reload_challenges(library, progress_tracker)
+                .then(|| (library.challenges().to_vec(), library.paths().to_vec(), load_errors(library)))
+        })
         .context("Failed to display challenge list screen")?;
+    let challenges = library.challenges();
 
-    let challenge = match selected_challenge {
-        Some(c) => c,
-        None => {
-            return Ok(());
+    match selection {
+        Some(ListSelection::Train(challenge)) => {
+            run_challenge(&challenge, challenges.len(), progress_tracker, session)?;
+        }
+        Some(ListSelection::Drill(challenge)) => {
+            run_drill(&challenge, challenges.len(), progress_tracker, session)?;
         }
+        None => {}
+    }
+
+    Ok(())
+}
+
+/// Reruns one challenge until the target tier is hit enough times in a row
+/// or the user stops
+///
+/// Every run counts as a regular attempt on the challenge; randomized runs
+/// only change the letters, so they score like the original.
+fn run_drill<R: application::ProgressRepository>(
+    challenge: &Challenge,
+    total_challenges: usize,
+    progress_tracker: &ProgressTracker<R>,
+    session: Session,
+) -> Result<()> {
+    let mut drill_screen = DrillScreen::new();
+    let setup = match drill_screen
+        .configure(challenge)
+        .context("Failed to display drill screen")?
+    {
+        Some(setup) => setup,
+        None => return Ok(()),
     };
+    let mut drill = Drill::new(setup.target, setup.streak, challenge.tier_thresholds());
 
-    // Show challenge brief screen
-    let mut challenge_screen = ChallengeScreen::new();
-    let challenge_mode = challenge_screen
-        .show(&challenge)
-        .context("Failed to display challenge screen")?;
+    // One runner serves every run
+    let config_mode = progress_tracker.get_progress().config_mode();
+    let editor_kind = session.editor(progress_tracker);
+    let editor = TerminalEditor::new(editor_kind).with_config_mode(config_mode);
+    let mut runner = ChallengeRunner::new(editor, FileChangeWatcher::new(), LocalFileSystem::new());
+    if session.use_recording {
+        runner = runner.with_recorder(Box::new(AsciinemaRecorder::new()));
+    }
 
-    let challenge_mode = match challenge_mode {
-        Some(mode) => mode,
+    let seed = fresh_seed();
+    for run in 0u64.. {
+        let generated = ChallengeGenerator::generate(challenge, seed.wrapping_add(run));
+        let variant = if setup.randomize {
+            SurfaceRandomizer::randomize(&generated, seed.wrapping_add(run))
+        } else {
+            generated
+        };
+        if !drill_screen
+            .show_progress(&drill, &variant)
+            .context("Failed to display drill progress")?
+        {
+            break;
+        }
+
+        let solution = runner
+            .run(&variant)
+            .context("Failed to run challenge")?
+            .with_config_mode(config_mode)
+            .with_ranked_keystrokes(editor_kind.ranks_keystrokes());
+        progress_tracker
+            .record_variant_solution(challenge, &variant, &solution)
+            .context("Failed to record progress")?;
+        progress_tracker
+            .check_achievements(total_challenges)
+            .context("Failed to check achievements")?;
+
+        drill.record(solution.is_completed(), solution.scored_time(), solution.ranked_keystrokes());
+        if drill.is_complete() {
+            drill_screen
+                .show_progress(&drill, &variant)
+                .context("Failed to display drill progress")?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a gauntlet: the chosen challenges back to back without briefs, on
+/// one clock, ending at the first leg left unsolved
+///
+/// Runs in vanilla mode go on the gauntlet's leaderboard; runs with the
+/// user's own config are shown but not stored.
+fn run_gauntlet<R: application::ProgressRepository>(
+    challenges: &[Challenge],
+    paths: &[LearningPath],
+    progress_tracker: &ProgressTracker<R>,
+    session: Session,
+) -> Result<()> {
+    let progress = progress_tracker.get_progress();
+    let mut gauntlet_screen = GauntletScreen::new(GauntletPlanner::presets(challenges, paths));
+    let selection = match gauntlet_screen
+        .show(&progress)
+        .context("Failed to display gauntlet screen")?
+    {
+        Some(selection) => selection,
         None => return Ok(()),
     };
 
-    // Dependency injection: create concrete implementations
-    let editor = HelixEditor::new();
-    let watcher = FileChangeWatcher::new();
-    let filesystem = LocalFileSystem::new();
+    let seed = fresh_seed();
+    let legs = GauntletPlanner::pick(&selection, challenges, &progress, seed);
+    if legs.is_empty() {
+        return Ok(());
+    }
 
-    // Create the challenge runner with injected dependencies
-    let mut runner = ChallengeRunner::new(editor, watcher, filesystem);
+    // One runner serves every leg
+    let config_mode = progress.config_mode();
+    let editor_kind = session.editor(progress_tracker);
+    let editor = TerminalEditor::new(editor_kind).with_config_mode(config_mode);
+    let mut runner = ChallengeRunner::new(editor, FileChangeWatcher::new(), LocalFileSystem::new());
+    if session.use_recording {
+        runner = runner.with_recorder(Box::new(AsciinemaRecorder::new()));
+    }
 
-    // Add recorder if available
-    if use_recording {
-        let recorder = AsciinemaRecorder::new("hx");
-        runner = runner.with_recorder(Box::new(recorder));
+    let start = Instant::now();
+    let mut splits = Vec::new();
+    for (leg, challenge) in legs.iter().enumerate() {
+        let challenge = &ChallengeGenerator::generate(challenge, seed.wrapping_add(leg as u64));
+        let solution = runner
+            .run(challenge)
+            .context("Failed to run challenge")?
+            .with_config_mode(config_mode)
+            .with_ranked_keystrokes(editor_kind.ranks_keystrokes());
+        splits.push(GauntletSplit::new(
+            challenge.id(),
+            solution.scored_time(),
+            solution.ranked_keystrokes(),
+            solution.is_completed(),
+        ));
+        if !solution.is_completed() {
+            break;
+        }
     }
 
-    // Run the challenge
-    let solution = runner.run(&challenge).context("Failed to run challenge")?;
+    let result = GauntletResult::new(
+        selection.key(),
+        selection.name(),
+        Utc::now(),
+        start.elapsed(),
+        legs.len(),
+        splits,
+    );
+    let mut rank = None;
+    if config_mode == ConfigMode::Vanilla {
+        progress_tracker
+            .record_gauntlet(result.clone())
+            .context("Failed to record gauntlet")?;
+        rank = progress_tracker
+            .get_progress()
+            .gauntlet_leaderboard(result.key())
+            .iter()
+            .position(|run| **run == result)
+            .map(|index| index + 1);
+    }
+
+    GauntletResultsScreen::new()
+        .with_challenges(challenges)
+        .show(&result, rank)
+        .context("Failed to display gauntlet results")
+}
+
+/// Runs challenges back to back (due reviews, weakest-skill training) until
+/// the queue is empty or the user quits a challenge brief
+fn run_queue<R: application::ProgressRepository>(
+    queue: &[Challenge],
+    total_challenges: usize,
+    progress_tracker: &ProgressTracker<R>,
+    session: Session,
+) -> Result<()> {
+    for challenge in queue {
+        if !run_challenge(challenge, total_challenges, progress_tracker, session)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Shows the brief, runs the editor and shows the results for one challenge
+///
+/// Returns false if the user backed out at the brief.
+fn run_challenge<R: application::ProgressRepository>(
+    challenge: &Challenge,
+    total_challenges: usize,
+    progress_tracker: &ProgressTracker<R>,
+    session: Session,
+) -> Result<bool> {
+    let challenge = &ChallengeGenerator::generate(challenge, fresh_seed());
+    let (solution, challenge_mode) =
+        match play_challenge(challenge, progress_tracker, session, Brief::Challenge)? {
+            Some(played) => played,
+            None => return Ok(false),
+        };
 
     // Only record progress and check achievements in challenge mode (not practice mode)
     let newly_unlocked = if !challenge_mode.practice_mode {
         // Record the solution in progress tracker
         progress_tracker
-            .record_solution(challenge.id(), &solution)
+            .record_solution(challenge, &solution)
             .context("Failed to record progress")?;
 
         // Check for new achievements
@@ -195,5 +1023,129 @@ fn run_training<R: application::ProgressRepository>(
             .context("Failed to display results screen")?;
     }
 
-    Ok(())
+    Ok(true)
+}
+
+/// Plays today's daily challenge
+///
+/// The first vanilla challenge-mode attempt of the (UTC) day is the scored
+/// one; it goes into the daily history, not the challenge's own stats.
+/// Once it is played, the brief opens in practice mode.
+fn run_daily<R: application::ProgressRepository>(
+    progress_tracker: &ProgressTracker<R>,
+    session: Session,
+) -> Result<()> {
+    let today = Utc::now().date_naive();
+    // Only the built-in challenges are the same for everyone, whatever
+    // packs or local edits are loaded
+    let builtin = BuiltinChallengeLoader::new()
+        .load()
+        .context("Failed to load built-in challenges")?
+        .into_challenges();
+    let progress = progress_tracker.get_progress();
+    let unlocked: Vec<Challenge> = builtin
+        .iter()
+        .filter(|challenge| !progress.is_locked(challenge, &builtin))
+        .cloned()
+        .collect();
+    let challenge = match DailyPicker::pick(&unlocked, today) {
+        Some(challenge) => challenge,
+        None => return Ok(()),
+    };
+    // Generated challenges get the same text for everyone on the day
+    let challenge = &ChallengeGenerator::generate(challenge, DailyPicker::seed(today));
+
+    // The first run of the day is the scored one, whatever mode is set
+    let played_today = progress_tracker.get_progress().daily_result(today).is_some();
+    let brief = if played_today { Brief::Practice } else { Brief::Scored };
+    let (solution, _) = match play_challenge(challenge, progress_tracker, session, brief)? {
+        Some(played) => played,
+        None => return Ok(()),
+    };
+
+    if brief == Brief::Scored {
+        progress_tracker
+            .record_daily(challenge, &solution, today)
+            .context("Failed to record daily challenge")?;
+    }
+
+    ResultsScreen::new()
+        .show(&solution)
+        .context("Failed to display results screen")
+}
+
+/// Seed for a fresh generated buffer or random pick
+fn fresh_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+/// How the brief opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Brief {
+    /// Challenge mode, with practice one key away
+    Challenge,
+    /// Practice mode, with challenge one key away
+    Practice,
+    /// Challenge mode in the vanilla config, without a practice toggle
+    Scored,
+}
+
+/// Shows the brief and runs the editor for one challenge
+///
+/// Returns None if the user backed out at the brief.
+fn play_challenge<R: application::ProgressRepository>(
+    challenge: &Challenge,
+    progress_tracker: &ProgressTracker<R>,
+    session: Session,
+    brief: Brief,
+) -> Result<Option<(Solution, ChallengeMode)>> {
+    let config_mode = match brief {
+        Brief::Scored => ConfigMode::Vanilla,
+        Brief::Challenge | Brief::Practice => progress_tracker.get_progress().config_mode(),
+    };
+    let blind_mode = progress_tracker.get_progress().blind_mode();
+
+    // Show challenge brief screen
+    let mut challenge_screen = ChallengeScreen::new()
+        .with_config_mode(config_mode)
+        .with_blind_mode(blind_mode)
+        .with_practice_mode(brief == Brief::Practice)
+        .with_practice_toggle(brief != Brief::Scored);
+    let challenge_mode = challenge_screen
+        .show(challenge)
+        .context("Failed to display challenge screen")?;
+
+    let challenge_mode = match challenge_mode {
+        Some(mode) => mode,
+        None => return Ok(None),
+    };
+
+    // Dependency injection: create concrete implementations
+    let editor_kind = session.editor(progress_tracker);
+    let editor = TerminalEditor::new(editor_kind).with_config_mode(config_mode);
+    let watcher = FileChangeWatcher::new();
+    let filesystem = LocalFileSystem::new();
+
+    // Create the challenge runner with injected dependencies
+    let mut runner = ChallengeRunner::new(editor, watcher, filesystem);
+
+    // Add recorder if available
+    if session.use_recording {
+        let recorder = AsciinemaRecorder::new();
+        runner = runner.with_recorder(Box::new(recorder));
+    }
+
+    // Run the challenge
+    let solution = runner
+        .run(challenge)
+        .context("Failed to run challenge")?
+        .with_config_mode(config_mode)
+        .with_blind(challenge_mode.blind)
+        .with_time_penalty(challenge_mode.time_penalty)
+        .with_hints_used(challenge_mode.hints_used)
+        .with_ranked_keystrokes(editor_kind.ranks_keystrokes());
+
+    Ok(Some((solution, challenge_mode)))
 }
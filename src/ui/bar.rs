@@ -0,0 +1,23 @@
+/// Width of every bar in cells
+const WIDTH: usize = 10;
+
+/// Ten-cell bar filled to `done` out of `total`, e.g. "███████░░░"
+///
+/// Rounds down, so the bar is only full once everything is done.
+pub fn progress_bar(done: usize, total: usize) -> String {
+    let filled = (done.min(total) * WIDTH).checked_div(total).unwrap_or(0);
+    format!("{}{}", "█".repeat(filled), "░".repeat(WIDTH - filled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(progress_bar(3, 6), "█████░░░░░");
+        assert_eq!(progress_bar(64, 100), "██████░░░░");
+        assert_eq!(progress_bar(100, 100), "██████████");
+        assert_eq!(progress_bar(0, 0), "░░░░░░░░░░");
+    }
+}
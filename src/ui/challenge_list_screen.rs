@@ -1,5 +1,6 @@
 use std::io;
 use std::collections::HashSet;
+use std::time::Duration;
 
 use anyhow::Result;
 use crossterm::{
@@ -9,14 +10,25 @@ use crossterm::{
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
     Terminal,
 };
 
-use crate::domain::{Challenge, Progress};
+use crate::domain::{Challenge, LearningPath, Progress, Score};
+use crate::ui::bar::progress_bar;
+
+/// How often the list checks for edited challenge files while idle
+const RELOAD_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Load errors listed under the challenges; the rest are counted
+const MAX_LISTED_ERRORS: usize = 4;
+
+/// Fresh challenges, learning paths and load errors after challenge files
+/// changed on disk
+type Reload<'a> = dyn FnMut() -> Option<(Vec<Challenge>, Vec<LearningPath>, Vec<String>)> + 'a;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum FilterMode {
@@ -25,10 +37,28 @@ enum FilterMode {
     Completed,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SortMode {
+    /// File order
+    Default,
+    /// Highest best score first; unscored challenges last
+    Score,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum DisplayMode {
     List,
     FilterPanel,
+    Paths,
+}
+
+/// What the user picked in the challenge list
+#[derive(Debug, Clone)]
+pub enum ListSelection {
+    /// Play the challenge once
+    Train(Challenge),
+    /// Repeat the challenge until a tier is reached several times in a row
+    Drill(Challenge),
 }
 
 pub struct ChallengeListScreen {
@@ -38,9 +68,15 @@ pub struct ChallengeListScreen {
     selected_index: usize,
     filter_mode: FilterMode,
     tag_filters: HashSet<String>,
+    sort_mode: SortMode,
     display_mode: DisplayMode,
     filter_panel_selected: usize,
     available_tags: Vec<String>,
+    paths: Vec<LearningPath>,
+    /// One-line notice shown in the status bar (e.g. why a challenge is locked)
+    notice: Option<String>,
+    /// Challenge files that failed to load, shown below the list
+    load_errors: Vec<String>,
 }
 
 impl ChallengeListScreen {
@@ -55,9 +91,13 @@ impl ChallengeListScreen {
             selected_index: 0,
             filter_mode: FilterMode::All,
             tag_filters: HashSet::new(),
+            sort_mode: SortMode::Default,
             display_mode: DisplayMode::List,
             filter_panel_selected: 0,
             available_tags,
+            paths: Vec::new(),
+            notice: None,
+            load_errors: Vec::new(),
         }
     }
 
@@ -66,6 +106,91 @@ impl ChallengeListScreen {
         self
     }
 
+    pub fn with_paths(mut self, paths: Vec<LearningPath>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    pub fn with_load_errors(mut self, errors: Vec<String>) -> Self {
+        self.load_errors = errors;
+        self
+    }
+
+    /// Swaps in reloaded challenges and paths, keeping the selection and
+    /// filters that still apply
+    fn replace_challenges(
+        &mut self,
+        challenges: Vec<Challenge>,
+        paths: Vec<LearningPath>,
+        errors: Vec<String>,
+    ) {
+        let selected_id = self
+            .filtered_challenges
+            .get(self.selected_index)
+            .map(|&idx| self.all_challenges[idx].id().to_string());
+
+        self.available_tags = Self::extract_all_tags(&challenges);
+        self.tag_filters.retain(|tag| self.available_tags.contains(tag));
+        self.filter_panel_selected = self
+            .filter_panel_selected
+            .min(self.available_tags.len().saturating_sub(1));
+        self.all_challenges = challenges;
+        self.paths = paths;
+        if self.paths.is_empty() && self.display_mode == DisplayMode::Paths {
+            self.display_mode = DisplayMode::List;
+        }
+        self.load_errors = errors;
+        self.apply_filters();
+
+        if let Some(position) = selected_id.and_then(|id| {
+            self.filtered_challenges
+                .iter()
+                .position(|&idx| self.all_challenges[idx].id() == id)
+        }) {
+            self.selected_index = position;
+        }
+        self.notice = Some(match self.load_errors.len() {
+            0 => format!("Reloaded {} challenges", self.all_challenges.len()),
+            errors => format!(
+                "Reloaded {} challenges; {} file(s) failed to load",
+                self.all_challenges.len(),
+                errors
+            ),
+        });
+    }
+
+    /// Prerequisites still to reach their tier; unknown ids never lock a challenge
+    fn unmet_requirements<'a>(&self, challenge: &'a Challenge) -> Vec<&'a str> {
+        let empty = Progress::new();
+        self.progress
+            .as_ref()
+            .unwrap_or(&empty)
+            .unmet_requirements(challenge)
+            .into_iter()
+            .filter(|id| self.all_challenges.iter().any(|c| c.id() == *id))
+            .collect()
+    }
+
+    fn is_locked(&self, challenge: &Challenge) -> bool {
+        !self.unmet_requirements(challenge).is_empty()
+    }
+
+    /// Returns the challenge if it can be started, or explains why not
+    fn select(&mut self, challenge_idx: usize) -> Option<Challenge> {
+        let challenge = &self.all_challenges[challenge_idx];
+        let unmet = self.unmet_requirements(challenge);
+        if unmet.is_empty() {
+            return Some(challenge.clone());
+        }
+
+        self.notice = Some(format!(
+            "🔒 Locked: reach {} on {} first",
+            challenge.required_tier().name(),
+            unmet.join(", ")
+        ));
+        None
+    }
+
     /// Extract all unique tags from challenges, sorted
     fn extract_all_tags(challenges: &[Challenge]) -> Vec<String> {
         let mut tags: HashSet<String> = HashSet::new();
@@ -91,7 +216,7 @@ impl ChallengeListScreen {
                     FilterMode::Incomplete => {
                         if let Some(ref progress) = self.progress {
                             progress.get_challenge_stats(challenge.id())
-                                .map_or(true, |stats| !stats.is_completed())
+                                .is_none_or(|stats| !stats.is_completed())
                         } else {
                             true
                         }
@@ -99,7 +224,7 @@ impl ChallengeListScreen {
                     FilterMode::Completed => {
                         if let Some(ref progress) = self.progress {
                             progress.get_challenge_stats(challenge.id())
-                                .map_or(false, |stats| stats.is_completed())
+                                .is_some_and(|stats| stats.is_completed())
                         } else {
                             false
                         }
@@ -118,15 +243,35 @@ impl ChallengeListScreen {
             .map(|(idx, _)| idx)
             .collect();
 
+        if self.sort_mode == SortMode::Score {
+            // Stable sort keeps file order among equal scores
+            let mut filtered = std::mem::take(&mut self.filtered_challenges);
+            filtered.sort_by_key(|&idx| std::cmp::Reverse(self.best_score(&self.all_challenges[idx])));
+            self.filtered_challenges = filtered;
+        }
+
         // Reset selected index if out of bounds
         if self.selected_index >= self.filtered_challenges.len() && !self.filtered_challenges.is_empty() {
             self.selected_index = 0;
         }
     }
 
+    fn best_score(&self, challenge: &Challenge) -> Option<Score> {
+        self.progress
+            .as_ref()
+            .and_then(|progress| progress.get_challenge_stats(challenge.id()))
+            .and_then(|stats| stats.best_score())
+    }
+
     /// Get a random challenge from filtered set
     fn get_random_challenge(&self) -> Option<Challenge> {
-        if self.filtered_challenges.is_empty() {
+        let unlocked: Vec<usize> = self
+            .filtered_challenges
+            .iter()
+            .copied()
+            .filter(|&idx| !self.is_locked(&self.all_challenges[idx]))
+            .collect();
+        if unlocked.is_empty() {
             return None;
         }
 
@@ -136,13 +281,19 @@ impl ChallengeListScreen {
             .unwrap()
             .as_nanos() as usize;
 
-        let random_idx = seed % self.filtered_challenges.len();
-        let challenge_idx = self.filtered_challenges[random_idx];
+        let random_idx = seed % unlocked.len();
+        let challenge_idx = unlocked[random_idx];
         Some(self.all_challenges[challenge_idx].clone())
     }
 
     /// Shows the challenge list and returns the selected challenge
-    pub fn show(mut self) -> Result<Option<Challenge>> {
+    pub fn show(self) -> Result<Option<ListSelection>> {
+        self.show_with_reload(&mut || None)
+    }
+
+    /// Shows the challenge list, asking `reload` for fresh challenges
+    /// whenever the user is idle so edits to challenge files show up live
+    pub fn show_with_reload(mut self, reload: &mut Reload) -> Result<Option<ListSelection>> {
         self.apply_filters();
 
         // Setup terminal
@@ -152,7 +303,7 @@ impl ChallengeListScreen {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        let result = self.run(&mut terminal);
+        let result = self.run(&mut terminal, reload);
 
         // Restore terminal
         disable_raw_mode()?;
@@ -162,40 +313,59 @@ impl ChallengeListScreen {
         result
     }
 
-    fn run(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<Option<Challenge>> {
+    fn run(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        reload: &mut Reload,
+    ) -> Result<Option<ListSelection>> {
         loop {
             terminal.draw(|f| self.render(f))?;
 
+            if !event::poll(RELOAD_POLL_INTERVAL)? {
+                if let Some((challenges, paths, errors)) = reload() {
+                    self.replace_challenges(challenges, paths, errors);
+                }
+                continue;
+            }
+
             if let Event::Key(key) = event::read()? {
+                self.notice = None;
                 match self.display_mode {
                     DisplayMode::List => {
                         match key.code {
                             KeyCode::Char('q') | KeyCode::Esc => {
                                 return Ok(None);
                             }
-                            KeyCode::Up | KeyCode::Char('k') => {
-                                if self.selected_index > 0 {
-                                    self.selected_index -= 1;
-                                }
+                            KeyCode::Up | KeyCode::Char('k') if self.selected_index > 0 => {
+                                self.selected_index -= 1;
                             }
-                            KeyCode::Down | KeyCode::Char('j') => {
-                                if self.selected_index < self.filtered_challenges.len().saturating_sub(1) {
-                                    self.selected_index += 1;
+                            KeyCode::Down | KeyCode::Char('j')
+                                if self.selected_index < self.filtered_challenges.len().saturating_sub(1) =>
+                            {
+                                self.selected_index += 1;
+                            }
+                            KeyCode::Enter if !self.filtered_challenges.is_empty() => {
+                                let challenge_idx = self.filtered_challenges[self.selected_index];
+                                if let Some(challenge) = self.select(challenge_idx) {
+                                    return Ok(Some(ListSelection::Train(challenge)));
                                 }
                             }
-                            KeyCode::Enter => {
-                                if !self.filtered_challenges.is_empty() {
-                                    let challenge_idx = self.filtered_challenges[self.selected_index];
-                                    return Ok(Some(self.all_challenges[challenge_idx].clone()));
+                            KeyCode::Char('d') if !self.filtered_challenges.is_empty() => {
+                                let challenge_idx = self.filtered_challenges[self.selected_index];
+                                if let Some(challenge) = self.select(challenge_idx) {
+                                    return Ok(Some(ListSelection::Drill(challenge)));
                                 }
                             }
+                            KeyCode::Char('p') if !self.paths.is_empty() => {
+                                self.display_mode = DisplayMode::Paths;
+                            }
                             KeyCode::Char('f') => {
                                 // Toggle to filter panel
                                 self.display_mode = DisplayMode::FilterPanel;
                             }
                             KeyCode::Char('r') => {
                                 // Quick practice - random challenge
-                                return Ok(self.get_random_challenge());
+                                return Ok(self.get_random_challenge().map(ListSelection::Train));
                             }
                             KeyCode::Char('a') => {
                                 // Show all
@@ -212,36 +382,46 @@ impl ChallengeListScreen {
                                 self.filter_mode = FilterMode::Completed;
                                 self.apply_filters();
                             }
+                            KeyCode::Char('s') => {
+                                self.sort_mode = match self.sort_mode {
+                                    SortMode::Default => SortMode::Score,
+                                    SortMode::Score => SortMode::Default,
+                                };
+                                self.apply_filters();
+                            }
                             _ => {}
                         }
                     }
+                    DisplayMode::Paths => {
+                        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('p')) {
+                            self.display_mode = DisplayMode::List;
+                        }
+                    }
                     DisplayMode::FilterPanel => {
                         match key.code {
                             KeyCode::Esc | KeyCode::Char('q') => {
                                 // Return to list
                                 self.display_mode = DisplayMode::List;
                             }
-                            KeyCode::Up | KeyCode::Char('k') => {
-                                if self.filter_panel_selected > 0 {
-                                    self.filter_panel_selected -= 1;
-                                }
+                            KeyCode::Up | KeyCode::Char('k') if self.filter_panel_selected > 0 => {
+                                self.filter_panel_selected -= 1;
                             }
-                            KeyCode::Down | KeyCode::Char('j') => {
-                                if self.filter_panel_selected < self.available_tags.len().saturating_sub(1) {
-                                    self.filter_panel_selected += 1;
-                                }
+                            KeyCode::Down | KeyCode::Char('j')
+                                if self.filter_panel_selected < self.available_tags.len().saturating_sub(1) =>
+                            {
+                                self.filter_panel_selected += 1;
                             }
-                            KeyCode::Enter | KeyCode::Char(' ') => {
+                            KeyCode::Enter | KeyCode::Char(' ')
+                                if self.filter_panel_selected < self.available_tags.len() =>
+                            {
                                 // Toggle selected tag filter
-                                if !self.available_tags.is_empty() && self.filter_panel_selected < self.available_tags.len() {
-                                    let tag = self.available_tags[self.filter_panel_selected].clone();
-                                    if self.tag_filters.contains(&tag) {
-                                        self.tag_filters.remove(&tag);
-                                    } else {
-                                        self.tag_filters.insert(tag);
-                                    }
-                                    self.apply_filters();
+                                let tag = self.available_tags[self.filter_panel_selected].clone();
+                                if self.tag_filters.contains(&tag) {
+                                    self.tag_filters.remove(&tag);
+                                } else {
+                                    self.tag_filters.insert(tag);
                                 }
+                                self.apply_filters();
                             }
                             KeyCode::Char('x') => {
                                 // Clear all tag filters
@@ -262,6 +442,7 @@ impl ChallengeListScreen {
         match self.display_mode {
             DisplayMode::List => self.render_list_view(f, size),
             DisplayMode::FilterPanel => self.render_filter_panel(f, size),
+            DisplayMode::Paths => self.render_paths(f, size),
         }
     }
 
@@ -272,14 +453,55 @@ impl ChallengeListScreen {
                 Constraint::Length(3),  // Title
                 Constraint::Length(2),  // Filter status
                 Constraint::Min(0),     // Challenge list
-                Constraint::Length(4),  // Footer
+                Constraint::Length(self.errors_height()),  // Load errors
+                Constraint::Length(5),  // Footer
             ])
             .split(area);
 
         self.render_title(f, chunks[0]);
         self.render_filter_status(f, chunks[1]);
         self.render_list(f, chunks[2]);
-        self.render_footer(f, chunks[3]);
+        self.render_load_errors(f, chunks[3]);
+        self.render_footer(f, chunks[4]);
+    }
+
+    /// Rows for the load errors panel: borders plus the listed errors and a
+    /// line for any remainder
+    fn errors_height(&self) -> u16 {
+        match self.load_errors.len() {
+            0 => 0,
+            n if n > MAX_LISTED_ERRORS => MAX_LISTED_ERRORS as u16 + 3,
+            n => n as u16 + 2,
+        }
+    }
+
+    fn render_load_errors(&self, f: &mut ratatui::Frame, area: Rect) {
+        if self.load_errors.is_empty() {
+            return;
+        }
+
+        let mut lines: Vec<Line> = self
+            .load_errors
+            .iter()
+            .take(MAX_LISTED_ERRORS)
+            .map(|error| Line::from(error.as_str()))
+            .collect();
+        if self.load_errors.len() > MAX_LISTED_ERRORS {
+            lines.push(Line::from(format!(
+                "... and {} more",
+                self.load_errors.len() - MAX_LISTED_ERRORS
+            )));
+        }
+
+        let errors = Paragraph::new(lines)
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .title(" Failed to load ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Red)),
+            );
+        f.render_widget(errors, area);
     }
 
     fn render_filter_panel(&self, f: &mut ratatui::Frame, area: Rect) {
@@ -338,6 +560,78 @@ impl ChallengeListScreen {
         f.render_widget(footer, chunks[2]);
     }
 
+    fn render_paths(&self, f: &mut ratatui::Frame, area: Rect) {
+        let chunks = Layout::default()
+            .constraints([
+                Constraint::Length(3),  // Title
+                Constraint::Min(0),     // Paths
+                Constraint::Length(3),  // Footer
+            ])
+            .split(area);
+
+        let title = Paragraph::new("LEARNING PATHS")
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::BOTTOM));
+        f.render_widget(title, chunks[0]);
+
+        let empty = Progress::new();
+        let progress = self.progress.as_ref().unwrap_or(&empty);
+        let mut lines = Vec::new();
+        for path in &self.paths {
+            lines.push(Line::from(Span::styled(
+                path.name(),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )));
+
+            let current = path.current_stage(progress).map(|stage| stage.name());
+            for (i, stage) in path.stages().iter().enumerate() {
+                let total = stage.challenge_ids().len();
+                let done = stage.completed_count(progress);
+                let marker = if done == total {
+                    "✓"
+                } else if current == Some(stage.name()) {
+                    "▶"
+                } else {
+                    " "
+                };
+                let style = if done == total {
+                    Style::default().fg(Color::Green)
+                } else if current == Some(stage.name()) {
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "  {} {}. {:<28} {} {}/{}",
+                        marker,
+                        i + 1,
+                        stage.name(),
+                        progress_bar(done, total),
+                        done,
+                        total
+                    ),
+                    style,
+                )));
+            }
+            lines.push(Line::from(""));
+        }
+
+        let paths = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        f.render_widget(paths, chunks[1]);
+
+        let footer = Paragraph::new("p/Esc: Back to List")
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        f.render_widget(footer, chunks[2]);
+    }
+
     fn render_title(&self, f: &mut ratatui::Frame, area: Rect) {
         let title = Paragraph::new("CHALLENGE SELECTION")
             .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
@@ -361,16 +655,27 @@ impl ChallengeListScreen {
             format!(" | Tags: {}", tags.join(", "))
         };
 
+        let sort_text = match self.sort_mode {
+            SortMode::Default => "",
+            SortMode::Score => " | Sorted by score",
+        };
+
         let status = format!(
-            "Showing: {} ({}/{}){}",
+            "Showing: {} ({}/{}){}{}",
             mode_text,
             self.filtered_challenges.len(),
             self.all_challenges.len(),
-            tag_text
+            tag_text,
+            sort_text
         );
 
+        let (status, color) = match &self.notice {
+            Some(notice) => (notice.clone(), Color::Red),
+            None => (status, Color::Yellow),
+        };
+
         let status_widget = Paragraph::new(status)
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(color))
             .alignment(Alignment::Left);
 
         f.render_widget(status_widget, area);
@@ -392,34 +697,42 @@ impl ChallengeListScreen {
             .map(|(display_idx, &challenge_idx)| {
                 let challenge = &self.all_challenges[challenge_idx];
 
-                // Show completion status if we have progress
-                let completion_marker = if let Some(ref progress) = self.progress {
-                    if let Some(stats) = progress.get_challenge_stats(challenge.id()) {
-                        if stats.is_completed() {
-                            " ✓"
-                        } else {
-                            ""
+                // Show completion status, tier and best score if we have progress
+                let completion_marker = self
+                    .progress
+                    .as_ref()
+                    .and_then(|progress| progress.get_challenge_stats(challenge.id()))
+                    .filter(|stats| stats.is_completed())
+                    .map(|stats| {
+                        let tier = stats.mastery_tier().map_or("", |tier| tier.emoji());
+                        let no_hints = if stats.is_solved_without_hints() { " 🎯" } else { "" };
+                        // Bests from before the challenge changed aren't comparable
+                        let legacy = if stats.has_legacy_bests() { " (legacy)" } else { "" };
+                        match stats.best_score() {
+                            Some(score) => format!(" ✓ {}{} {}{}", tier, no_hints, score.points(), legacy),
+                            None => format!(" ✓ {}{}{}", tier, no_hints, legacy),
                         }
-                    } else {
-                        ""
-                    }
-                } else {
-                    ""
-                };
+                    })
+                    .unwrap_or_default();
 
                 let difficulty_tag = challenge
                     .difficulty()
                     .map(|d| format!(" [{}]", d))
                     .unwrap_or_default();
 
+                let locked = self.is_locked(challenge);
+                let lock_marker = if locked { " 🔒" } else { "" };
+
                 let number = format!("{:2}. ", display_idx + 1);
                 let title = challenge.title();
-                let content = format!("{}{}{}{}", number, title, difficulty_tag, completion_marker);
+                let content = format!("{}{}{}{}{}", number, title, difficulty_tag, completion_marker, lock_marker);
 
                 let style = if display_idx == self.selected_index {
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD)
+                } else if locked {
+                    Style::default().fg(Color::DarkGray)
                 } else {
                     Style::default().fg(Color::White)
                 };
@@ -439,8 +752,9 @@ impl ChallengeListScreen {
 
     fn render_footer(&self, f: &mut ratatui::Frame, area: Rect) {
         let help_lines = vec![
-            Line::from("↑/↓: Navigate  Enter: Select  r: Random  f: Filters"),
-            Line::from("a: All  i: Incomplete  c: Completed  q/Esc: Quit"),
+            Line::from("↑/↓: Navigate  Enter: Select  d: Drill  r: Random  f: Filters  p: Paths"),
+            Line::from("a: All  i: Incomplete  c: Completed  s: Sort by score  q/Esc: Quit"),
+            Line::from("🎯 = solved without hints  🔒 = prerequisites missing  (legacy) = bests from an older version"),
         ];
 
         let footer = Paragraph::new(help_lines)
@@ -451,3 +765,51 @@ impl ChallengeListScreen {
         f.render_widget(footer, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MasteryTier;
+    use chrono::Utc;
+    use std::time::Duration;
+
+    fn challenges() -> Vec<Challenge> {
+        vec![
+            Challenge::new("basics", "Basics", "D", "a", "b", "h"),
+            Challenge::new("advanced", "Advanced", "D", "a", "b", "h")
+                .with_requirements(vec!["basics".to_string(), "missing".to_string()], MasteryTier::Bronze),
+        ]
+    }
+
+    #[test]
+    fn test_locked_until_prerequisites_complete() {
+        let mut screen = ChallengeListScreen::new(challenges()).with_progress(Progress::new());
+        assert!(screen.select(1).is_none());
+        assert!(screen.notice.as_deref().is_some_and(|notice| notice.contains("basics")));
+
+        let mut progress = Progress::new();
+        progress.record_attempt("basics".to_string(), true, Duration::from_secs(5), None, Utc::now());
+        let mut screen = ChallengeListScreen::new(challenges()).with_progress(progress);
+        // Unknown prerequisites don't lock a challenge forever
+        assert_eq!(screen.select(1).map(|c| c.id().to_string()), Some("advanced".to_string()));
+    }
+
+    #[test]
+    fn test_replace_challenges_keeps_selection() {
+        let mut screen = ChallengeListScreen::new(challenges());
+        screen.apply_filters();
+        screen.selected_index = 1;
+
+        let mut reloaded = challenges();
+        reloaded.insert(0, Challenge::new("intro", "Intro", "D", "a", "b", "h"));
+        screen.display_mode = DisplayMode::Paths;
+        screen.replace_challenges(reloaded, Vec::new(), vec!["broken-03.toml:1: bad".to_string()]);
+
+        assert_eq!(screen.all_challenges.len(), 3);
+        assert_eq!(screen.all_challenges[screen.filtered_challenges[screen.selected_index]].id(), "advanced");
+        assert_eq!(screen.errors_height(), 3);
+        // No paths left to show
+        assert_eq!(screen.display_mode, DisplayMode::List);
+        assert!(screen.notice.as_deref().is_some_and(|notice| notice.contains("1 file(s) failed")));
+    }
+}
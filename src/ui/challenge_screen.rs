@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode};
 use ratatui::{
@@ -8,23 +10,131 @@ use ratatui::{
     Frame,
 };
 
-use crate::domain::Challenge;
+use crate::domain::blind_mode::{REVEAL_DELAY, REVEAL_PENALTY};
+use crate::domain::mastery_tier::HINTED_TIER_CAP;
+use crate::domain::{BlindMode, Challenge, ConfigMode};
 
 pub struct ChallengeMode {
     pub practice_mode: bool,
+    /// The target was not shown before the editor opened
+    pub blind: bool,
+    /// Time added to the attempt: the time on the brief in the reveal modes,
+    /// plus the penalty for revealing the target on demand
+    pub time_penalty: Duration,
+    /// Number of progressive hint levels revealed before starting
+    pub hints_used: u32,
 }
 
 /// Renders the challenge brief screen and waits for user to press Enter
 pub struct ChallengeScreen {
     practice_mode: bool,
+    practice_toggle: bool,
     show_hints: bool,
+    hints_revealed: usize,
+    config_mode: ConfigMode,
+    blind_mode: BlindMode,
+    opened_at: Instant,
+    revealed_on_demand: bool,
 }
 
 impl ChallengeScreen {
     pub fn new() -> Self {
         Self {
             practice_mode: false,
+            practice_toggle: true,
             show_hints: false,
+            hints_revealed: 0,
+            config_mode: ConfigMode::Vanilla,
+            blind_mode: BlindMode::Off,
+            opened_at: Instant::now(),
+            revealed_on_demand: false,
+        }
+    }
+
+    pub fn with_config_mode(mut self, config_mode: ConfigMode) -> Self {
+        self.config_mode = config_mode;
+        self
+    }
+
+    pub fn with_blind_mode(mut self, blind_mode: BlindMode) -> Self {
+        self.blind_mode = blind_mode;
+        self
+    }
+
+    /// Start with practice mode on (it can still be toggled with `p`)
+    pub fn with_practice_mode(mut self, practice_mode: bool) -> Self {
+        self.practice_mode = practice_mode;
+        self
+    }
+
+    /// Allow switching between practice and challenge mode with `p`
+    pub fn with_practice_toggle(mut self, practice_toggle: bool) -> Self {
+        self.practice_toggle = practice_toggle;
+        self
+    }
+
+    /// Time left until the target is revealed in `RevealAfterDelay` mode
+    fn time_until_reveal(&self) -> Option<Duration> {
+        match self.blind_mode {
+            BlindMode::RevealAfterDelay => {
+                Some(REVEAL_DELAY.saturating_sub(self.opened_at.elapsed()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Time on the clock before the editor opens; in the reveal modes it
+    /// runs from the brief, so waiting for the target isn't free
+    fn brief_time(&self) -> Duration {
+        if self.blind_mode.reveals_on_brief() {
+            self.opened_at.elapsed()
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    fn is_target_visible(&self) -> bool {
+        match self.blind_mode {
+            BlindMode::Off => true,
+            BlindMode::Hidden => false,
+            BlindMode::RevealAfterDelay => self.time_until_reveal() == Some(Duration::ZERO),
+            BlindMode::RevealOnDemand => self.revealed_on_demand,
+        }
+    }
+
+    fn challenge_mode(&self) -> ChallengeMode {
+        // Seeing the target for free makes the attempt a regular one;
+        // revealing it on demand keeps it blind but costs time
+        let blind = self.blind_mode.is_blind()
+            && (self.blind_mode == BlindMode::RevealOnDemand || !self.is_target_visible());
+        let reveal_penalty = if self.revealed_on_demand {
+            REVEAL_PENALTY
+        } else {
+            Duration::ZERO
+        };
+        let time_penalty = self.brief_time() + reveal_penalty;
+
+        ChallengeMode {
+            practice_mode: self.practice_mode,
+            blind,
+            time_penalty,
+            hints_used: self.hints_revealed as u32,
+        }
+    }
+
+    /// Reveals the next progressive hint level, if any are left
+    fn reveal_next_hint(&mut self, challenge: &Challenge) {
+        if self.hints_revealed < challenge.progressive_hints().len() {
+            self.hints_revealed += 1;
+        }
+    }
+
+    /// Handles a key while the hints overlay is open
+    fn handle_hints_key(&mut self, code: KeyCode, challenge: &Challenge) {
+        match code {
+            KeyCode::Char('n') | KeyCode::Char(' ') => self.reveal_next_hint(challenge),
+            KeyCode::Char('h') | KeyCode::Esc | KeyCode::Char('q') => self.show_hints = false,
+            _ => {}
         }
     }
 
@@ -33,23 +143,39 @@ impl ChallengeScreen {
     pub fn show(&mut self, challenge: &Challenge) -> Result<Option<ChallengeMode>> {
         let mut terminal = ratatui::init();
         terminal.clear()?;
+        self.opened_at = Instant::now();
 
         let result = loop {
             terminal.draw(|frame| self.render(frame, challenge))?;
 
+            // Redraw periodically while the clock runs on the brief
+            if self.blind_mode.reveals_on_brief() && !event::poll(Duration::from_millis(250))? {
+                continue;
+            }
+
             if let Event::Key(key) = event::read()? {
+                if self.show_hints {
+                    self.handle_hints_key(key.code, challenge);
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Enter => {
-                        break Ok(Some(ChallengeMode {
-                            practice_mode: self.practice_mode,
-                        }));
+                        break Ok(Some(self.challenge_mode()));
+                    }
+                    KeyCode::Char('r') if self.blind_mode == BlindMode::RevealOnDemand => {
+                        self.revealed_on_demand = true;
                     }
                     KeyCode::Esc | KeyCode::Char('q') => break Ok(None),
-                    KeyCode::Char('p') => {
+                    KeyCode::Char('p') if self.practice_toggle => {
                         self.practice_mode = !self.practice_mode;
                     }
                     KeyCode::Char('h') if challenge.has_progressive_hints() => {
-                        self.show_hints = !self.show_hints;
+                        // Opening the hints is asking for the first one
+                        self.show_hints = true;
+                        if self.hints_revealed == 0 {
+                            self.reveal_next_hint(challenge);
+                        }
                     }
                     _ => {}
                 }
@@ -111,6 +237,49 @@ impl ChallengeScreen {
             content_text.push(Line::from(""));
         }
 
+        // Editor config explanation
+        let config_text = match self.config_mode {
+            ConfigMode::Vanilla => "Isolated default config (change in Settings).",
+            ConfigMode::Personal => "Your own editor config; tracked separately from vanilla records.",
+        };
+        content_text.push(Line::from(vec![
+            Span::styled(
+                format!("🔧 {}: ", self.config_mode.name()),
+                Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(config_text),
+        ]));
+        content_text.push(Line::from(""));
+
+        if self.blind_mode.is_blind() {
+            let blind_text = if self.revealed_on_demand {
+                format!("Target revealed (+{}s penalty).", REVEAL_PENALTY.as_secs())
+            } else {
+                match self.time_until_reveal() {
+                    Some(left) if !left.is_zero() => {
+                        format!("Target hidden. Revealed in {}s.", left.as_secs() + 1)
+                    }
+                    Some(_) => "Target revealed; this attempt counts as a regular one.".to_string(),
+                    None if self.blind_mode == BlindMode::RevealOnDemand => format!(
+                        "Target hidden. Press r to reveal it (+{}s penalty).",
+                        REVEAL_PENALTY.as_secs()
+                    ),
+                    None => "Target hidden. Work from the description alone.".to_string(),
+                }
+            };
+            content_text.push(Line::from(vec![
+                Span::styled("🙈 Blind: ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(blind_text),
+            ]));
+            if self.blind_mode.reveals_on_brief() {
+                content_text.push(Line::from(vec![
+                    Span::styled("⏱ Clock running: ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                    Span::raw(format!("{}s", self.brief_time().as_secs())),
+                ]));
+            }
+            content_text.push(Line::from(""));
+        }
+
         // Add tags if present
         if !challenge.tags().is_empty() {
             let tags_str = challenge.tags().join(", ");
@@ -121,24 +290,69 @@ impl ChallengeScreen {
             content_text.push(Line::from(""));
         }
 
+        if !self.is_target_visible() {
+            // Blind: only name the files to edit
+            if challenge.is_multi_file() {
+                for file in challenge.files() {
+                    content_text.push(Line::from(Span::styled(
+                        format!("📄 {}", file.path()),
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    )));
+                }
+                content_text.push(Line::from(""));
+            }
+        } else if challenge.is_multi_file() {
+            // Show every file with its starting and target content
+            for file in challenge.files() {
+                content_text.push(Line::from(Span::styled(
+                    format!("📄 {}", file.path()),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                )));
+                content_text.push(Line::from(Span::styled("Starting:", Style::default().fg(Color::Yellow))));
+                content_text.extend(file.starting_content().lines().map(|line| Line::from(format!("  {}", line))));
+                content_text.push(Line::from(Span::styled("Target:", Style::default().fg(Color::Green))));
+                content_text.extend(file.target_content().lines().map(|line| Line::from(format!("  {}", line))));
+                content_text.push(Line::from(""));
+            }
+        } else {
+            content_text.extend(vec![
+                Line::from(vec![
+                    Span::styled("Starting: ", Style::default().fg(Color::Yellow)),
+                    Span::raw(format!("\"{}\"", challenge.starting_content())),
+                ]),
+                Line::from(vec![
+                    Span::styled("Target:   ", Style::default().fg(Color::Green)),
+                    Span::raw(format!("\"{}\"", challenge.target_content())),
+                ]),
+                Line::from(""),
+            ]);
+        }
+
         content_text.extend(vec![
-            Line::from(vec![
-                Span::styled("Starting: ", Style::default().fg(Color::Yellow)),
-                Span::raw(format!("\"{}\"", challenge.starting_content())),
-            ]),
-            Line::from(vec![
-                Span::styled("Target:   ", Style::default().fg(Color::Green)),
-                Span::raw(format!("\"{}\"", challenge.target_content())),
-            ]),
-            Line::from(""),
             Line::from(vec![
                 Span::raw("💡 Hint: "),
                 Span::styled(challenge.hint(), Style::default().fg(Color::Magenta)),
             ]),
         ]);
 
-        // Add optimal solution info if available
-        if let (Some(solution), Some(keystrokes)) = (challenge.optimal_solution(), challenge.optimal_keystrokes()) {
+        if self.hints_revealed > 0 {
+            content_text.push(Line::from(Span::styled(
+                format!(
+                    "💡 Hints used: {}/{} (tier capped at {})",
+                    self.hints_revealed,
+                    challenge.progressive_hints().len(),
+                    HINTED_TIER_CAP.name()
+                ),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+
+        // Add optimal solution info if available (it would give the target away in blind mode)
+        if let (true, Some(solution), Some(keystrokes)) = (
+            self.is_target_visible(),
+            challenge.optimal_solution(),
+            challenge.optimal_keystrokes(),
+        ) {
             content_text.push(Line::from(""));
             content_text.push(Line::from(vec![
                 Span::styled("⭐ Optimal: ", Style::default().fg(Color::Green)),
@@ -147,7 +361,14 @@ impl ChallengeScreen {
         }
 
         content_text.push(Line::from(""));
-        content_text.push(Line::from("Editor closes automatically when complete."));
+        if challenge.is_multi_file() {
+            content_text.push(Line::from(format!(
+                "Editor closes automatically when all {} files match their targets.",
+                challenge.files().len()
+            )));
+        } else {
+            content_text.push(Line::from("Editor closes automatically when complete."));
+        }
 
         let content = Paragraph::new(content_text)
             .alignment(Alignment::Left)
@@ -155,11 +376,20 @@ impl ChallengeScreen {
         frame.render_widget(content, chunks[1]);
 
         // Footer with all options
-        let mut footer_lines = vec![
-            Line::from("p: Toggle Practice Mode  Enter: Begin  Esc/q: Quit"),
-        ];
+        let mut footer_lines = vec![Line::from(if self.practice_toggle {
+            "p: Toggle Practice Mode  Enter: Begin  Esc/q: Quit"
+        } else {
+            "Enter: Begin  Esc/q: Quit"
+        })];
+        let mut extra_options = Vec::new();
         if challenge.has_progressive_hints() {
-            footer_lines.push(Line::from("h: View Progressive Hints"));
+            extra_options.push("h: View Progressive Hints (caps tier)");
+        }
+        if self.blind_mode == BlindMode::RevealOnDemand && !self.revealed_on_demand {
+            extra_options.push("r: Reveal Target (time penalty)");
+        }
+        if !extra_options.is_empty() {
+            footer_lines.push(Line::from(extra_options.join("  ")));
         }
 
         let footer = Paragraph::new(footer_lines)
@@ -196,15 +426,27 @@ impl ChallengeScreen {
             Line::from(""),
         ];
 
-        for (i, hint) in challenge.progressive_hints().iter().enumerate() {
-            hint_lines.push(Line::from(vec![
-                Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(hint),
-            ]));
+        let hints = challenge.progressive_hints();
+        for (i, hint) in hints.iter().enumerate() {
+            let label = Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+            if i < self.hints_revealed {
+                hint_lines.push(Line::from(vec![label, Span::raw(hint)]));
+            } else {
+                hint_lines.push(Line::from(vec![
+                    label,
+                    Span::styled("🔒 press n to reveal", Style::default().fg(Color::DarkGray)),
+                ]));
+            }
             hint_lines.push(Line::from(""));
         }
 
-        if let (Some(solution), Some(keystrokes)) = (challenge.optimal_solution(), challenge.optimal_keystrokes()) {
+        // The optimal solution is the last resort, after every hint (and never in blind mode)
+        if let (true, true, Some(solution), Some(keystrokes)) = (
+            self.hints_revealed == hints.len(),
+            self.is_target_visible(),
+            challenge.optimal_solution(),
+            challenge.optimal_keystrokes(),
+        ) {
             hint_lines.push(Line::from(""));
             hint_lines.push(Line::from(vec![
                 Span::styled("Optimal Solution: ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
@@ -214,6 +456,15 @@ impl ChallengeScreen {
             ]));
         }
 
+        hint_lines.push(Line::from(""));
+        hint_lines.push(Line::from(Span::styled(
+            format!(
+                "Each hint used lowers this attempt's score; any hint caps the tier at {}.",
+                HINTED_TIER_CAP.name()
+            ),
+            Style::default().fg(Color::DarkGray),
+        )));
+
         let hints_widget = Paragraph::new(hint_lines)
             .alignment(Alignment::Left)
             .wrap(Wrap { trim: true })
@@ -234,7 +485,7 @@ impl ChallengeScreen {
             ])
             .split(area)[1];
 
-        let footer = Paragraph::new("Press 'h' to close hints")
+        let footer = Paragraph::new("n: Reveal Next Hint  h/Esc: Close Hints")
             .style(Style::default().fg(Color::Gray))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
@@ -247,3 +498,48 @@ impl Default for ChallengeScreen {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blind_modes() {
+        let hidden = ChallengeScreen::new().with_blind_mode(BlindMode::Hidden);
+        assert!(!hidden.is_target_visible());
+        assert!(hidden.challenge_mode().blind);
+
+        let mut on_demand = ChallengeScreen::new().with_blind_mode(BlindMode::RevealOnDemand);
+        on_demand.revealed_on_demand = true;
+        let mode = on_demand.challenge_mode();
+        assert!(on_demand.is_target_visible());
+        assert!(mode.blind);
+        assert!(mode.time_penalty >= REVEAL_PENALTY);
+
+        let mut delayed = ChallengeScreen::new().with_blind_mode(BlindMode::RevealAfterDelay);
+        assert!(delayed.challenge_mode().blind);
+        delayed.opened_at = Instant::now() - REVEAL_DELAY;
+        assert!(delayed.is_target_visible());
+        let mode = delayed.challenge_mode();
+        assert!(!mode.blind);
+        // Waiting for the reveal was on the clock
+        assert!(mode.time_penalty >= REVEAL_DELAY);
+    }
+
+    #[test]
+    fn test_hints_revealed_one_level_at_a_time() {
+        let challenge = Challenge::new("id", "Title", "Desc", "a", "b", "hint")
+            .with_progressive_hints(vec!["first".to_string(), "second".to_string()]);
+        let mut screen = ChallengeScreen::new();
+        assert_eq!(screen.challenge_mode().hints_used, 0);
+
+        screen.show_hints = true;
+        screen.reveal_next_hint(&challenge);
+        screen.handle_hints_key(KeyCode::Char('n'), &challenge);
+        screen.handle_hints_key(KeyCode::Char('n'), &challenge);
+        assert_eq!(screen.challenge_mode().hints_used, 2);
+
+        screen.handle_hints_key(KeyCode::Esc, &challenge);
+        assert!(!screen.show_hints);
+    }
+}
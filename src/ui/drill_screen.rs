@@ -0,0 +1,294 @@
+use crate::domain::{Challenge, Drill, MasteryTier};
+use crate::ui::gauntlet_screen::format_time;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Sparkline, Wrap},
+    Frame,
+};
+
+const TIERS: [MasteryTier; 4] = [
+    MasteryTier::Bronze,
+    MasteryTier::Silver,
+    MasteryTier::Gold,
+    MasteryTier::Platinum,
+];
+
+/// Most runs in a row a drill can ask for
+const MAX_STREAK: u32 = 10;
+
+/// How a drill is run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrillSetup {
+    pub target: MasteryTier,
+    /// Runs in a row at or above the target that finish the drill
+    pub streak: u32,
+    /// Give every run new surface text
+    pub randomize: bool,
+}
+
+impl Default for DrillSetup {
+    fn default() -> Self {
+        Self {
+            target: MasteryTier::Gold,
+            streak: 3,
+            randomize: false,
+        }
+    }
+}
+
+/// Sets up a drill and shows its progress between runs
+pub struct DrillScreen {
+    setup: DrillSetup,
+}
+
+impl DrillScreen {
+    pub fn new() -> Self {
+        Self {
+            setup: DrillSetup::default(),
+        }
+    }
+
+    /// Asks for the drill goal; None if the user backed out
+    pub fn configure(&mut self, challenge: &Challenge) -> Result<Option<DrillSetup>> {
+        let mut terminal = ratatui::init();
+        terminal.clear()?;
+
+        let result = loop {
+            terminal.draw(|frame| self.render_setup(frame, challenge))?;
+
+            if let Event::Key(key) = event::read()? {
+                if let Some(choice) = self.handle_setup_key(key.code) {
+                    break choice;
+                }
+            }
+        };
+
+        ratatui::restore();
+        Ok(result)
+    }
+
+    /// Shows the runs so far and the next run's text
+    ///
+    /// Returns true to start the next run, false to stop (always false once
+    /// the drill is complete).
+    pub fn show_progress(&self, drill: &Drill, next: &Challenge) -> Result<bool> {
+        let mut terminal = ratatui::init();
+        terminal.clear()?;
+
+        let result = loop {
+            terminal.draw(|frame| self.render_progress(frame, drill, next))?;
+
+            if let Event::Key(key) = event::read()? {
+                if drill.is_complete() {
+                    break false;
+                }
+                match key.code {
+                    KeyCode::Enter | KeyCode::Char(' ') => break true,
+                    KeyCode::Esc | KeyCode::Char('q') => break false,
+                    _ => {}
+                }
+            }
+        };
+
+        ratatui::restore();
+        Ok(result)
+    }
+
+    /// Some(choice) ends the setup; None keeps it open
+    fn handle_setup_key(&mut self, code: KeyCode) -> Option<Option<DrillSetup>> {
+        let tier_index = TIERS.iter().position(|tier| *tier == self.setup.target).unwrap_or(0);
+        match code {
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.setup.target = TIERS[tier_index.saturating_sub(1)];
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.setup.target = TIERS[(tier_index + 1).min(TIERS.len() - 1)];
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.setup.streak = (self.setup.streak + 1).min(MAX_STREAK);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.setup.streak = self.setup.streak.saturating_sub(1).max(1);
+            }
+            KeyCode::Char('r') => self.setup.randomize = !self.setup.randomize,
+            KeyCode::Enter => return Some(Some(self.setup)),
+            KeyCode::Esc | KeyCode::Char('q') => return Some(None),
+            _ => {}
+        }
+        None
+    }
+
+    fn render_setup(&self, frame: &mut Frame, challenge: &Challenge) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Min(6),    // Settings
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+
+        self.render_title(frame, chunks[0], challenge);
+
+        let highlight = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let settings = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(format!(
+                "  Target tier:      ◀ {} {} ▶",
+                self.setup.target.emoji(),
+                self.setup.target.name()
+            ))
+            .style(highlight),
+            Line::from(format!("  Runs in a row:    ▲ {} ▼", self.setup.streak)).style(highlight),
+            Line::from(format!(
+                "  Randomized text:  {}",
+                if self.setup.randomize { "on" } else { "off" }
+            ))
+            .style(highlight),
+            Line::from(""),
+            Line::from("  Randomized text swaps the letters of every run; the edit stays the same.")
+                .style(Style::default().fg(Color::DarkGray)),
+            Line::from("  Tiers above bronze need keystroke recording (asciinema).")
+                .style(Style::default().fg(Color::DarkGray)),
+        ])
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(settings, chunks[1]);
+
+        self.render_footer(frame, chunks[2], "←/→: Tier  ↑/↓: Runs  r: Randomize  Enter: Start  Esc: Back");
+    }
+
+    fn render_progress(&self, frame: &mut Frame, drill: &Drill, next: &Challenge) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Length(4), // Status
+                Constraint::Length(5), // Time sparkline
+                Constraint::Length(5), // Keystroke sparkline
+                Constraint::Min(4),    // Next run
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+
+        self.render_title(frame, chunks[0], next);
+
+        let mut status = vec![Line::from(format!(
+            "Streak: {}/{} runs at {} {} or better",
+            drill.streak(),
+            drill.required_streak(),
+            drill.target().emoji(),
+            drill.target().name()
+        ))];
+        if let Some(last) = drill.runs().last() {
+            let tier = last
+                .tier()
+                .map_or("✗ unsolved".to_string(), |tier| format!("{} {}", tier.emoji(), tier.name()));
+            let keystrokes = last.keystrokes().map_or("-".to_string(), |k| k.to_string());
+            status.push(Line::from(format!(
+                "Run {}: {}  {} keys  {}",
+                drill.runs().len(),
+                format_time(last.time()),
+                keystrokes,
+                tier
+            )));
+        }
+        let status_style = if drill.is_complete() {
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let status = Paragraph::new(status)
+            .style(status_style)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::BOTTOM));
+        frame.render_widget(status, chunks[1]);
+
+        let times: Vec<u64> = drill
+            .runs()
+            .iter()
+            .map(|run| run.time().as_millis() as u64)
+            .collect();
+        let keystrokes: Vec<u64> = drill
+            .runs()
+            .iter()
+            .map(|run| run.keystrokes().unwrap_or(0) as u64)
+            .collect();
+        Self::render_sparkline(frame, chunks[2], "Time per run", &times, Color::Cyan);
+        Self::render_sparkline(frame, chunks[3], "Keystrokes per run", &keystrokes, Color::Magenta);
+
+        let preview = if next.is_multi_file() {
+            format!("{} files", next.files().len())
+        } else {
+            format!("{}\n\n→ {}", next.starting_content(), next.target_content())
+        };
+        let preview = Paragraph::new(preview)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title("Next run").borders(Borders::ALL));
+        frame.render_widget(preview, chunks[4]);
+
+        let footer = if drill.is_complete() {
+            "🎉 Drill complete!  Press any key to finish"
+        } else {
+            "Enter: Next run  Esc: Stop drilling"
+        };
+        self.render_footer(frame, chunks[5], footer);
+    }
+
+    /// Sparkline of the latest runs that fit the width
+    fn render_sparkline(frame: &mut Frame, area: Rect, title: &str, values: &[u64], color: Color) {
+        let width = area.width.saturating_sub(2) as usize;
+        let recent = &values[values.len().saturating_sub(width)..];
+        let sparkline = Sparkline::default()
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .data(recent)
+            .style(Style::default().fg(color));
+        frame.render_widget(sparkline, area);
+    }
+
+    fn render_title(&self, frame: &mut Frame, area: Rect, challenge: &Challenge) {
+        let title = Paragraph::new(format!("DRILL: {}", challenge.title()))
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::BOTTOM));
+        frame.render_widget(title, area);
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, text: &str) {
+        let footer = Paragraph::new(text.to_string())
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(footer, area);
+    }
+}
+
+impl Default for DrillScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_keys_clamp_and_toggle() {
+        let mut screen = DrillScreen::new();
+        for _ in 0..5 {
+            screen.handle_setup_key(KeyCode::Right);
+            screen.handle_setup_key(KeyCode::Down);
+        }
+        screen.handle_setup_key(KeyCode::Char('r'));
+
+        let setup = screen.handle_setup_key(KeyCode::Enter).unwrap().unwrap();
+        assert_eq!(setup.target, MasteryTier::Platinum);
+        assert_eq!(setup.streak, 1);
+        assert!(setup.randomize);
+        assert_eq!(screen.handle_setup_key(KeyCode::Esc), Some(None));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::domain::{Challenge, GauntletResult};
+use crate::ui::gauntlet_screen::format_time;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Row, Table},
+    Frame,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Shows the splits of a finished gauntlet
+pub struct GauntletResultsScreen {
+    /// Challenge titles by id, for the splits
+    titles: HashMap<String, String>,
+}
+
+impl GauntletResultsScreen {
+    pub fn new() -> Self {
+        Self {
+            titles: HashMap::new(),
+        }
+    }
+
+    pub fn with_challenges(mut self, challenges: &[Challenge]) -> Self {
+        self.titles = challenges
+            .iter()
+            .map(|c| (c.id().to_string(), c.title().to_string()))
+            .collect();
+        self
+    }
+
+    /// Displays the result and its leaderboard rank, if it made it onto one
+    pub fn show(&self, result: &GauntletResult, rank: Option<usize>) -> Result<()> {
+        let mut terminal = ratatui::init();
+        terminal.clear()?;
+
+        loop {
+            terminal.draw(|frame| self.render(frame, result, rank))?;
+
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    KeyCode::Char(_) | KeyCode::Enter | KeyCode::Esc => break,
+                    _ => {}
+                }
+            }
+        }
+
+        ratatui::restore();
+        Ok(())
+    }
+
+    fn render(&self, frame: &mut Frame, result: &GauntletResult, rank: Option<usize>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5), // Summary
+                Constraint::Min(5),    // Splits
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+
+        let (headline, color) = if result.is_completed() {
+            ("✓ GAUNTLET COMPLETE", Color::Green)
+        } else {
+            ("✗ GAUNTLET ABANDONED", Color::Red)
+        };
+        let mut summary = vec![
+            Line::from(headline).style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Line::from(format!(
+                "{}  —  {}/{} legs  —  total {}",
+                result.name(),
+                result.splits().iter().filter(|split| split.is_completed()).count(),
+                result.legs(),
+                format_time(result.total_time())
+            )),
+        ];
+        if let Some(rank) = rank {
+            summary.push(Line::from(format!("🏆 Leaderboard rank #{}", rank)).style(Style::default().fg(Color::Yellow)));
+        }
+        let summary = Paragraph::new(summary)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::BOTTOM));
+        frame.render_widget(summary, chunks[0]);
+
+        let mut elapsed = Duration::ZERO;
+        let rows = result.splits().iter().enumerate().map(|(i, split)| {
+            elapsed += split.time();
+            let title = self
+                .titles
+                .get(split.challenge_id())
+                .map_or(split.challenge_id(), String::as_str);
+            let keys = split.keystrokes().map_or("-".to_string(), |k| k.to_string());
+            let style = if split.is_completed() {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            Row::new(vec![
+                format!("{}", i + 1),
+                title.to_string(),
+                format_time(split.time()),
+                format_time(elapsed),
+                keys,
+            ])
+            .style(style)
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Min(20),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(6),
+            ],
+        )
+        .header(
+            Row::new(vec!["#", "Challenge", "Split", "Elapsed", "Keys"])
+                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::default().title("Splits").borders(Borders::ALL));
+        frame.render_widget(table, chunks[1]);
+
+        let footer = Paragraph::new("[ Press any key to continue ]")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(footer, chunks[2]);
+    }
+}
+
+impl Default for GauntletResultsScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
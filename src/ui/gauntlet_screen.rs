@@ -0,0 +1,189 @@
+use crate::domain::{GauntletSelection, Progress};
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+/// Leaderboard entries shown per gauntlet
+const LEADERBOARD_SIZE: usize = 10;
+
+/// Picks a gauntlet to run, showing the leaderboard of the highlighted one
+pub struct GauntletScreen {
+    presets: Vec<GauntletSelection>,
+    selected_index: usize,
+}
+
+impl GauntletScreen {
+    pub fn new(presets: Vec<GauntletSelection>) -> Self {
+        Self {
+            presets,
+            selected_index: 0,
+        }
+    }
+
+    /// Returns the chosen gauntlet, or None if the user backed out
+    pub fn show(&mut self, progress: &Progress) -> Result<Option<GauntletSelection>> {
+        let mut terminal = ratatui::init();
+        terminal.clear()?;
+
+        let result = loop {
+            terminal.draw(|frame| self.render(frame, progress))?;
+
+            if let Event::Key(key) = event::read()? {
+                if let Some(choice) = self.handle_key(key) {
+                    break choice;
+                }
+            }
+        };
+
+        ratatui::restore();
+        Ok(result)
+    }
+
+    /// Some(choice) ends the screen; None keeps it open
+    fn handle_key(&mut self, key: KeyEvent) -> Option<Option<GauntletSelection>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => Some(None),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.selected_index + 1 < self.presets.len() {
+                    self.selected_index += 1;
+                }
+                None
+            }
+            KeyCode::Enter => self.presets.get(self.selected_index).cloned().map(Some),
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, progress: &Progress) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Min(8),    // Gauntlets and leaderboard
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+
+        let title = Paragraph::new("GAUNTLET")
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::BOTTOM));
+        frame.render_widget(title, chunks[0]);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        self.render_presets(frame, body[0]);
+        self.render_leaderboard(frame, body[1], progress);
+
+        let footer = Paragraph::new(
+            "↑/↓: Navigate  Enter: Start  Esc: Back  —  challenges run back to back on one clock",
+        )
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(footer, chunks[2]);
+    }
+
+    fn render_presets(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .presets
+            .iter()
+            .map(|preset| ListItem::new(preset.name()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Gauntlets")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .highlight_symbol("▶ ");
+
+        let mut state = ListState::default().with_selected(Some(self.selected_index));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn render_leaderboard(&self, frame: &mut Frame, area: Rect, progress: &Progress) {
+        let selected = self.presets.get(self.selected_index);
+        let name = selected.map(GauntletSelection::name).unwrap_or_default();
+        let key = selected.map(GauntletSelection::key).unwrap_or_default();
+
+        let runs = progress.gauntlet_leaderboard(&key);
+        let items: Vec<ListItem> = if runs.is_empty() {
+            vec![ListItem::new("No finished runs yet").style(Style::default().fg(Color::DarkGray))]
+        } else {
+            runs.iter()
+                .take(LEADERBOARD_SIZE)
+                .enumerate()
+                .map(|(i, run)| {
+                    ListItem::new(format!(
+                        "{:>2}. {:>10}  {}",
+                        i + 1,
+                        format_time(run.total_time()),
+                        run.finished_at().format("%Y-%m-%d")
+                    ))
+                })
+                .collect()
+        };
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(format!("Leaderboard: {}", name))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(list, area);
+    }
+}
+
+/// "8.250s" under a minute, "2:05.100" above
+pub fn format_time(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    let (minutes, seconds, millis) = (millis / 60_000, millis / 1000 % 60, millis % 1000);
+    if minutes > 0 {
+        format!("{}:{:02}.{:03}", minutes, seconds, millis)
+    } else {
+        format!("{}.{:03}s", seconds, millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_enter_returns_highlighted_gauntlet() {
+        let mut screen = GauntletScreen::new(vec![
+            GauntletSelection::Random { count: 5 },
+            GauntletSelection::Random { count: 10 },
+        ]);
+        screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        let choice = screen.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(choice, Some(Some(GauntletSelection::Random { count: 10 })));
+
+        let back = screen.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(back, Some(None));
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(Duration::from_millis(8_250)), "8.250s");
+        assert_eq!(format_time(Duration::from_millis(125_100)), "2:05.100");
+    }
+}
@@ -14,29 +14,101 @@ use std::time::Duration;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MenuAction {
     StartTraining,
+    /// Play today's daily challenge
+    Daily,
+    /// Work through the challenges due for spaced-repetition review
+    Review,
+    /// Work through challenges of the lowest-rated skill tag
+    TrainWeakestSkill,
     ViewProgress,
     BrowseChallenges,
+    /// Chain challenges back to back on one clock
+    Gauntlet,
     Settings,
+    /// Hide the panel listing challenge files that failed to load
+    DismissWarnings,
     Quit,
 }
 
+/// Most broken files listed in the warning panel before summarizing the rest
+const MAX_LISTED_FAILURES: usize = 5;
+
 pub struct MainMenuScreen {
     selected_index: usize,
-    options: Vec<(&'static str, MenuAction)>,
+    options: Vec<(String, MenuAction)>,
+    /// Challenge files that failed to load, one description each
+    load_failures: Vec<String>,
 }
 
 impl MainMenuScreen {
     pub fn new() -> Self {
         Self {
             selected_index: 0,
-            options: vec![
+            options: [
                 ("Start Training", MenuAction::StartTraining),
+                ("Daily Challenge", MenuAction::Daily),
                 ("View Progress", MenuAction::ViewProgress),
                 ("Browse Challenges", MenuAction::BrowseChallenges),
+                ("Gauntlet", MenuAction::Gauntlet),
                 ("Settings", MenuAction::Settings),
                 ("Quit", MenuAction::Quit),
-            ],
+            ]
+            .into_iter()
+            .map(|(label, action)| (label.to_string(), action))
+            .collect(),
+            load_failures: Vec::new(),
+        }
+    }
+
+    /// Shows a dismissible warning panel listing challenge files that failed
+    /// to load
+    pub fn with_load_failures(mut self, failures: Vec<String>) -> Self {
+        self.load_failures = failures;
+        self
+    }
+
+    /// Shows whether today's daily challenge was played and the daily streak
+    pub fn with_daily(mut self, played_today: bool, streak: u32) -> Self {
+        let mut label = "Daily Challenge".to_string();
+        if played_today {
+            label.push_str(" ✓");
+        }
+        if streak > 0 {
+            label.push_str(&format!(" (🔥 {})", streak));
+        }
+        if let Some(option) = self
+            .options
+            .iter_mut()
+            .find(|(_, action)| *action == MenuAction::Daily)
+        {
+            option.0 = label;
+        }
+        self
+    }
+
+    /// Offers a review entry when challenges are due for review
+    pub fn with_reviews_due(mut self, due: usize) -> Self {
+        if due > 0 {
+            self.options
+                .insert(1, (format!("Review due ({})", due), MenuAction::Review));
         }
+        self
+    }
+
+    /// Offers training the named weakest skill, below the review entry
+    pub fn with_weakest_skill(mut self, tag: Option<&str>) -> Self {
+        if let Some(tag) = tag {
+            let index = self
+                .options
+                .iter()
+                .position(|(_, action)| *action == MenuAction::ViewProgress)
+                .unwrap_or(1);
+            self.options.insert(
+                index,
+                (format!("Train Weakest Skill ({})", tag), MenuAction::TrainWeakestSkill),
+            );
+        }
+        self
     }
 
     pub fn show(&mut self, progress: &Progress, total_challenges: usize) -> Result<MenuAction> {
@@ -68,7 +140,7 @@ impl MainMenuScreen {
                 Constraint::Length(3),  // Title
                 Constraint::Length(1),  // Spacing
                 Constraint::Length(6),  // Progress summary
-                Constraint::Length(1),  // Spacing
+                Constraint::Length(self.warning_height()), // Load warnings
                 Constraint::Min(8),     // Menu options
                 Constraint::Length(3),  // Controls
             ])
@@ -80,6 +152,11 @@ impl MainMenuScreen {
         // Progress summary
         self.render_progress_summary(frame, chunks[2], progress, total_challenges);
 
+        // Load warnings
+        if !self.load_failures.is_empty() {
+            self.render_load_failures(frame, chunks[3]);
+        }
+
         // Menu options
         self.render_menu(frame, chunks[4]);
 
@@ -87,6 +164,44 @@ impl MainMenuScreen {
         self.render_controls(frame, chunks[5]);
     }
 
+    /// Lines of the warning panel, borders included; 1 (spacing) without it
+    fn warning_height(&self) -> u16 {
+        if self.load_failures.is_empty() {
+            return 1;
+        }
+        let listed = self.load_failures.len().min(MAX_LISTED_FAILURES);
+        let more = usize::from(self.load_failures.len() > MAX_LISTED_FAILURES);
+        (listed + more + 2) as u16
+    }
+
+    fn render_load_failures(&self, frame: &mut Frame, area: Rect) {
+        let mut lines: Vec<Line> = self
+            .load_failures
+            .iter()
+            .take(MAX_LISTED_FAILURES)
+            .map(|failure| Line::from(failure.as_str()))
+            .collect();
+        let hidden = self.load_failures.len().saturating_sub(MAX_LISTED_FAILURES);
+        if hidden > 0 {
+            lines.push(Line::from(format!("… and {} more", hidden)).fg(Color::DarkGray));
+        }
+
+        let title = format!(
+            " ⚠ {} challenge file(s) could not be loaded (x: dismiss) ",
+            self.load_failures.len()
+        );
+        let panel = Paragraph::new(lines)
+            .style(Style::default().fg(Color::Yellow))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title(title),
+            );
+
+        frame.render_widget(panel, area);
+    }
+
     fn render_title(&self, frame: &mut Frame, area: Rect) {
         let title = Paragraph::new(vec![
             Line::from("EDITOR DOJO").style(
@@ -204,7 +319,12 @@ impl MainMenuScreen {
     }
 
     fn render_controls(&self, frame: &mut Frame, area: Rect) {
-        let controls = Paragraph::new("↑/↓: Navigate  Enter: Select  q: Quit")
+        let text = if self.load_failures.is_empty() {
+            "↑/↓: Navigate  Enter: Select  q: Quit"
+        } else {
+            "↑/↓: Navigate  Enter: Select  x: Dismiss warnings  q: Quit"
+        };
+        let controls = Paragraph::new(text)
             .alignment(Alignment::Center)
             .style(Style::default().fg(Color::DarkGray))
             .block(Block::default().borders(Borders::TOP));
@@ -228,6 +348,7 @@ impl MainMenuScreen {
                 None
             }
             KeyCode::Enter => Some(self.options[self.selected_index].1),
+            KeyCode::Char('x') if !self.load_failures.is_empty() => Some(MenuAction::DismissWarnings),
             _ => None,
         }
     }
@@ -252,3 +373,49 @@ impl Default for MainMenuScreen {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_review_entry_only_when_due() {
+        let menu = MainMenuScreen::new().with_reviews_due(0);
+        assert!(menu.options.iter().all(|(_, action)| *action != MenuAction::Review));
+
+        let mut menu = MainMenuScreen::new().with_reviews_due(3);
+        assert_eq!(menu.options[1].0, "Review due (3)");
+        menu.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        let action = menu.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(action, Some(MenuAction::Review));
+    }
+
+    #[test]
+    fn test_weakest_skill_entry_follows_review() {
+        let menu = MainMenuScreen::new()
+            .with_reviews_due(2)
+            .with_weakest_skill(Some("deletion"));
+        assert_eq!(menu.options[1].1, MenuAction::Review);
+        assert_eq!(menu.options[3].0, "Train Weakest Skill (deletion)");
+        assert_eq!(menu.options[4].1, MenuAction::ViewProgress);
+    }
+
+    #[test]
+    fn test_dismiss_only_with_load_failures() {
+        let x = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert_eq!(MainMenuScreen::new().handle_key(x), None);
+
+        let mut menu = MainMenuScreen::new()
+            .with_load_failures(vec!["challenges/helix/bad.toml:3: invalid string".to_string()]);
+        assert_eq!(menu.warning_height(), 3);
+        assert_eq!(menu.handle_key(x), Some(MenuAction::DismissWarnings));
+    }
+
+    #[test]
+    fn test_daily_label_shows_played_and_streak() {
+        let menu = MainMenuScreen::new().with_daily(true, 4);
+        let daily = menu.options.iter().find(|(_, action)| *action == MenuAction::Daily).unwrap();
+        assert_eq!(daily.0, "Daily Challenge ✓ (🔥 4)");
+    }
+}
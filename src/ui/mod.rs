@@ -3,9 +3,18 @@ pub mod challenge_screen;
 pub mod results_screen;
 pub mod main_menu_screen;
 pub mod progress_screen;
+pub mod settings_screen;
+pub mod gauntlet_screen;
+pub mod gauntlet_results_screen;
+pub mod drill_screen;
+mod bar;
 
-pub use challenge_list_screen::ChallengeListScreen;
+pub use challenge_list_screen::{ChallengeListScreen, ListSelection};
 pub use challenge_screen::{ChallengeMode, ChallengeScreen};
 pub use results_screen::ResultsScreen;
 pub use main_menu_screen::{MainMenuScreen, MenuAction};
 pub use progress_screen::ProgressScreen;
+pub use settings_screen::{Settings, SettingsScreen};
+pub use gauntlet_screen::GauntletScreen;
+pub use gauntlet_results_screen::GauntletResultsScreen;
+pub use drill_screen::DrillScreen;
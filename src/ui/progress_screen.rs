@@ -1,4 +1,7 @@
-use crate::domain::{Achievement, AchievementId, MasteryTier, Progress};
+use crate::domain::{
+    Achievement, AchievementId, Challenge, ChallengeStats, MasteryTier, Progress, SkillLevel,
+};
+use crate::ui::bar::progress_bar;
 use anyhow::Result;
 use chrono::Utc;
 use crossterm::event::{self, Event, KeyCode};
@@ -9,13 +12,36 @@ use ratatui::{
     widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Wrap},
     Frame,
 };
+use std::collections::HashMap;
 use std::time::Duration;
 
-pub struct ProgressScreen;
+pub struct ProgressScreen {
+    /// Challenge titles by id, for the score list
+    titles: HashMap<String, String>,
+    /// Per-tag skill levels, weakest first
+    skills: Vec<SkillLevel>,
+}
 
 impl ProgressScreen {
     pub fn new() -> Self {
-        Self
+        Self {
+            titles: HashMap::new(),
+            skills: Vec::new(),
+        }
+    }
+
+    /// Show challenge titles instead of ids in the score list
+    pub fn with_challenges(mut self, challenges: &[Challenge]) -> Self {
+        self.titles = challenges
+            .iter()
+            .map(|c| (c.id().to_string(), c.title().to_string()))
+            .collect();
+        self
+    }
+
+    pub fn with_skills(mut self, skills: Vec<SkillLevel>) -> Self {
+        self.skills = skills;
+        self
     }
 
     pub fn show(&self, progress: &Progress, total_challenges: usize) -> Result<()> {
@@ -49,9 +75,9 @@ impl ProgressScreen {
                 Constraint::Length(1),  // Spacing
                 Constraint::Length(3),  // Overall progress bar
                 Constraint::Length(1),  // Spacing
-                Constraint::Length(11), // Stats box (increased for more stats)
+                Constraint::Length(16), // Stats box (increased for more stats)
                 Constraint::Length(1),  // Spacing
-                Constraint::Min(8),     // Achievements
+                Constraint::Min(8),     // Achievements and scores
                 Constraint::Length(3),  // Footer
             ])
             .split(area);
@@ -59,7 +85,17 @@ impl ProgressScreen {
         self.render_title(frame, chunks[0]);
         self.render_progress_bar(frame, chunks[2], progress, total_challenges);
         self.render_stats(frame, chunks[4], progress, total_challenges);
-        self.render_achievements(frame, chunks[6], progress);
+        let bottom = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Percentage(32),
+                Constraint::Percentage(28),
+            ])
+            .split(chunks[6]);
+        self.render_achievements(frame, bottom[0], progress);
+        self.render_skills(frame, bottom[1]);
+        self.render_scores(frame, bottom[2], progress);
         self.render_footer(frame, chunks[7]);
     }
 
@@ -135,24 +171,22 @@ impl ProgressScreen {
             "0 days".to_string()
         };
         let longest_streak = format!("{} days", progress.longest_streak());
+        let daily_streak = format!(
+            "{} days (best {})",
+            progress.daily_streak(today),
+            progress.longest_daily_streak()
+        );
         let total_attempts = progress.total_attempts();
 
-        // Calculate mastery tier counts
-        let mut gold_count = 0;
-        let mut silver_count = 0;
-        let mut bronze_count = 0;
-
-        for stats in progress.all_challenge_stats().values() {
-            if let Some(tier) = stats.mastery_tier() {
-                match tier {
-                    MasteryTier::Gold => gold_count += 1,
-                    MasteryTier::Silver => silver_count += 1,
-                    MasteryTier::Bronze => bronze_count += 1,
-                }
-            }
-        }
-
-        let mastery_text = format!("{} 🥇  {} 🥈  {} 🥉", gold_count, silver_count, bronze_count);
+        let stats = progress.all_challenge_stats().values();
+        let mastery_text = Self::tier_counts_text(stats.clone().filter_map(ChallengeStats::mastery_tier));
+        // Blind mastery is a separate category
+        let blind_mastery_text =
+            Self::tier_counts_text(stats.clone().filter_map(ChallengeStats::blind_mastery_tier));
+        let total_score: u32 = stats
+            .filter_map(|stats| stats.best_score())
+            .map(|score| score.points())
+            .sum();
         let achievement_count = progress.achievement_count();
         let total_achievements = AchievementId::all().len();
 
@@ -182,6 +216,10 @@ impl ProgressScreen {
                 Span::raw("  Longest streak:         "),
                 Span::styled(longest_streak, Style::default().fg(Color::Magenta)),
             ]),
+            Line::from(vec![
+                Span::raw("  Daily streak:           "),
+                Span::styled(daily_streak, Style::default().fg(Color::Magenta)),
+            ]),
             Line::from(vec![
                 Span::raw("  Total attempts:         "),
                 Span::styled(format!("{}", total_attempts), Style::default().fg(Color::Cyan)),
@@ -190,6 +228,21 @@ impl ProgressScreen {
                 Span::raw("  Mastery tiers:          "),
                 Span::styled(mastery_text, Style::default().fg(Color::Green)),
             ]),
+            Line::from(vec![
+                Span::raw("  Blind mastery:          "),
+                Span::styled(blind_mastery_text, Style::default().fg(Color::Green)),
+            ]),
+            Line::from(vec![
+                Span::raw("  Solved without hints:   "),
+                Span::styled(
+                    format!("{}", progress.total_solved_without_hints()),
+                    Style::default().fg(Color::Green),
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("  Total score:            "),
+                Span::styled(format!("{}", total_score), Style::default().fg(Color::Yellow)),
+            ]),
             Line::from(vec![
                 Span::raw("  Achievements:           "),
                 Span::styled(
@@ -197,6 +250,13 @@ impl ProgressScreen {
                     Style::default().fg(Color::Yellow),
                 ),
             ]),
+            Line::from(vec![
+                Span::raw("  My-config completions:  "),
+                Span::styled(
+                    format!("{}", progress.personal_config_completed()),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]),
         ];
 
         let stats = Paragraph::new(lines).block(
@@ -273,6 +333,98 @@ impl ProgressScreen {
         frame.render_widget(list, list_area);
     }
 
+    fn render_skills(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .skills
+            .iter()
+            .map(|skill| {
+                let color = match skill.rating() {
+                    0..40 => Color::Red,
+                    40..70 => Color::Yellow,
+                    _ => Color::Green,
+                };
+                ListItem::new(format!(
+                    "{:<15} {} {:>3}",
+                    skill.tag(),
+                    progress_bar(skill.rating() as usize, 100),
+                    skill.rating()
+                ))
+                .style(Style::default().fg(color))
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title("Skills (weakest first)")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(list, area);
+    }
+
+    fn render_scores(&self, frame: &mut Frame, area: Rect, progress: &Progress) {
+        let items: Vec<ListItem> = Self::by_score(progress)
+            .into_iter()
+            .map(|stats| {
+                let title = self
+                    .titles
+                    .get(stats.challenge_id())
+                    .map_or(stats.challenge_id(), String::as_str);
+                let tier = stats.mastery_tier().map_or("  ", |tier| tier.emoji());
+                let points = stats.best_score().map_or(0, |score| score.points());
+                let last = match stats.scores().last() {
+                    Some((_, score)) => format!("  (last {})", score.points()),
+                    None => String::new(),
+                };
+                ListItem::new(format!("{:>5} {} {}{}", points, tier, title, last))
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title("Best scores")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(list, area);
+    }
+
+    /// Scored challenges, highest score first
+    fn by_score(progress: &Progress) -> Vec<&ChallengeStats> {
+        let mut scored: Vec<&ChallengeStats> = progress
+            .all_challenge_stats()
+            .values()
+            .filter(|stats| stats.best_score().is_some())
+            .collect();
+        scored.sort_by(|a, b| {
+            b.best_score()
+                .cmp(&a.best_score())
+                .then_with(|| a.challenge_id().cmp(b.challenge_id()))
+        });
+        scored
+    }
+
+    /// "1 💎  2 🥇  0 🥈  3 🥉" for the given tiers
+    fn tier_counts_text(tiers: impl Iterator<Item = MasteryTier>) -> String {
+        let tiers: Vec<MasteryTier> = tiers.collect();
+
+        [
+            MasteryTier::Platinum,
+            MasteryTier::Gold,
+            MasteryTier::Silver,
+            MasteryTier::Bronze,
+        ]
+        .iter()
+        .map(|tier| {
+            let count = tiers.iter().filter(|&t| t == tier).count();
+            format!("{} {}", count, tier.emoji())
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+    }
+
     fn render_footer(&self, frame: &mut Frame, area: Rect) {
         let footer = Paragraph::new("[ Press any key to return ]")
             .alignment(Alignment::Center)
@@ -303,7 +455,7 @@ impl ProgressScreen {
             let seconds = total_secs % 60;
             format!("{}m {}s", minutes, seconds)
         } else {
-            format!("{}s", total_secs)
+            format!("{:.3}s", duration.as_secs_f64())
         }
     }
 }
@@ -313,3 +465,35 @@ impl Default for ProgressScreen {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Attempt, Score};
+
+    #[test]
+    fn test_scores_sorted_highest_first() {
+        let mut progress = Progress::new();
+        for (id, points) in [("low", 300), ("high", 900), ("mid", 600)] {
+            progress.record(
+                id.to_string(),
+                Attempt::new(true, Duration::from_secs(10), Some(10), Utc::now())
+                    .with_score(Score::new(points)),
+            );
+        }
+
+        let ids: Vec<&str> = ProgressScreen::by_score(&progress)
+            .iter()
+            .map(|stats| stats.challenge_id())
+            .collect();
+        assert_eq!(ids, ["high", "mid", "low"]);
+    }
+
+    #[test]
+    fn test_tier_counts_text() {
+        let text = ProgressScreen::tier_counts_text(
+            [MasteryTier::Gold, MasteryTier::Platinum, MasteryTier::Gold].into_iter(),
+        );
+        assert_eq!(text, "1 💎  2 🥇  0 🥈  0 🥉");
+    }
+}
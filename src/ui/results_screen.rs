@@ -8,7 +8,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::domain::{Achievement, Solution};
+use crate::domain::{Achievement, ConfigMode, Solution};
 
 /// Renders the results screen after challenge completion
 pub struct ResultsScreen;
@@ -92,11 +92,40 @@ impl ResultsScreen {
 
         // Content
         let elapsed = solution.elapsed_seconds();
+        let millis = solution.elapsed_millis() % 1000;
         let mut content_lines = vec![
             Line::from(""),
-            Line::from(format!("Time: {}:{:02}s", elapsed / 60, elapsed % 60)),
+            Line::from(format!("Time: {}:{:02}.{:03}s", elapsed / 60, elapsed % 60, millis)),
         ];
 
+        if !solution.time_penalty().is_zero() {
+            content_lines.push(
+                Line::from(format!("Penalty: +{}s", solution.time_penalty().as_secs()))
+                    .style(Style::default().fg(Color::Red)),
+            );
+        }
+
+        if solution.hints_used() > 0 {
+            content_lines.push(
+                Line::from(format!("💡 Hints used: {}", solution.hints_used()))
+                    .style(Style::default().fg(Color::Yellow)),
+            );
+        }
+
+        if solution.is_blind() {
+            content_lines.push(
+                Line::from("🙈 Blind attempt (tracked as a separate mastery category)")
+                    .style(Style::default().fg(Color::Magenta)),
+            );
+        }
+
+        if solution.config_mode() == ConfigMode::Personal {
+            content_lines.push(
+                Line::from("Editor config: My config (tracked separately)")
+                    .style(Style::default().fg(Color::Magenta)),
+            );
+        }
+
         // Add recording information if available
         if let Some(recording) = solution.recording() {
             content_lines.push(Line::from(format!("Keystrokes: {}", recording.keystroke_count())));
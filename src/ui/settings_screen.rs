@@ -0,0 +1,226 @@
+use crate::domain::{BlindMode, ConfigMode};
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Settings chosen by the user
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub editor: String,
+    pub config_mode: ConfigMode,
+    pub blind_mode: BlindMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SettingsField {
+    Editor,
+    ConfigMode,
+    BlindMode,
+}
+
+pub struct SettingsScreen {
+    editors: Vec<String>,
+    editor_index: usize,
+    config_mode: ConfigMode,
+    blind_mode: BlindMode,
+    selected_field: SettingsField,
+}
+
+impl SettingsScreen {
+    /// Creates a settings screen offering the given editors
+    pub fn new(editors: Vec<String>, current: Settings) -> Self {
+        let editor_index = editors
+            .iter()
+            .position(|name| *name == current.editor)
+            .unwrap_or(0);
+
+        Self {
+            editors,
+            editor_index,
+            config_mode: current.config_mode,
+            blind_mode: current.blind_mode,
+            selected_field: SettingsField::Editor,
+        }
+    }
+
+    /// Shows the screen; returns the new settings, or None if cancelled
+    pub fn show(&mut self) -> Result<Option<Settings>> {
+        let mut terminal = ratatui::init();
+        terminal.clear()?;
+
+        let result = loop {
+            terminal.draw(|frame| self.render(frame))?;
+
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    KeyCode::Enter => break Some(self.settings()),
+                    KeyCode::Char('q') | KeyCode::Esc => break None,
+                    _ => self.handle_key(key),
+                }
+            }
+        };
+
+        ratatui::restore();
+        Ok(result)
+    }
+
+    fn settings(&self) -> Settings {
+        Settings {
+            editor: self.editors.get(self.editor_index).cloned().unwrap_or_default(),
+            config_mode: self.config_mode,
+            blind_mode: self.blind_mode,
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_field = match self.selected_field {
+                    SettingsField::Editor => SettingsField::BlindMode,
+                    SettingsField::ConfigMode => SettingsField::Editor,
+                    SettingsField::BlindMode => SettingsField::ConfigMode,
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_field = match self.selected_field {
+                    SettingsField::Editor => SettingsField::ConfigMode,
+                    SettingsField::ConfigMode => SettingsField::BlindMode,
+                    SettingsField::BlindMode => SettingsField::Editor,
+                };
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                let forward = !matches!(key.code, KeyCode::Left | KeyCode::Char('h'));
+                self.change_selected(forward);
+            }
+            _ => {}
+        }
+    }
+
+    fn change_selected(&mut self, forward: bool) {
+        match self.selected_field {
+            SettingsField::Editor if !self.editors.is_empty() => {
+                let len = self.editors.len();
+                self.editor_index = if forward {
+                    (self.editor_index + 1) % len
+                } else {
+                    (self.editor_index + len - 1) % len
+                };
+            }
+            SettingsField::Editor => {}
+            SettingsField::ConfigMode => self.config_mode = self.config_mode.toggle(),
+            SettingsField::BlindMode if forward => self.blind_mode = self.blind_mode.next(),
+            SettingsField::BlindMode => self.blind_mode = self.blind_mode.previous(),
+        }
+    }
+
+    fn render(&self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Min(8),    // Options
+                Constraint::Length(3), // Controls
+            ])
+            .split(frame.area());
+
+        let title = Paragraph::new("SETTINGS")
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, chunks[0]);
+
+        self.render_options(frame, chunks[1]);
+
+        let controls = Paragraph::new("↑/↓: Select  ←/→: Change  Enter: Save  Esc: Cancel")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(controls, chunks[2]);
+    }
+
+    fn render_options(&self, frame: &mut Frame, area: Rect) {
+        let settings = self.settings();
+        let mode_help = match settings.config_mode {
+            ConfigMode::Vanilla => {
+                "The editor starts with an empty, isolated config so keystroke counts match the optimal solutions."
+            }
+            ConfigMode::Personal => {
+                "The editor loads your own config. Results are tracked separately from vanilla records."
+            }
+        };
+
+        let lines = vec![
+            Line::from(""),
+            self.option_line(SettingsField::Editor, "Editor", &settings.editor),
+            Line::from(""),
+            self.option_line(SettingsField::ConfigMode, "Config", settings.config_mode.name()),
+            Line::from(""),
+            self.option_line(SettingsField::BlindMode, "Blind", &settings.blind_mode.name()),
+            Line::from(""),
+            Line::from(Span::styled(mode_help, Style::default().fg(Color::Gray))),
+        ];
+
+        let options = Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            );
+        frame.render_widget(options, area);
+    }
+
+    fn option_line(&self, field: SettingsField, label: &str, value: &str) -> Line<'static> {
+        let selected = self.selected_field == field;
+        let prefix = if selected { "> " } else { "  " };
+        let value_style = if selected {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+
+        Line::from(vec![
+            Span::raw(format!("{}{:<8}", prefix, label)),
+            Span::styled(format!("◀ {} ▶", value), value_style),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_cycle_editor_and_toggle_config_mode() {
+        let mut screen = SettingsScreen::new(
+            vec!["Helix".to_string(), "Vim".to_string()],
+            Settings {
+                editor: "Vim".to_string(),
+                config_mode: ConfigMode::Vanilla,
+                blind_mode: BlindMode::Off,
+            },
+        );
+
+        screen.handle_key(key(KeyCode::Right));
+        assert_eq!(screen.settings().editor, "Helix");
+
+        screen.handle_key(key(KeyCode::Down));
+        screen.handle_key(key(KeyCode::Right));
+        assert_eq!(screen.settings().config_mode, ConfigMode::Personal);
+
+        screen.handle_key(key(KeyCode::Down));
+        screen.handle_key(key(KeyCode::Left));
+        assert_eq!(screen.settings().blind_mode, BlindMode::RevealOnDemand);
+    }
+}